@@ -3,6 +3,7 @@ import 'dart:io';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:path_provider/path_provider.dart';
 import '../../src/rust/api.dart';
+import '../../src/rust/node/error.dart';
 
 class NodeState {
   final NodeStats? stats;
@@ -30,8 +31,15 @@ class NodeNotifier extends AsyncNotifier<NodeState> {
     if (running) {
       _startStatsPolling();
       // Fetch initial stats immediately if possible
-      final stats = await getNodeStats();
-      return NodeState(isRunning: true, stats: stats);
+      try {
+        final stats = await getNodeStats();
+        return NodeState(isRunning: true, stats: stats);
+      } on BonsaiNodeError catch (e) {
+        if (e.kind != BonsaiNodeErrorKind.notRunning) rethrow;
+        // Stopped between the two calls
+        _statsTimer?.cancel();
+        return NodeState(isRunning: false);
+      }
     }
     return NodeState(isRunning: false);
   }
@@ -75,11 +83,14 @@ class NodeNotifier extends AsyncNotifier<NodeState> {
     _statsTimer = Timer.periodic(const Duration(seconds: 2), (timer) async {
       try {
         final stats = await getNodeStats();
-        if (stats != null) {
-          state = AsyncValue.data(
-            state.value?.copyWith(stats: stats) ??
-                NodeState(stats: stats, isRunning: true),
-          );
+        state = AsyncValue.data(
+          state.value?.copyWith(stats: stats) ??
+              NodeState(stats: stats, isRunning: true),
+        );
+      } on BonsaiNodeError catch (e) {
+        if (e.kind == BonsaiNodeErrorKind.notRunning) {
+          timer.cancel();
+          state = AsyncValue.data(NodeState(isRunning: false));
         }
       } catch (e) {
         // Silently fail or use a proper logger in the future
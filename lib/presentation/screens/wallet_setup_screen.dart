@@ -18,6 +18,7 @@ class _WalletSetupScreenState extends State<WalletSetupScreen> {
   bool _confirmed = false;
   bool _isLoading = false;
   final _mnemonicController = TextEditingController();
+  DateTime? _birthday;
   String? _errorMessage;
 
   Future<String> get _dataDir async {
@@ -63,7 +64,14 @@ class _WalletSetupScreenState extends State<WalletSetupScreen> {
 
     try {
       final dataDir = await _dataDir;
-      await importWalletMnemonic(dataDir: dataDir, mnemonic: mnemonic);
+      final birthday = _birthday;
+      await importWalletMnemonic(
+        dataDir: dataDir,
+        mnemonic: mnemonic,
+        birthday: birthday == null
+            ? null
+            : BigInt.from(birthday.millisecondsSinceEpoch ~/ 1000),
+      );
       _navigateToMain();
     } catch (e) {
       setState(() {
@@ -73,6 +81,25 @@ class _WalletSetupScreenState extends State<WalletSetupScreen> {
     }
   }
 
+  Future<void> _pickBirthday() async {
+    final now = DateTime.now();
+    final picked = await showDatePicker(
+      context: context,
+      initialDate: _birthday ?? now,
+      firstDate: DateTime(2009, 1, 3),
+      lastDate: now,
+    );
+    if (picked != null) {
+      setState(() => _birthday = picked);
+    }
+  }
+
+  String _formatDate(DateTime date) {
+    final month = date.month.toString().padLeft(2, '0');
+    final day = date.day.toString().padLeft(2, '0');
+    return '${date.year}-$month-$day';
+  }
+
   void _navigateToMain() {
     Navigator.of(
       context,
@@ -340,6 +367,37 @@ class _WalletSetupScreenState extends State<WalletSetupScreen> {
             ),
           ),
         ),
+        const SizedBox(height: 16),
+        InkWell(
+          onTap: _isLoading ? null : _pickBirthday,
+          borderRadius: BorderRadius.circular(12),
+          child: Padding(
+            padding: const EdgeInsets.symmetric(vertical: 8),
+            child: Row(
+              children: [
+                const Icon(Icons.event, color: Colors.white70),
+                const SizedBox(width: 12),
+                Expanded(
+                  child: Text(
+                    _birthday == null
+                        ? 'Wallet creation date (optional)'
+                        : 'Created on ${_formatDate(_birthday!)}',
+                    style: const TextStyle(color: Colors.white70),
+                  ),
+                ),
+                if (_birthday != null)
+                  IconButton(
+                    icon: const Icon(Icons.close, color: Colors.white38),
+                    onPressed: () => setState(() => _birthday = null),
+                  ),
+              ],
+            ),
+          ),
+        ),
+        const Text(
+          'Scanning starts from this date. Leave empty to scan the full chain.',
+          style: TextStyle(color: Colors.white38, fontSize: 12),
+        ),
         if (_errorMessage != null) ...[
           const SizedBox(height: 12),
           Text(_errorMessage!, style: const TextStyle(color: Colors.redAccent)),
@@ -4,9 +4,11 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'frb_generated.dart';
+import 'node/error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `census_buckets`, `install_geoip_database`, `latest_snapshot`, `new`, `parse_ban_address`, `resolve_node_peer`, `start_services`, `update_watchlist`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
 
 Future<void> startNodeService({
   required String dataDir,
@@ -21,8 +23,271 @@ Future<void> stopNodeService() =>
 
 Future<bool> isNodeRunning() => RustLib.instance.api.crateApiIsNodeRunning();
 
-Future<NodeStats?> getNodeStats() =>
-    RustLib.instance.api.crateApiGetNodeStats();
+/// Node statistics. Fields the node failed to report are flagged in `field_status`
+/// rather than failing the whole call.
+///
+/// Served from the background sampler's latest snapshot, so this never touches the node.
+Future<NodeStats> getNodeStats() => RustLib.instance.api.crateApiGetNodeStats();
+
+/// The stats history of the running node, oldest first, for drawing charts.
+/// Holds up to an hour of samples, one per second.
+Future<List<NodeStatsSample>> getNodeStatsHistory() =>
+    RustLib.instance.api.crateApiGetNodeStatsHistory();
+
+/// UNIX timestamp of when the running node was started
+Future<BigInt> getNodeStartTime() =>
+    RustLib.instance.api.crateApiGetNodeStartTime();
+
+/// Sync phase, progress and ETA of the running node
+Future<SyncProgressInfo> getSyncProgress() =>
+    RustLib.instance.api.crateApiGetSyncProgress();
+
+/// Connect to a peer at `host:port` (or `host`, on the network's default port)
+Future<String> connectPeer({required String address}) =>
+    RustLib.instance.api.crateApiConnectPeer(address: address);
+
+/// Disconnect the peer at the given socket address
+Future<void> disconnectNodePeer({required String address}) =>
+    RustLib.instance.api.crateApiDisconnectNodePeer(address: address);
+
+/// Ban a peer's IP for `duration_secs` and disconnect it if connected
+Future<void> banPeer({required String address, required BigInt durationSecs}) =>
+    RustLib.instance.api.crateApiBanPeer(
+      address: address,
+      durationSecs: durationSecs,
+    );
+
+/// Lift the ban on a peer's IP
+Future<bool> unbanPeer({required String address}) =>
+    RustLib.instance.api.crateApiUnbanPeer(address: address);
+
+/// List the currently banned peers
+Future<List<BannedPeerInfo>> listBannedPeers() =>
+    RustLib.instance.api.crateApiListBannedPeers();
+
+/// Remove every ban
+Future<void> clearBannedPeers() =>
+    RustLib.instance.api.crateApiClearBannedPeers();
+
+/// Save the trusted peers for `network`. They are applied the next time the node starts.
+Future<void> setTrustedPeers({
+  required String dataDir,
+  required String network,
+  required List<TrustedPeerConfig> peers,
+  required TrustedPeerMode mode,
+}) => RustLib.instance.api.crateApiSetTrustedPeers(
+  dataDir: dataDir,
+  network: network,
+  peers: peers,
+  mode: mode,
+);
+
+/// Get the saved trusted peers for `network`, in fallback order
+Future<List<TrustedPeerConfig>> getTrustedPeers({
+  required String dataDir,
+  required String network,
+}) => RustLib.instance.api.crateApiGetTrustedPeers(
+  dataDir: dataDir,
+  network: network,
+);
+
+/// Connection status of each trusted peer of the running node
+Future<List<TrustedPeerStatus>> getTrustedPeersStatus() =>
+    RustLib.instance.api.crateApiGetTrustedPeersStatus();
+
+/// Load the GeoLite2 ASN and City databases from `dir`, used to enrich peers in [`get_node_stats`]
+Future<List<GeoIpDatabaseStatus>> loadGeoipDatabases({required String dir}) =>
+    RustLib.instance.api.crateApiLoadGeoipDatabases(dir: dir);
+
+/// Load a single ASN or City database from `path`, replacing the loaded one of the same kind
+Future<GeoIpDatabaseStatus> loadGeoipDatabase({required String path}) =>
+    RustLib.instance.api.crateApiLoadGeoipDatabase(path: path);
+
+/// Validate the `.mmdb` file at `source_path`, copy it into `geoip_dir` and start using it
+Future<GeoIpDatabaseStatus> importGeoipDatabase({
+  required String sourcePath,
+  required String geoipDir,
+}) => RustLib.instance.api.crateApiImportGeoipDatabase(
+  sourcePath: sourcePath,
+  geoipDir: geoipDir,
+);
+
+/// Metadata of the loaded GeoIP databases, empty if none is loaded
+Future<List<GeoIpDatabaseStatus>> getGeoipDatabases() =>
+    RustLib.instance.api.crateApiGetGeoipDatabases();
+
+/// Unload the GeoIP databases; peers are then reported without location data
+Future<void> unloadGeoipDatabases() =>
+    RustLib.instance.api.crateApiUnloadGeoipDatabases();
+
+/// Set the peer diversity policy, applied on every stats refresh
+Future<void> setPeerDiversityPolicy({required PeerDiversityPolicy policy}) =>
+    RustLib.instance.api.crateApiSetPeerDiversityPolicy(policy: policy);
+
+/// Get the current peer diversity policy
+Future<PeerDiversityPolicy> getPeerDiversityPolicy() =>
+    RustLib.instance.api.crateApiGetPeerDiversityPolicy();
+
+/// The outbound peers checked against the diversity policy, as of the latest sample
+Future<PeerDiversityInfo> getPeerDiversity() =>
+    RustLib.instance.api.crateApiGetPeerDiversity();
+
+/// Group the connected peers by country, ASN and software
+Future<PeerCensusInfo> getPeerCensus() =>
+    RustLib.instance.api.crateApiGetPeerCensus();
+
+/// Fetch a block header by height or hash
+Future<BlockHeaderInfo> getBlockHeader({required String query}) =>
+    RustLib.instance.api.crateApiGetBlockHeader(query: query);
+
+/// Fetch a block by height or hash and decode its transactions
+Future<BlockInfo> getBlock({required String query}) =>
+    RustLib.instance.api.crateApiGetBlock(query: query);
+
+/// Upper bounds in sat/vB of the fee-rate histogram buckets
+Future<Float64List> feeRateBucketBounds() =>
+    RustLib.instance.api.crateApiFeeRateBucketBounds();
+
+/// Fee statistics of up to `limit` of the most recent blocks, newest first
+Future<List<BlockFeeStatsInfo>> getRecentBlockFeeStats({required int limit}) =>
+    RustLib.instance.api.crateApiGetRecentBlockFeeStats(limit: limit);
+
+/// Fee statistics of the block at `height`, if it is among the recent ones
+Future<BlockFeeStatsInfo?> getBlockFeeStats({required int height}) =>
+    RustLib.instance.api.crateApiGetBlockFeeStats(height: height);
+
+/// A page of the recent blocks, newest first
+Future<RecentBlocksPage> getRecentBlocks({
+  required int offset,
+  required int limit,
+}) =>
+    RustLib.instance.api.crateApiGetRecentBlocks(offset: offset, limit: limit);
+
+/// Reorgs seen since the node started, newest first
+Future<List<ReorgInfo>> getRecentReorgs() =>
+    RustLib.instance.api.crateApiGetRecentReorgs();
+
+/// Stream every new reorg to the app, as the node detects it
+Stream<ReorgInfo> reorgStream() => RustLib.instance.api.crateApiReorgStream();
+
+/// Per-consumer metrics of the block pipeline
+Future<List<BlockConsumerMetrics>> getBlockPipelineMetrics() =>
+    RustLib.instance.api.crateApiGetBlockPipelineMetrics();
+
+/// Add or remove one of the built-in block consumers while the node runs
+Future<void> setBlockConsumerEnabled({
+  required String name,
+  required bool enabled,
+}) => RustLib.instance.api.crateApiSetBlockConsumerEnabled(
+  name: name,
+  enabled: enabled,
+);
+
+/// Names of the block consumers currently receiving blocks
+Future<List<String>> getBlockConsumers() =>
+    RustLib.instance.api.crateApiGetBlockConsumers();
+
+/// Watch an address that isn't in the wallet for activity
+Future<WatchEntryInfo> addWatchAddress({
+  required String address,
+  required String label,
+}) => RustLib.instance.api.crateApiAddWatchAddress(
+  address: address,
+  label: label,
+);
+
+/// Watch a raw output script, in hex, for activity
+Future<WatchEntryInfo> addWatchScript({
+  required String scriptHex,
+  required String label,
+}) => RustLib.instance.api.crateApiAddWatchScript(
+  scriptHex: scriptHex,
+  label: label,
+);
+
+/// Stop watching an address or script hex. Returns whether it was watched
+Future<bool> removeWatch({required String scriptOrAddress}) =>
+    RustLib.instance.api.crateApiRemoveWatch(scriptOrAddress: scriptOrAddress);
+
+Future<List<WatchEntryInfo>> getWatchlist() =>
+    RustLib.instance.api.crateApiGetWatchlist();
+
+/// Up to `limit` watchlist matches, newest first
+Future<List<WatchMatchInfo>> getWatchMatches({required int limit}) =>
+    RustLib.instance.api.crateApiGetWatchMatches(limit: limit);
+
+/// Stream every new watchlist match to the app, as blocks come in
+Stream<WatchMatchInfo> watchActivityStream() =>
+    RustLib.instance.api.crateApiWatchActivityStream();
+
+/// Export the accumulator at the node's validation tip
+Future<AccumulatorPackageInfo> exportAccumulatorPackage() =>
+    RustLib.instance.api.crateApiExportAccumulatorPackage();
+
+/// Decode a package and check it against the running node, without importing it
+Future<AccumulatorImportPreview> inspectAccumulatorPackage({
+  required String package,
+}) =>
+    RustLib.instance.api.crateApiInspectAccumulatorPackage(package: package);
+
+/// Import a package as the assume-utreexo starting point of this network.
+/// `accept_trust` must be set once the user has seen the trust warning.
+/// Takes effect on the next start with an empty chain state, see [`reset_node_chain_state`]
+Future<void> importAccumulatorPackage({
+  required String package,
+  required bool acceptTrust,
+}) => RustLib.instance.api.crateApiImportAccumulatorPackage(
+  package: package,
+  acceptTrust: acceptTrust,
+);
+
+/// Forget an imported accumulator. Returns whether there was one.
+Future<bool> removeImportedAccumulator({
+  required String dataDir,
+  required String network,
+}) => RustLib.instance.api.crateApiRemoveImportedAccumulator(
+  dataDir: dataDir,
+  network: network,
+);
+
+/// Delete the node's blocks, headers and filters for `network`, so the next start syncs again,
+/// from an imported accumulator if there is one. The node must be stopped first.
+Future<void> resetNodeChainState({
+  required String dataDir,
+  required String network,
+}) => RustLib.instance.api.crateApiResetNodeChainState(
+  dataDir: dataDir,
+  network: network,
+);
+
+/// The accumulator's structure, and its changes over up to `history_len` recent blocks
+Future<AccumulatorInfo> getAccumulatorInfo({required int historyLen}) =>
+    RustLib.instance.api.crateApiGetAccumulatorInfo(historyLen: historyLen);
+
+/// Encode `data` as UR frames (BCR-2020-005), e.g. an accumulator package, a PSBT or a
+/// descriptor export. `ur_type` defaults to "bytes", `max_fragment_len` to 200 bytes,
+/// `frame_count` to twice the fragment count
+Future<AnimatedQr> encodeAnimatedQr({
+  required List<int> data,
+  String? urType,
+  int? maxFragmentLen,
+  int? frameCount,
+}) => RustLib.instance.api.crateApiEncodeAnimatedQr(
+  data: data,
+  urType: urType,
+  maxFragmentLen: maxFragmentLen,
+  frameCount: frameCount,
+);
+
+/// Start a new QR scan, dropping any scan in progress
+Future<void> startQrScan() => RustLib.instance.api.crateApiStartQrScan();
+
+/// Feed one scanned QR code to the scan, in any order. Starts a scan if none is running
+Future<QrScanProgress> receiveQrFrame({required String frame}) =>
+    RustLib.instance.api.crateApiReceiveQrFrame(frame: frame);
+
+/// Drop the scan in progress
+Future<void> cancelQrScan() => RustLib.instance.api.crateApiCancelQrScan();
 
 Future<WalletInfo?> getWalletInfo() =>
     RustLib.instance.api.crateApiGetWalletInfo();
@@ -31,9 +296,28 @@ Future<WalletInfo?> getWalletInfo() =>
 Future<List<WalletTransactionInfo>> getWalletTransactions() =>
     RustLib.instance.api.crateApiGetWalletTransactions();
 
-/// Sync wallet - process pending blocks from the node
+/// Details of a confirmed wallet transaction, without fetching its block.
+/// `None` if it isn't in the index.
+Future<IndexedTransactionInfo?> getTransactionDetail({required String txid}) =>
+    RustLib.instance.api.crateApiGetTransactionDetail(txid: txid);
+
+/// Sync wallet - blocks are applied as the node delivers them,
+/// this returns how many were applied since the last call
 Future<int> syncWallet() => RustLib.instance.api.crateApiSyncWallet();
 
+/// Rescan the wallet with the node's block filters, from `from_height`
+/// or else from the wallet's birthday. Returns the start height
+Future<int> startWalletRescan({int? fromHeight}) =>
+    RustLib.instance.api.crateApiStartWalletRescan(fromHeight: fromHeight);
+
+/// Progress of the current or last wallet rescan
+Future<WalletRescanProgress?> getWalletRescanProgress() =>
+    RustLib.instance.api.crateApiGetWalletRescanProgress();
+
+/// Stop the running rescan, keeping what it found. Returns whether one was running
+Future<bool> cancelWalletRescan() =>
+    RustLib.instance.api.crateApiCancelWalletRescan();
+
 /// Create and sign a Bitcoin transaction
 /// Returns the transaction ID and raw hex for broadcast
 Future<SendTransactionResult> sendTransaction({
@@ -55,18 +339,601 @@ Future<String> createWalletMnemonic({required String dataDir}) =>
     RustLib.instance.api.crateApiCreateWalletMnemonic(dataDir: dataDir);
 
 /// Import an existing mnemonic phrase
+/// `birthday` is the UNIX time the wallet was first used, if known;
+/// without it a rescan has to start from the first block
 Future<void> importWalletMnemonic({
   required String dataDir,
   required String mnemonic,
+  BigInt? birthday,
 }) => RustLib.instance.api.crateApiImportWalletMnemonic(
   dataDir: dataDir,
   mnemonic: mnemonic,
+  birthday: birthday,
 );
 
 /// Get the stored mnemonic phrase (for backup display)
 Future<String?> getWalletMnemonic({required String dataDir}) =>
     RustLib.instance.api.crateApiGetWalletMnemonic(dataDir: dataDir);
 
+/// How one block changed the accumulator
+class AccumulatorDeltaInfo {
+  final int height;
+  final String hash;
+  final int leavesAdded;
+  final int leavesDeleted;
+  /// Leaf count after the block; `None` if the history doesn't reach the validation tip yet
+  final BigInt? leavesAfter;
+
+  const AccumulatorDeltaInfo({
+    required this.height,
+    required this.hash,
+    required this.leavesAdded,
+    required this.leavesDeleted,
+    this.leavesAfter,
+  });
+
+  @override
+  int get hashCode =>
+      height.hashCode ^
+      hash.hashCode ^
+      leavesAdded.hashCode ^
+      leavesDeleted.hashCode ^
+      leavesAfter.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccumulatorDeltaInfo &&
+          runtimeType == other.runtimeType &&
+          height == other.height &&
+          hash == other.hash &&
+          leavesAdded == other.leavesAdded &&
+          leavesDeleted == other.leavesDeleted &&
+          leavesAfter == other.leavesAfter;
+}
+
+/// What importing a package would mean, to show the user before they accept it
+class AccumulatorImportPreview {
+  final AccumulatorPackageInfo package;
+  /// Whether the package's block is in our own header chain, at its height
+  final bool inHeaderChain;
+  /// Why it can't be imported, if it can't
+  final String? problem;
+  final String trustWarning;
+
+  const AccumulatorImportPreview({
+    required this.package,
+    required this.inHeaderChain,
+    this.problem,
+    required this.trustWarning,
+  });
+
+  @override
+  int get hashCode =>
+      package.hashCode ^
+      inHeaderChain.hashCode ^
+      problem.hashCode ^
+      trustWarning.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccumulatorImportPreview &&
+          runtimeType == other.runtimeType &&
+          package == other.package &&
+          inHeaderChain == other.inHeaderChain &&
+          problem == other.problem &&
+          trustWarning == other.trustWarning;
+}
+
+/// The node's utreexo accumulator at its validation tip
+class AccumulatorInfo {
+  final int height;
+  final String blockHash;
+  /// Every leaf ever added; deletions don't lower it
+  final BigInt leaves;
+  final int forestRows;
+  /// Tallest tree first
+  final List<AccumulatorRootInfo> roots;
+  final int rootsSizeBytes;
+  final int serializedSizeBytes;
+  /// SHA-256 of the serialized accumulator, in hex. Matches on any node at the same block
+  final String commitment;
+  /// Newest first
+  final List<AccumulatorDeltaInfo> history;
+
+  const AccumulatorInfo({
+    required this.height,
+    required this.blockHash,
+    required this.leaves,
+    required this.forestRows,
+    required this.roots,
+    required this.rootsSizeBytes,
+    required this.serializedSizeBytes,
+    required this.commitment,
+    required this.history,
+  });
+
+  @override
+  int get hashCode =>
+      height.hashCode ^
+      blockHash.hashCode ^
+      leaves.hashCode ^
+      forestRows.hashCode ^
+      roots.hashCode ^
+      rootsSizeBytes.hashCode ^
+      serializedSizeBytes.hashCode ^
+      commitment.hashCode ^
+      history.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccumulatorInfo &&
+          runtimeType == other.runtimeType &&
+          height == other.height &&
+          blockHash == other.blockHash &&
+          leaves == other.leaves &&
+          forestRows == other.forestRows &&
+          roots == other.roots &&
+          rootsSizeBytes == other.rootsSizeBytes &&
+          serializedSizeBytes == other.serializedSizeBytes &&
+          commitment == other.commitment &&
+          history == other.history;
+}
+
+/// The node's utreexo accumulator, packaged to bootstrap another device
+class AccumulatorPackageInfo {
+  /// Checksummed text, for copying or a QR code
+  final String package;
+  final int height;
+  final String blockHash;
+  final BigInt leaves;
+  final List<String> roots;
+  /// e.g. "bitcoin", "signet"; `None` if the magic is unknown
+  final String? network;
+
+  const AccumulatorPackageInfo({
+    required this.package,
+    required this.height,
+    required this.blockHash,
+    required this.leaves,
+    required this.roots,
+    this.network,
+  });
+
+  @override
+  int get hashCode =>
+      package.hashCode ^
+      height.hashCode ^
+      blockHash.hashCode ^
+      leaves.hashCode ^
+      roots.hashCode ^
+      network.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccumulatorPackageInfo &&
+          runtimeType == other.runtimeType &&
+          package == other.package &&
+          height == other.height &&
+          blockHash == other.blockHash &&
+          leaves == other.leaves &&
+          roots == other.roots &&
+          network == other.network;
+}
+
+/// One tree of the utreexo forest
+class AccumulatorRootInfo {
+  final String hash;
+  /// Tree height; the tree holds 2^row leaves
+  final int row;
+  /// Position in the forest, leaves numbered first, then each row above
+  final BigInt position;
+
+  const AccumulatorRootInfo({
+    required this.hash,
+    required this.row,
+    required this.position,
+  });
+
+  @override
+  int get hashCode => hash.hashCode ^ row.hashCode ^ position.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccumulatorRootInfo &&
+          runtimeType == other.runtimeType &&
+          hash == other.hash &&
+          row == other.row &&
+          position == other.position;
+}
+
+/// Frames of an animated QR code, to show in a loop
+class AnimatedQr {
+  final String urType;
+  /// Frames needed at the least; the rest let a scanner make up for missed ones
+  final int fragmentCount;
+  /// `ur:` strings, one per QR code. A single one if the payload fits it
+  final List<String> frames;
+
+  const AnimatedQr({
+    required this.urType,
+    required this.fragmentCount,
+    required this.frames,
+  });
+
+  @override
+  int get hashCode =>
+      urType.hashCode ^ fragmentCount.hashCode ^ frames.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AnimatedQr &&
+          runtimeType == other.runtimeType &&
+          urType == other.urType &&
+          fragmentCount == other.fragmentCount &&
+          frames == other.frames;
+}
+
+/// A banned peer address for Flutter
+class BannedPeerInfo {
+  final String address;
+  final BigInt createdAt;
+  final BigInt bannedUntil;
+
+  const BannedPeerInfo({
+    required this.address,
+    required this.createdAt,
+    required this.bannedUntil,
+  });
+
+  @override
+  int get hashCode =>
+      address.hashCode ^ createdAt.hashCode ^ bannedUntil.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BannedPeerInfo &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          createdAt == other.createdAt &&
+          bannedUntil == other.bannedUntil;
+}
+
+/// How one block consumer (wallet, fee stats, ...) is keeping up with the node
+class BlockConsumerMetrics {
+  final String name;
+  final BigInt delivered;
+  final BigInt processed;
+  /// Blocks waiting in this consumer's queue
+  final BigInt lag;
+  /// Height of the last block processed
+  final int lastHeight;
+  /// How often the node had to wait for this consumer
+  final BigInt stalls;
+  final BigInt avgProcessMicros;
+  /// Still replaying past blocks from the node
+  final bool catchingUp;
+
+  const BlockConsumerMetrics({
+    required this.name,
+    required this.delivered,
+    required this.processed,
+    required this.lag,
+    required this.lastHeight,
+    required this.stalls,
+    required this.avgProcessMicros,
+    required this.catchingUp,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      delivered.hashCode ^
+      processed.hashCode ^
+      lag.hashCode ^
+      lastHeight.hashCode ^
+      stalls.hashCode ^
+      avgProcessMicros.hashCode ^
+      catchingUp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlockConsumerMetrics &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          delivered == other.delivered &&
+          processed == other.processed &&
+          lag == other.lag &&
+          lastHeight == other.lastHeight &&
+          stalls == other.stalls &&
+          avgProcessMicros == other.avgProcessMicros &&
+          catchingUp == other.catchingUp;
+}
+
+/// Fee statistics of one block, computed from the outputs it spends
+class BlockFeeStatsInfo {
+  final int height;
+  final String hash;
+  final int txCount;
+  final BigInt totalFeesSats;
+  /// Fee rates in sat/vB
+  final double minFeeRate;
+  final double medianFeeRate;
+  final double maxFeeRate;
+  /// Transaction count per bucket of `fee_rate_bucket_bounds`, plus a last one for everything above
+  final Uint32List feeRateHistogram;
+  final double segwitPercent;
+  final double taprootPercent;
+  /// Transactions left out of the fee figures because a spent output was missing
+  final int missingPrevouts;
+
+  const BlockFeeStatsInfo({
+    required this.height,
+    required this.hash,
+    required this.txCount,
+    required this.totalFeesSats,
+    required this.minFeeRate,
+    required this.medianFeeRate,
+    required this.maxFeeRate,
+    required this.feeRateHistogram,
+    required this.segwitPercent,
+    required this.taprootPercent,
+    required this.missingPrevouts,
+  });
+
+  @override
+  int get hashCode =>
+      height.hashCode ^
+      hash.hashCode ^
+      txCount.hashCode ^
+      totalFeesSats.hashCode ^
+      minFeeRate.hashCode ^
+      medianFeeRate.hashCode ^
+      maxFeeRate.hashCode ^
+      feeRateHistogram.hashCode ^
+      segwitPercent.hashCode ^
+      taprootPercent.hashCode ^
+      missingPrevouts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlockFeeStatsInfo &&
+          runtimeType == other.runtimeType &&
+          height == other.height &&
+          hash == other.hash &&
+          txCount == other.txCount &&
+          totalFeesSats == other.totalFeesSats &&
+          minFeeRate == other.minFeeRate &&
+          medianFeeRate == other.medianFeeRate &&
+          maxFeeRate == other.maxFeeRate &&
+          feeRateHistogram == other.feeRateHistogram &&
+          segwitPercent == other.segwitPercent &&
+          taprootPercent == other.taprootPercent &&
+          missingPrevouts == other.missingPrevouts;
+}
+
+/// A block header, for the block explorer
+class BlockHeaderInfo {
+  final String hash;
+  final int height;
+  final String previousHash;
+  final String merkleRoot;
+  final int version;
+  /// UNIX timestamp from the header
+  final int timestamp;
+  final int bits;
+  final int nonce;
+  final double difficulty;
+
+  const BlockHeaderInfo({
+    required this.hash,
+    required this.height,
+    required this.previousHash,
+    required this.merkleRoot,
+    required this.version,
+    required this.timestamp,
+    required this.bits,
+    required this.nonce,
+    required this.difficulty,
+  });
+
+  @override
+  int get hashCode =>
+      hash.hashCode ^
+      height.hashCode ^
+      previousHash.hashCode ^
+      merkleRoot.hashCode ^
+      version.hashCode ^
+      timestamp.hashCode ^
+      bits.hashCode ^
+      nonce.hashCode ^
+      difficulty.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlockHeaderInfo &&
+          runtimeType == other.runtimeType &&
+          hash == other.hash &&
+          height == other.height &&
+          previousHash == other.previousHash &&
+          merkleRoot == other.merkleRoot &&
+          version == other.version &&
+          timestamp == other.timestamp &&
+          bits == other.bits &&
+          nonce == other.nonce &&
+          difficulty == other.difficulty;
+}
+
+/// A decoded block, for the block explorer
+class BlockInfo {
+  final BlockHeaderInfo header;
+  final int size;
+  final BigInt weight;
+  final int txCount;
+  final List<TransactionInfo> transactions;
+  /// New coins created by this block
+  final BigInt subsidySats;
+  /// Coinbase value above the subsidy, i.e. the fees the miner collected
+  final BigInt feesSats;
+  final BigInt coinbaseValueSats;
+
+  const BlockInfo({
+    required this.header,
+    required this.size,
+    required this.weight,
+    required this.txCount,
+    required this.transactions,
+    required this.subsidySats,
+    required this.feesSats,
+    required this.coinbaseValueSats,
+  });
+
+  @override
+  int get hashCode =>
+      header.hashCode ^
+      size.hashCode ^
+      weight.hashCode ^
+      txCount.hashCode ^
+      transactions.hashCode ^
+      subsidySats.hashCode ^
+      feesSats.hashCode ^
+      coinbaseValueSats.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlockInfo &&
+          runtimeType == other.runtimeType &&
+          header == other.header &&
+          size == other.size &&
+          weight == other.weight &&
+          txCount == other.txCount &&
+          transactions == other.transactions &&
+          subsidySats == other.subsidySats &&
+          feesSats == other.feesSats &&
+          coinbaseValueSats == other.coinbaseValueSats;
+}
+
+/// Metadata of a loaded GeoIP database
+class GeoIpDatabaseStatus {
+  /// "ASN" or "City"
+  final String kind;
+  final String path;
+  final String databaseType;
+  /// UNIX timestamp of when the database was built
+  final BigInt buildEpoch;
+  /// Nodes in the database's search tree, which grows with the number of networks it holds
+  final int searchTreeNodes;
+  final int ipVersion;
+  final String? description;
+
+  const GeoIpDatabaseStatus({
+    required this.kind,
+    required this.path,
+    required this.databaseType,
+    required this.buildEpoch,
+    required this.searchTreeNodes,
+    required this.ipVersion,
+    this.description,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      path.hashCode ^
+      databaseType.hashCode ^
+      buildEpoch.hashCode ^
+      searchTreeNodes.hashCode ^
+      ipVersion.hashCode ^
+      description.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GeoIpDatabaseStatus &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          path == other.path &&
+          databaseType == other.databaseType &&
+          buildEpoch == other.buildEpoch &&
+          searchTreeNodes == other.searchTreeNodes &&
+          ipVersion == other.ipVersion &&
+          description == other.description;
+}
+
+/// A confirmed wallet transaction, from the on-disk transaction index
+class IndexedTransactionInfo {
+  final String blockHash;
+  final int height;
+  /// Position in its block, the coinbase being 0
+  final int position;
+  final String rawTxHex;
+  final TransactionInfo transaction;
+
+  const IndexedTransactionInfo({
+    required this.blockHash,
+    required this.height,
+    required this.position,
+    required this.rawTxHex,
+    required this.transaction,
+  });
+
+  @override
+  int get hashCode =>
+      blockHash.hashCode ^
+      height.hashCode ^
+      position.hashCode ^
+      rawTxHex.hashCode ^
+      transaction.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IndexedTransactionInfo &&
+          runtimeType == other.runtimeType &&
+          blockHash == other.blockHash &&
+          height == other.height &&
+          position == other.position &&
+          rawTxHex == other.rawTxHex &&
+          transaction == other.transaction;
+}
+
+class InscriptionInfo {
+  /// e.g. "text/plain;charset=utf-8", "image/png"
+  final String? contentType;
+  final int contentLength;
+  /// The content, if it is text
+  final String? text;
+
+  const InscriptionInfo({
+    this.contentType,
+    required this.contentLength,
+    this.text,
+  });
+
+  @override
+  int get hashCode =>
+      contentType.hashCode ^ contentLength.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InscriptionInfo &&
+          runtimeType == other.runtimeType &&
+          contentType == other.contentType &&
+          contentLength == other.contentLength &&
+          text == other.text;
+}
+
 class NodeStats {
   final bool inIbd;
   final int headers;
@@ -75,6 +942,10 @@ class NodeStats {
   final BigInt uptimeSecs;
   final BigInt peersCount;
   final List<PeerDetailedInfo> peers;
+  /// Which fields could be fetched. A failed field keeps its default value.
+  final List<StatFieldStatus> fieldStatus;
+  /// UNIX timestamp of when these stats were sampled
+  final BigInt sampledAt;
 
   const NodeStats({
     required this.inIbd,
@@ -84,51 +955,243 @@ class NodeStats {
     required this.uptimeSecs,
     required this.peersCount,
     required this.peers,
+    required this.fieldStatus,
+    required this.sampledAt,
+  });
+
+  @override
+  int get hashCode =>
+      inIbd.hashCode ^
+      headers.hashCode ^
+      blocks.hashCode ^
+      userAgent.hashCode ^
+      uptimeSecs.hashCode ^
+      peersCount.hashCode ^
+      peers.hashCode ^
+      fieldStatus.hashCode ^
+      sampledAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NodeStats &&
+          runtimeType == other.runtimeType &&
+          inIbd == other.inIbd &&
+          headers == other.headers &&
+          blocks == other.blocks &&
+          userAgent == other.userAgent &&
+          uptimeSecs == other.uptimeSecs &&
+          peersCount == other.peersCount &&
+          peers == other.peers &&
+          fieldStatus == other.fieldStatus &&
+          sampledAt == other.sampledAt;
+}
+
+/// One point of the node's stats history
+class NodeStatsSample {
+  /// UNIX timestamp of the sample
+  final BigInt timestamp;
+  final int blocks;
+  final int headers;
+  final int peers;
+  final double blocksPerSec;
+
+  const NodeStatsSample({
+    required this.timestamp,
+    required this.blocks,
+    required this.headers,
+    required this.peers,
+    required this.blocksPerSec,
+  });
+
+  @override
+  int get hashCode =>
+      timestamp.hashCode ^
+      blocks.hashCode ^
+      headers.hashCode ^
+      peers.hashCode ^
+      blocksPerSec.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NodeStatsSample &&
+          runtimeType == other.runtimeType &&
+          timestamp == other.timestamp &&
+          blocks == other.blocks &&
+          headers == other.headers &&
+          peers == other.peers &&
+          blocksPerSec == other.blocksPerSec;
+}
+
+/// What an OP_RETURN output carries
+class OpReturnInfo {
+  final String dataHex;
+  /// The data, if it is printable text
+  final String? text;
+  /// e.g. "runes", "omni", "witness_commitment"
+  final String? protocol;
+
+  const OpReturnInfo({required this.dataHex, this.text, this.protocol});
+
+  @override
+  int get hashCode => dataHex.hashCode ^ text.hashCode ^ protocol.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OpReturnInfo &&
+          runtimeType == other.runtimeType &&
+          dataHex == other.dataHex &&
+          text == other.text &&
+          protocol == other.protocol;
+}
+
+/// Number of peers sharing a country, ASN, implementation or version
+class PeerCensusBucket {
+  final String key;
+  final String label;
+  final int peers;
+
+  const PeerCensusBucket({
+    required this.key,
+    required this.label,
+    required this.peers,
+  });
+
+  @override
+  int get hashCode => key.hashCode ^ label.hashCode ^ peers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerCensusBucket &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          label == other.label &&
+          peers == other.peers;
+}
+
+/// Summary of the connected peers for the metrics screen
+class PeerCensusInfo {
+  final int totalPeers;
+  final List<PeerCensusBucket> byCountry;
+  final List<PeerCensusBucket> byAsn;
+  final List<PeerCensusBucket> byImplementation;
+  final List<PeerCensusBucket> byVersion;
+  final int utreexoPeers;
+  final int compactFilterPeers;
+
+  const PeerCensusInfo({
+    required this.totalPeers,
+    required this.byCountry,
+    required this.byAsn,
+    required this.byImplementation,
+    required this.byVersion,
+    required this.utreexoPeers,
+    required this.compactFilterPeers,
   });
 
   @override
   int get hashCode =>
-      inIbd.hashCode ^
-      headers.hashCode ^
-      blocks.hashCode ^
-      userAgent.hashCode ^
-      uptimeSecs.hashCode ^
-      peersCount.hashCode ^
-      peers.hashCode;
+      totalPeers.hashCode ^
+      byCountry.hashCode ^
+      byAsn.hashCode ^
+      byImplementation.hashCode ^
+      byVersion.hashCode ^
+      utreexoPeers.hashCode ^
+      compactFilterPeers.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is NodeStats &&
+      other is PeerCensusInfo &&
           runtimeType == other.runtimeType &&
-          inIbd == other.inIbd &&
-          headers == other.headers &&
-          blocks == other.blocks &&
-          userAgent == other.userAgent &&
-          uptimeSecs == other.uptimeSecs &&
-          peersCount == other.peersCount &&
-          peers == other.peers;
+          totalPeers == other.totalPeers &&
+          byCountry == other.byCountry &&
+          byAsn == other.byAsn &&
+          byImplementation == other.byImplementation &&
+          byVersion == other.byVersion &&
+          utreexoPeers == other.utreexoPeers &&
+          compactFilterPeers == other.compactFilterPeers;
 }
 
 class PeerDetailedInfo {
   final String address;
   final String userAgent;
+  /// Implementation parsed from the user agent, e.g. "Bitcoin Core"
+  final String implementation;
   final int height;
+  /// Derived from the connection kind
   final bool isInbound;
+  final BigInt services;
+  final List<String> serviceNames;
+  /// Handshake state, e.g. "Ready"
+  final String status;
+  /// "Regular", "Feeler", "Extra" or "Manual"
+  final String connectionKind;
+  /// "v1" (plaintext) or "v2" (BIP-0324 encrypted)
+  final String transport;
+  /// UNIX timestamp of when the stats sampler first saw the peer connected. Not the connection
+  /// time: it starts over when the app restarts
+  final BigInt firstSeen;
+  /// Round trip of the last ping, `None` until the peer has answered one
+  final BigInt? pingMs;
+  final BigInt bytesSent;
+  final BigInt bytesReceived;
+  /// "public", "private", "loopback", "tor", "i2p" or "cjdns"
+  final String addressType;
+  final int? asn;
+  final String? asnOrganization;
+  final String? city;
+  final String? country;
+  final String? countryCode;
 
   const PeerDetailedInfo({
     required this.address,
     required this.userAgent,
+    required this.implementation,
     required this.height,
     required this.isInbound,
+    required this.services,
+    required this.serviceNames,
+    required this.status,
+    required this.connectionKind,
+    required this.transport,
+    required this.firstSeen,
+    this.pingMs,
+    required this.bytesSent,
+    required this.bytesReceived,
+    required this.addressType,
+    this.asn,
+    this.asnOrganization,
+    this.city,
+    this.country,
+    this.countryCode,
   });
 
   @override
   int get hashCode =>
       address.hashCode ^
       userAgent.hashCode ^
+      implementation.hashCode ^
       height.hashCode ^
-      isInbound.hashCode;
+      isInbound.hashCode ^
+      services.hashCode ^
+      serviceNames.hashCode ^
+      status.hashCode ^
+      connectionKind.hashCode ^
+      transport.hashCode ^
+      firstSeen.hashCode ^
+      pingMs.hashCode ^
+      bytesSent.hashCode ^
+      bytesReceived.hashCode ^
+      addressType.hashCode ^
+      asn.hashCode ^
+      asnOrganization.hashCode ^
+      city.hashCode ^
+      country.hashCode ^
+      countryCode.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -137,8 +1200,298 @@ class PeerDetailedInfo {
           runtimeType == other.runtimeType &&
           address == other.address &&
           userAgent == other.userAgent &&
+          implementation == other.implementation &&
+          height == other.height &&
+          isInbound == other.isInbound &&
+          services == other.services &&
+          serviceNames == other.serviceNames &&
+          status == other.status &&
+          connectionKind == other.connectionKind &&
+          transport == other.transport &&
+          firstSeen == other.firstSeen &&
+          pingMs == other.pingMs &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived &&
+          addressType == other.addressType &&
+          asn == other.asn &&
+          asnOrganization == other.asnOrganization &&
+          city == other.city &&
+          country == other.country &&
+          countryCode == other.countryCode;
+}
+
+/// What to do when outbound peers are concentrated in one ASN or country
+enum PeerDiversityAction { warn, rotate }
+
+/// Peer diversity of the running node
+class PeerDiversityInfo {
+  /// 0.0 when every outbound peer shares one ASN, approaching 1.0 as they spread out
+  final double score;
+  final int outboundPeers;
+  final List<PeerGroupShare> asnGroups;
+  final List<PeerGroupShare> countryGroups;
+  final List<String> concentratedPeers;
+  final List<String> rotatedPeers;
+  final List<String> warnings;
+
+  const PeerDiversityInfo({
+    required this.score,
+    required this.outboundPeers,
+    required this.asnGroups,
+    required this.countryGroups,
+    required this.concentratedPeers,
+    required this.rotatedPeers,
+    required this.warnings,
+  });
+
+  @override
+  int get hashCode =>
+      score.hashCode ^
+      outboundPeers.hashCode ^
+      asnGroups.hashCode ^
+      countryGroups.hashCode ^
+      concentratedPeers.hashCode ^
+      rotatedPeers.hashCode ^
+      warnings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerDiversityInfo &&
+          runtimeType == other.runtimeType &&
+          score == other.score &&
+          outboundPeers == other.outboundPeers &&
+          asnGroups == other.asnGroups &&
+          countryGroups == other.countryGroups &&
+          concentratedPeers == other.concentratedPeers &&
+          rotatedPeers == other.rotatedPeers &&
+          warnings == other.warnings;
+}
+
+/// Limits on how many outbound peers may share an ASN or a country
+class PeerDiversityPolicy {
+  /// Largest share (0.0 - 1.0) of outbound peers in a single ASN
+  final double maxAsnShare;
+  /// Largest share (0.0 - 1.0) of outbound peers in a single country
+  final double maxCountryShare;
+  final int minPeers;
+  final PeerDiversityAction action;
+
+  const PeerDiversityPolicy({
+    required this.maxAsnShare,
+    required this.maxCountryShare,
+    required this.minPeers,
+    required this.action,
+  });
+
+  @override
+  int get hashCode =>
+      maxAsnShare.hashCode ^
+      maxCountryShare.hashCode ^
+      minPeers.hashCode ^
+      action.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerDiversityPolicy &&
+          runtimeType == other.runtimeType &&
+          maxAsnShare == other.maxAsnShare &&
+          maxCountryShare == other.maxCountryShare &&
+          minPeers == other.minPeers &&
+          action == other.action;
+}
+
+/// Outbound peers in one ASN (key "AS1234") or country (key "US")
+class PeerGroupShare {
+  final String key;
+  final String label;
+  final int peers;
+  final double share;
+  final bool overLimit;
+
+  const PeerGroupShare({
+    required this.key,
+    required this.label,
+    required this.peers,
+    required this.share,
+    required this.overLimit,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^
+      label.hashCode ^
+      peers.hashCode ^
+      share.hashCode ^
+      overLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerGroupShare &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          label == other.label &&
+          peers == other.peers &&
+          share == other.share &&
+          overLimit == other.overLimit;
+}
+
+/// How far the QR scan has got
+class QrScanProgress {
+  /// `None` until the first frame is read
+  final String? urType;
+  /// 0.0 to 1.0
+  final double progress;
+  final int receivedFrames;
+  final int knownFragments;
+  final int fragmentCount;
+  final bool complete;
+  /// The payload, once complete
+  final Uint8List? data;
+
+  const QrScanProgress({
+    this.urType,
+    required this.progress,
+    required this.receivedFrames,
+    required this.knownFragments,
+    required this.fragmentCount,
+    required this.complete,
+    this.data,
+  });
+
+  @override
+  int get hashCode =>
+      urType.hashCode ^
+      progress.hashCode ^
+      receivedFrames.hashCode ^
+      knownFragments.hashCode ^
+      fragmentCount.hashCode ^
+      complete.hashCode ^
+      data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QrScanProgress &&
+          runtimeType == other.runtimeType &&
+          urType == other.urType &&
+          progress == other.progress &&
+          receivedFrames == other.receivedFrames &&
+          knownFragments == other.knownFragments &&
+          fragmentCount == other.fragmentCount &&
+          complete == other.complete &&
+          data == other.data;
+}
+
+/// A recent block on the best chain
+class RecentBlockInfo {
+  final int height;
+  final String hash;
+  /// UNIX timestamp from the header
+  final int time;
+  final int txCount;
+  final int size;
+  final BigInt weight;
+  final BigInt totalFeesSats;
+  /// Text the miner left in the coinbase, e.g. "/ViaBTC/"
+  final String? minerTag;
+
+  const RecentBlockInfo({
+    required this.height,
+    required this.hash,
+    required this.time,
+    required this.txCount,
+    required this.size,
+    required this.weight,
+    required this.totalFeesSats,
+    this.minerTag,
+  });
+
+  @override
+  int get hashCode =>
+      height.hashCode ^
+      hash.hashCode ^
+      time.hashCode ^
+      txCount.hashCode ^
+      size.hashCode ^
+      weight.hashCode ^
+      totalFeesSats.hashCode ^
+      minerTag.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecentBlockInfo &&
+          runtimeType == other.runtimeType &&
           height == other.height &&
-          isInbound == other.isInbound;
+          hash == other.hash &&
+          time == other.time &&
+          txCount == other.txCount &&
+          size == other.size &&
+          weight == other.weight &&
+          totalFeesSats == other.totalFeesSats &&
+          minerTag == other.minerTag;
+}
+
+class RecentBlocksPage {
+  final List<RecentBlockInfo> blocks;
+  /// How many recent blocks are kept in total
+  final int total;
+
+  const RecentBlocksPage({required this.blocks, required this.total});
+
+  @override
+  int get hashCode => blocks.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecentBlocksPage &&
+          runtimeType == other.runtimeType &&
+          blocks == other.blocks &&
+          total == other.total;
+}
+
+/// A chain reorganization seen by the node
+class ReorgInfo {
+  /// Height and hash of the last block both branches share
+  final int forkHeight;
+  final String forkHash;
+  /// How many blocks were disconnected
+  final int depth;
+  /// Hashes of the disconnected blocks, lowest first
+  final List<String> disconnectedHashes;
+  /// UNIX timestamp of when the reorg was detected
+  final BigInt detectedAt;
+
+  const ReorgInfo({
+    required this.forkHeight,
+    required this.forkHash,
+    required this.depth,
+    required this.disconnectedHashes,
+    required this.detectedAt,
+  });
+
+  @override
+  int get hashCode =>
+      forkHeight.hashCode ^
+      forkHash.hashCode ^
+      depth.hashCode ^
+      disconnectedHashes.hashCode ^
+      detectedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReorgInfo &&
+          runtimeType == other.runtimeType &&
+          forkHeight == other.forkHeight &&
+          forkHash == other.forkHash &&
+          depth == other.depth &&
+          disconnectedHashes == other.disconnectedHashes &&
+          detectedAt == other.detectedAt;
 }
 
 /// Send transaction result
@@ -160,6 +1513,293 @@ class SendTransactionResult {
           rawTxHex == other.rawTxHex;
 }
 
+/// Whether a field of [`NodeStats`] could be fetched, and why not
+class StatFieldStatus {
+  final String field;
+  final bool available;
+  final String? error;
+
+  const StatFieldStatus({
+    required this.field,
+    required this.available,
+    this.error,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ available.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StatFieldStatus &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          available == other.available &&
+          error == other.error;
+}
+
+/// What the node is currently syncing
+enum SyncPhaseKind {
+  connecting,
+  headers,
+  blocks,
+  /// Validating the blocks below the assume-utreexo snapshot
+  backfill,
+  /// Downloading compact filters
+  filters,
+  synced,
+}
+
+class SyncProgressInfo {
+  final SyncPhaseKind phase;
+  /// Percent complete of the current phase, weighted by transaction count on mainnet and by
+  /// block time on the test networks
+  final double percent;
+  /// Validated blocks over the target height, unweighted
+  final double heightPercent;
+  final int blocks;
+  final int headers;
+  /// The highest block with a downloaded compact filter
+  final int? filterHeight;
+  /// How far the assume-utreexo backfill has validated, while it runs
+  final int? backfillHeight;
+  final int targetHeight;
+  /// Blocks per second the current phase advanced at over the last minute
+  final double blocksPerSec;
+  /// Estimated seconds left in the current phase, while it advances
+  final BigInt? etaSecs;
+
+  const SyncProgressInfo({
+    required this.phase,
+    required this.percent,
+    required this.heightPercent,
+    required this.blocks,
+    required this.headers,
+    this.filterHeight,
+    this.backfillHeight,
+    required this.targetHeight,
+    required this.blocksPerSec,
+    this.etaSecs,
+  });
+
+  @override
+  int get hashCode =>
+      phase.hashCode ^
+      percent.hashCode ^
+      heightPercent.hashCode ^
+      blocks.hashCode ^
+      headers.hashCode ^
+      filterHeight.hashCode ^
+      backfillHeight.hashCode ^
+      targetHeight.hashCode ^
+      blocksPerSec.hashCode ^
+      etaSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncProgressInfo &&
+          runtimeType == other.runtimeType &&
+          phase == other.phase &&
+          percent == other.percent &&
+          heightPercent == other.heightPercent &&
+          blocks == other.blocks &&
+          headers == other.headers &&
+          filterHeight == other.filterHeight &&
+          backfillHeight == other.backfillHeight &&
+          targetHeight == other.targetHeight &&
+          blocksPerSec == other.blocksPerSec &&
+          etaSecs == other.etaSecs;
+}
+
+class TransactionInfo {
+  final String txid;
+  final bool isCoinbase;
+  final int size;
+  final int vsize;
+  final BigInt weight;
+  final List<TxInputInfo> inputs;
+  final List<TxOutputInfo> outputs;
+  final BigInt outputTotalSats;
+
+  const TransactionInfo({
+    required this.txid,
+    required this.isCoinbase,
+    required this.size,
+    required this.vsize,
+    required this.weight,
+    required this.inputs,
+    required this.outputs,
+    required this.outputTotalSats,
+  });
+
+  @override
+  int get hashCode =>
+      txid.hashCode ^
+      isCoinbase.hashCode ^
+      size.hashCode ^
+      vsize.hashCode ^
+      weight.hashCode ^
+      inputs.hashCode ^
+      outputs.hashCode ^
+      outputTotalSats.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TransactionInfo &&
+          runtimeType == other.runtimeType &&
+          txid == other.txid &&
+          isCoinbase == other.isCoinbase &&
+          size == other.size &&
+          vsize == other.vsize &&
+          weight == other.weight &&
+          inputs == other.inputs &&
+          outputs == other.outputs &&
+          outputTotalSats == other.outputTotalSats;
+}
+
+/// A trusted peer, as `host:port`, with its fallback order (lowest first)
+class TrustedPeerConfig {
+  final String address;
+  final int priority;
+
+  const TrustedPeerConfig({required this.address, required this.priority});
+
+  @override
+  int get hashCode => address.hashCode ^ priority.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrustedPeerConfig &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          priority == other.priority;
+}
+
+/// Whether the node may connect to peers other than the trusted ones
+enum TrustedPeerMode { prefer, only }
+
+/// Connection state of a trusted peer
+class TrustedPeerStatus {
+  final String address;
+  final int priority;
+  final String? resolvedAddress;
+  final bool isConnected;
+  final String? error;
+
+  const TrustedPeerStatus({
+    required this.address,
+    required this.priority,
+    this.resolvedAddress,
+    required this.isConnected,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      address.hashCode ^
+      priority.hashCode ^
+      resolvedAddress.hashCode ^
+      isConnected.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrustedPeerStatus &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          priority == other.priority &&
+          resolvedAddress == other.resolvedAddress &&
+          isConnected == other.isConnected &&
+          error == other.error;
+}
+
+class TxInputInfo {
+  /// `txid:vout` being spent, `None` for the coinbase input
+  final String? previousOutput;
+  final int sequence;
+  final int scriptSigSize;
+  final int witnessItems;
+  /// An inscription revealed by this input, if any
+  final InscriptionInfo? inscription;
+
+  const TxInputInfo({
+    this.previousOutput,
+    required this.sequence,
+    required this.scriptSigSize,
+    required this.witnessItems,
+    this.inscription,
+  });
+
+  @override
+  int get hashCode =>
+      previousOutput.hashCode ^
+      sequence.hashCode ^
+      scriptSigSize.hashCode ^
+      witnessItems.hashCode ^
+      inscription.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TxInputInfo &&
+          runtimeType == other.runtimeType &&
+          previousOutput == other.previousOutput &&
+          sequence == other.sequence &&
+          scriptSigSize == other.scriptSigSize &&
+          witnessItems == other.witnessItems &&
+          inscription == other.inscription;
+}
+
+class TxOutputInfo {
+  final BigInt valueSats;
+  /// e.g. "p2wpkh", "p2tr", "multisig", "op_return", "nonstandard"
+  final String scriptType;
+  /// `m` of a bare `m`-of-`n` multisig output
+  final int? multisigRequired;
+  /// `n` of a bare `m`-of-`n` multisig output
+  final int? multisigTotal;
+  final String? address;
+  final String scriptHex;
+  final OpReturnInfo? opReturn;
+
+  const TxOutputInfo({
+    required this.valueSats,
+    required this.scriptType,
+    this.multisigRequired,
+    this.multisigTotal,
+    this.address,
+    required this.scriptHex,
+    this.opReturn,
+  });
+
+  @override
+  int get hashCode =>
+      valueSats.hashCode ^
+      scriptType.hashCode ^
+      multisigRequired.hashCode ^
+      multisigTotal.hashCode ^
+      address.hashCode ^
+      scriptHex.hashCode ^
+      opReturn.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TxOutputInfo &&
+          runtimeType == other.runtimeType &&
+          valueSats == other.valueSats &&
+          scriptType == other.scriptType &&
+          multisigRequired == other.multisigRequired &&
+          multisigTotal == other.multisigTotal &&
+          address == other.address &&
+          scriptHex == other.scriptHex &&
+          opReturn == other.opReturn;
+}
+
 class WalletInfo {
   final BigInt balanceSats;
   final String address;
@@ -178,6 +1818,74 @@ class WalletInfo {
           address == other.address;
 }
 
+/// How a wallet rescan is going
+class WalletRescanProgress {
+  final int startHeight;
+  /// Next block to check
+  final int currentHeight;
+  final int tipHeight;
+  /// 0.0 - 1.0
+  final double progress;
+  final int matchedBlocks;
+  final int foundTransactions;
+  final int scriptCount;
+  final bool running;
+  /// Paused until the node has downloaded the filter of `current_height`
+  final bool waitingForFilters;
+  /// The highest downloaded filter, while waiting for filters
+  final int? filterHeight;
+  final bool cancelled;
+  final String? error;
+
+  const WalletRescanProgress({
+    required this.startHeight,
+    required this.currentHeight,
+    required this.tipHeight,
+    required this.progress,
+    required this.matchedBlocks,
+    required this.foundTransactions,
+    required this.scriptCount,
+    required this.running,
+    required this.waitingForFilters,
+    this.filterHeight,
+    required this.cancelled,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      startHeight.hashCode ^
+      currentHeight.hashCode ^
+      tipHeight.hashCode ^
+      progress.hashCode ^
+      matchedBlocks.hashCode ^
+      foundTransactions.hashCode ^
+      scriptCount.hashCode ^
+      running.hashCode ^
+      waitingForFilters.hashCode ^
+      filterHeight.hashCode ^
+      cancelled.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletRescanProgress &&
+          runtimeType == other.runtimeType &&
+          startHeight == other.startHeight &&
+          currentHeight == other.currentHeight &&
+          tipHeight == other.tipHeight &&
+          progress == other.progress &&
+          matchedBlocks == other.matchedBlocks &&
+          foundTransactions == other.foundTransactions &&
+          scriptCount == other.scriptCount &&
+          running == other.running &&
+          waitingForFilters == other.waitingForFilters &&
+          filterHeight == other.filterHeight &&
+          cancelled == other.cancelled &&
+          error == other.error;
+}
+
 /// Transaction info for Flutter
 class WalletTransactionInfo {
   final String txid;
@@ -221,3 +1929,91 @@ class WalletTransactionInfo {
           confirmationHeight == other.confirmationHeight &&
           timestamp == other.timestamp;
 }
+
+/// A script or address on the watchlist
+class WatchEntryInfo {
+  final String scriptHex;
+  final String? address;
+  /// e.g. "p2wpkh", "multisig", "op_return"
+  final String scriptType;
+  final String label;
+  /// UNIX timestamp
+  final BigInt addedAt;
+
+  const WatchEntryInfo({
+    required this.scriptHex,
+    this.address,
+    required this.scriptType,
+    required this.label,
+    required this.addedAt,
+  });
+
+  @override
+  int get hashCode =>
+      scriptHex.hashCode ^
+      address.hashCode ^
+      scriptType.hashCode ^
+      label.hashCode ^
+      addedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatchEntryInfo &&
+          runtimeType == other.runtimeType &&
+          scriptHex == other.scriptHex &&
+          address == other.address &&
+          scriptType == other.scriptType &&
+          label == other.label &&
+          addedAt == other.addedAt;
+}
+
+/// Activity of a watched script in a block
+class WatchMatchInfo {
+  final String scriptHex;
+  final String label;
+  final String txid;
+  final String blockHash;
+  final int height;
+  /// Paid to the script, rather than spent from it
+  final bool incoming;
+  final BigInt amountSats;
+  /// OP_RETURN data of the transaction, as text or hex
+  final String? memo;
+
+  const WatchMatchInfo({
+    required this.scriptHex,
+    required this.label,
+    required this.txid,
+    required this.blockHash,
+    required this.height,
+    required this.incoming,
+    required this.amountSats,
+    this.memo,
+  });
+
+  @override
+  int get hashCode =>
+      scriptHex.hashCode ^
+      label.hashCode ^
+      txid.hashCode ^
+      blockHash.hashCode ^
+      height.hashCode ^
+      incoming.hashCode ^
+      amountSats.hashCode ^
+      memo.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatchMatchInfo &&
+          runtimeType == other.runtimeType &&
+          scriptHex == other.scriptHex &&
+          label == other.label &&
+          txid == other.txid &&
+          blockHash == other.blockHash &&
+          height == other.height &&
+          incoming == other.incoming &&
+          amountSats == other.amountSats &&
+          memo == other.memo;
+}
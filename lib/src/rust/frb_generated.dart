@@ -9,6 +9,7 @@ import 'dart:convert';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'node/error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 /// Main entrypoint of the Rust API
@@ -66,7 +67,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1274970010;
+  int get rustContentHash => 626213667;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -77,41 +78,196 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<WatchEntryInfo> crateApiAddWatchAddress({
+    required String address,
+    required String label,
+  });
+
+  Future<WatchEntryInfo> crateApiAddWatchScript({
+    required String scriptHex,
+    required String label,
+  });
+
+  Future<void> crateApiBanPeer({
+    required String address,
+    required BigInt durationSecs,
+  });
+
+  Future<void> crateApiCancelQrScan();
+
+  Future<bool> crateApiCancelWalletRescan();
+
   Future<bool> crateApiCheckWalletExists({required String dataDir});
 
+  Future<void> crateApiClearBannedPeers();
+
+  Future<String> crateApiConnectPeer({required String address});
+
   Future<String> crateApiCreateWalletMnemonic({required String dataDir});
 
-  Future<NodeStats?> crateApiGetNodeStats();
+  Future<void> crateApiDisconnectNodePeer({required String address});
+
+  Future<AnimatedQr> crateApiEncodeAnimatedQr({
+    required List<int> data,
+    String? urType,
+    int? maxFragmentLen,
+    int? frameCount,
+  });
+
+  Future<AccumulatorPackageInfo> crateApiExportAccumulatorPackage();
+
+  Future<Float64List> crateApiFeeRateBucketBounds();
+
+  Future<AccumulatorInfo> crateApiGetAccumulatorInfo({required int historyLen});
+
+  Future<BlockInfo> crateApiGetBlock({required String query});
+
+  Future<List<String>> crateApiGetBlockConsumers();
+
+  Future<BlockFeeStatsInfo?> crateApiGetBlockFeeStats({required int height});
+
+  Future<BlockHeaderInfo> crateApiGetBlockHeader({required String query});
+
+  Future<List<BlockConsumerMetrics>> crateApiGetBlockPipelineMetrics();
+
+  Future<List<GeoIpDatabaseStatus>> crateApiGetGeoipDatabases();
+
+  Future<BigInt> crateApiGetNodeStartTime();
+
+  Future<NodeStats> crateApiGetNodeStats();
+
+  Future<List<NodeStatsSample>> crateApiGetNodeStatsHistory();
+
+  Future<PeerCensusInfo> crateApiGetPeerCensus();
+
+  Future<PeerDiversityInfo> crateApiGetPeerDiversity();
+
+  Future<PeerDiversityPolicy> crateApiGetPeerDiversityPolicy();
+
+  Future<List<BlockFeeStatsInfo>> crateApiGetRecentBlockFeeStats({
+    required int limit,
+  });
+
+  Future<RecentBlocksPage> crateApiGetRecentBlocks({
+    required int offset,
+    required int limit,
+  });
+
+  Future<List<ReorgInfo>> crateApiGetRecentReorgs();
+
+  Future<SyncProgressInfo> crateApiGetSyncProgress();
+
+  Future<IndexedTransactionInfo?> crateApiGetTransactionDetail({
+    required String txid,
+  });
+
+  Future<List<TrustedPeerConfig>> crateApiGetTrustedPeers({
+    required String dataDir,
+    required String network,
+  });
+
+  Future<List<TrustedPeerStatus>> crateApiGetTrustedPeersStatus();
 
   Future<WalletInfo?> crateApiGetWalletInfo();
 
   Future<String?> crateApiGetWalletMnemonic({required String dataDir});
 
+  Future<WalletRescanProgress?> crateApiGetWalletRescanProgress();
+
   Future<List<WalletTransactionInfo>> crateApiGetWalletTransactions();
 
+  Future<List<WatchMatchInfo>> crateApiGetWatchMatches({required int limit});
+
+  Future<List<WatchEntryInfo>> crateApiGetWatchlist();
+
+  Future<void> crateApiImportAccumulatorPackage({
+    required String package,
+    required bool acceptTrust,
+  });
+
+  Future<GeoIpDatabaseStatus> crateApiImportGeoipDatabase({
+    required String sourcePath,
+    required String geoipDir,
+  });
+
   Future<void> crateApiImportWalletMnemonic({
     required String dataDir,
     required String mnemonic,
+    BigInt? birthday,
   });
 
   Future<void> crateApiInitApp();
 
+  Future<AccumulatorImportPreview> crateApiInspectAccumulatorPackage({
+    required String package,
+  });
+
   Future<bool> crateApiIsNodeRunning();
 
+  Future<List<BannedPeerInfo>> crateApiListBannedPeers();
+
+  Future<GeoIpDatabaseStatus> crateApiLoadGeoipDatabase({required String path});
+
+  Future<List<GeoIpDatabaseStatus>> crateApiLoadGeoipDatabases({
+    required String dir,
+  });
+
+  Future<QrScanProgress> crateApiReceiveQrFrame({required String frame});
+
+  Future<bool> crateApiRemoveImportedAccumulator({
+    required String dataDir,
+    required String network,
+  });
+
+  Future<bool> crateApiRemoveWatch({required String scriptOrAddress});
+
+  Stream<ReorgInfo> crateApiReorgStream();
+
+  Future<void> crateApiResetNodeChainState({
+    required String dataDir,
+    required String network,
+  });
+
   Future<SendTransactionResult> crateApiSendTransaction({
     required String address,
     required BigInt amountSats,
     required double feeRate,
   });
 
+  Future<void> crateApiSetBlockConsumerEnabled({
+    required String name,
+    required bool enabled,
+  });
+
+  Future<void> crateApiSetPeerDiversityPolicy({
+    required PeerDiversityPolicy policy,
+  });
+
+  Future<void> crateApiSetTrustedPeers({
+    required String dataDir,
+    required String network,
+    required List<TrustedPeerConfig> peers,
+    required TrustedPeerMode mode,
+  });
+
   Future<void> crateApiStartNodeService({
     required String dataDir,
     required String network,
   });
 
+  Future<void> crateApiStartQrScan();
+
+  Future<int> crateApiStartWalletRescan({int? fromHeight});
+
   Future<void> crateApiStopNodeService();
 
   Future<int> crateApiSyncWallet();
+
+  Future<bool> crateApiUnbanPeer({required String address});
+
+  Future<void> crateApiUnloadGeoipDatabases();
+
+  Stream<WatchMatchInfo> crateApiWatchActivityStream();
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -123,12 +279,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<bool> crateApiCheckWalletExists({required String dataDir}) {
+  Future<WatchEntryInfo> crateApiAddWatchAddress({
+    required String address,
+    required String label,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
+          sse_encode_String(address, serializer);
+          sse_encode_String(label, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -137,28 +297,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_watch_entry_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
         ),
-        constMeta: kCrateApiCheckWalletExistsConstMeta,
-        argValues: [dataDir],
+        constMeta: kCrateApiAddWatchAddressConstMeta,
+        argValues: [address, label],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCheckWalletExistsConstMeta => const TaskConstMeta(
-    debugName: "check_wallet_exists",
-    argNames: ["dataDir"],
+  TaskConstMeta get kCrateApiAddWatchAddressConstMeta => const TaskConstMeta(
+    debugName: "add_watch_address",
+    argNames: ["address", "label"],
   );
 
   @override
-  Future<String> crateApiCreateWalletMnemonic({required String dataDir}) {
+  Future<WatchEntryInfo> crateApiAddWatchScript({
+    required String scriptHex,
+    required String label,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
+          sse_encode_String(scriptHex, serializer);
+          sse_encode_String(label, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -167,28 +331,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_watch_entry_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
         ),
-        constMeta: kCrateApiCreateWalletMnemonicConstMeta,
-        argValues: [dataDir],
+        constMeta: kCrateApiAddWatchScriptConstMeta,
+        argValues: [scriptHex, label],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCreateWalletMnemonicConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_wallet_mnemonic",
-        argNames: ["dataDir"],
-      );
+  TaskConstMeta get kCrateApiAddWatchScriptConstMeta => const TaskConstMeta(
+    debugName: "add_watch_script",
+    argNames: ["scriptHex", "label"],
+  );
 
   @override
-  Future<NodeStats?> crateApiGetNodeStats() {
+  Future<void> crateApiBanPeer({
+    required String address,
+    required BigInt durationSecs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(address, serializer);
+          sse_encode_u_64(durationSecs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -197,21 +365,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_node_stats,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bonsai_node_error,
         ),
-        constMeta: kCrateApiGetNodeStatsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBanPeerConstMeta,
+        argValues: [address, durationSecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetNodeStatsConstMeta =>
-      const TaskConstMeta(debugName: "get_node_stats", argNames: []);
+  TaskConstMeta get kCrateApiBanPeerConstMeta => const TaskConstMeta(
+    debugName: "ban_peer",
+    argNames: ["address", "durationSecs"],
+  );
 
   @override
-  Future<WalletInfo?> crateApiGetWalletInfo() {
+  Future<void> crateApiCancelQrScan() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -224,26 +394,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_wallet_info,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetWalletInfoConstMeta,
+        constMeta: kCrateApiCancelQrScanConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetWalletInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_wallet_info", argNames: []);
+  TaskConstMeta get kCrateApiCancelQrScanConstMeta =>
+      const TaskConstMeta(debugName: "cancel_qr_scan", argNames: []);
 
   @override
-  Future<String?> crateApiGetWalletMnemonic({required String dataDir}) {
+  Future<bool> crateApiCancelWalletRescan() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -252,27 +421,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetWalletMnemonicConstMeta,
-        argValues: [dataDir],
+        constMeta: kCrateApiCancelWalletRescanConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetWalletMnemonicConstMeta => const TaskConstMeta(
-    debugName: "get_wallet_mnemonic",
-    argNames: ["dataDir"],
-  );
+  TaskConstMeta get kCrateApiCancelWalletRescanConstMeta =>
+      const TaskConstMeta(debugName: "cancel_wallet_rescan", argNames: []);
 
   @override
-  Future<List<WalletTransactionInfo>> crateApiGetWalletTransactions() {
+  Future<bool> crateApiCheckWalletExists({required String dataDir}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -281,30 +449,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_wallet_transaction_info,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetWalletTransactionsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCheckWalletExistsConstMeta,
+        argValues: [dataDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetWalletTransactionsConstMeta =>
-      const TaskConstMeta(debugName: "get_wallet_transactions", argNames: []);
+  TaskConstMeta get kCrateApiCheckWalletExistsConstMeta => const TaskConstMeta(
+    debugName: "check_wallet_exists",
+    argNames: ["dataDir"],
+  );
 
   @override
-  Future<void> crateApiImportWalletMnemonic({
-    required String dataDir,
-    required String mnemonic,
-  }) {
+  Future<void> crateApiClearBannedPeers() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
-          sse_encode_String(mnemonic, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -314,27 +479,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_bonsai_node_error,
         ),
-        constMeta: kCrateApiImportWalletMnemonicConstMeta,
-        argValues: [dataDir, mnemonic],
+        constMeta: kCrateApiClearBannedPeersConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImportWalletMnemonicConstMeta =>
-      const TaskConstMeta(
-        debugName: "import_wallet_mnemonic",
-        argNames: ["dataDir", "mnemonic"],
-      );
+  TaskConstMeta get kCrateApiClearBannedPeersConstMeta =>
+      const TaskConstMeta(debugName: "clear_banned_peers", argNames: []);
 
   @override
-  Future<void> crateApiInitApp() {
+  Future<String> crateApiConnectPeer({required String address}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(address, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -343,25 +506,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bonsai_node_error,
         ),
-        constMeta: kCrateApiInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiConnectPeerConstMeta,
+        argValues: [address],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiConnectPeerConstMeta =>
+      const TaskConstMeta(debugName: "connect_peer", argNames: ["address"]);
 
   @override
-  Future<bool> crateApiIsNodeRunning() {
+  Future<String> crateApiCreateWalletMnemonic({required String dataDir}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -370,32 +534,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiIsNodeRunningConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCreateWalletMnemonicConstMeta,
+        argValues: [dataDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIsNodeRunningConstMeta =>
-      const TaskConstMeta(debugName: "is_node_running", argNames: []);
+  TaskConstMeta get kCrateApiCreateWalletMnemonicConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_wallet_mnemonic",
+        argNames: ["dataDir"],
+      );
 
   @override
-  Future<SendTransactionResult> crateApiSendTransaction({
-    required String address,
-    required BigInt amountSats,
-    required double feeRate,
-  }) {
+  Future<void> crateApiDisconnectNodePeer({required String address}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(address, serializer);
-          sse_encode_u_64(amountSats, serializer);
-          sse_encode_f_32(feeRate, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -404,32 +565,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_send_transaction_result,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bonsai_node_error,
         ),
-        constMeta: kCrateApiSendTransactionConstMeta,
-        argValues: [address, amountSats, feeRate],
+        constMeta: kCrateApiDisconnectNodePeerConstMeta,
+        argValues: [address],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSendTransactionConstMeta => const TaskConstMeta(
-    debugName: "send_transaction",
-    argNames: ["address", "amountSats", "feeRate"],
+  TaskConstMeta get kCrateApiDisconnectNodePeerConstMeta => const TaskConstMeta(
+    debugName: "disconnect_node_peer",
+    argNames: ["address"],
   );
 
   @override
-  Future<void> crateApiStartNodeService({
-    required String dataDir,
-    required String network,
+  Future<AnimatedQr> crateApiEncodeAnimatedQr({
+    required List<int> data,
+    String? urType,
+    int? maxFragmentLen,
+    int? frameCount,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
-          sse_encode_String(network, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_opt_String(urType, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxFragmentLen, serializer);
+          sse_encode_opt_box_autoadd_u_32(frameCount, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -438,23 +603,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_animated_qr,
+          decodeErrorData: sse_decode_bonsai_node_error,
         ),
-        constMeta: kCrateApiStartNodeServiceConstMeta,
-        argValues: [dataDir, network],
+        constMeta: kCrateApiEncodeAnimatedQrConstMeta,
+        argValues: [data, urType, maxFragmentLen, frameCount],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStartNodeServiceConstMeta => const TaskConstMeta(
-    debugName: "start_node_service",
-    argNames: ["dataDir", "network"],
+  TaskConstMeta get kCrateApiEncodeAnimatedQrConstMeta => const TaskConstMeta(
+    debugName: "encode_animated_qr",
+    argNames: ["data", "urType", "maxFragmentLen", "frameCount"],
   );
 
   @override
-  Future<void> crateApiStopNodeService() {
+  Future<AccumulatorPackageInfo> crateApiExportAccumulatorPackage() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -467,21 +632,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_accumulator_package_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
         ),
-        constMeta: kCrateApiStopNodeServiceConstMeta,
+        constMeta: kCrateApiExportAccumulatorPackageConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStopNodeServiceConstMeta =>
-      const TaskConstMeta(debugName: "stop_node_service", argNames: []);
+  TaskConstMeta get kCrateApiExportAccumulatorPackageConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_accumulator_package",
+        argNames: [],
+      );
 
   @override
-  Future<int> crateApiSyncWallet() {
+  Future<Float64List> crateApiFeeRateBucketBounds() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -494,634 +662,5230 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_f_64_strict,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSyncWalletConstMeta,
+        constMeta: kCrateApiFeeRateBucketBoundsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSyncWalletConstMeta =>
-      const TaskConstMeta(debugName: "sync_wallet", argNames: []);
+  TaskConstMeta get kCrateApiFeeRateBucketBoundsConstMeta =>
+      const TaskConstMeta(debugName: "fee_rate_bucket_bounds", argNames: []);
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  @override
+  Future<AccumulatorInfo> crateApiGetAccumulatorInfo({
+    required int historyLen,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(historyLen, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_accumulator_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetAccumulatorInfoConstMeta,
+        argValues: [historyLen],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateApiGetAccumulatorInfoConstMeta => const TaskConstMeta(
+    debugName: "get_accumulator_info",
+    argNames: ["historyLen"],
+  );
 
-  @protected
-  NodeStats dco_decode_box_autoadd_node_stats(dynamic raw) {
+  @override
+  Future<BlockInfo> crateApiGetBlock({required String query}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_block_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetBlockConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetBlockConstMeta =>
+      const TaskConstMeta(debugName: "get_block", argNames: ["query"]);
+
+  @override
+  Future<List<String>> crateApiGetBlockConsumers() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetBlockConsumersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetBlockConsumersConstMeta =>
+      const TaskConstMeta(debugName: "get_block_consumers", argNames: []);
+
+  @override
+  Future<BlockFeeStatsInfo?> crateApiGetBlockFeeStats({required int height}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(height, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_block_fee_stats_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetBlockFeeStatsConstMeta,
+        argValues: [height],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetBlockFeeStatsConstMeta => const TaskConstMeta(
+    debugName: "get_block_fee_stats",
+    argNames: ["height"],
+  );
+
+  @override
+  Future<BlockHeaderInfo> crateApiGetBlockHeader({required String query}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_block_header_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetBlockHeaderConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetBlockHeaderConstMeta =>
+      const TaskConstMeta(debugName: "get_block_header", argNames: ["query"]);
+
+  @override
+  Future<List<BlockConsumerMetrics>> crateApiGetBlockPipelineMetrics() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_block_consumer_metrics,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetBlockPipelineMetricsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetBlockPipelineMetricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_block_pipeline_metrics",
+        argNames: [],
+      );
+
+  @override
+  Future<List<GeoIpDatabaseStatus>> crateApiGetGeoipDatabases() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_geo_ip_database_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetGeoipDatabasesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetGeoipDatabasesConstMeta =>
+      const TaskConstMeta(debugName: "get_geoip_databases", argNames: []);
+
+  @override
+  Future<BigInt> crateApiGetNodeStartTime() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetNodeStartTimeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetNodeStartTimeConstMeta =>
+      const TaskConstMeta(debugName: "get_node_start_time", argNames: []);
+
+  @override
+  Future<NodeStats> crateApiGetNodeStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_node_stats,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetNodeStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetNodeStatsConstMeta =>
+      const TaskConstMeta(debugName: "get_node_stats", argNames: []);
+
+  @override
+  Future<List<NodeStatsSample>> crateApiGetNodeStatsHistory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_node_stats_sample,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetNodeStatsHistoryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetNodeStatsHistoryConstMeta =>
+      const TaskConstMeta(debugName: "get_node_stats_history", argNames: []);
+
+  @override
+  Future<PeerCensusInfo> crateApiGetPeerCensus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_peer_census_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetPeerCensusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetPeerCensusConstMeta =>
+      const TaskConstMeta(debugName: "get_peer_census", argNames: []);
+
+  @override
+  Future<PeerDiversityInfo> crateApiGetPeerDiversity() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_peer_diversity_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetPeerDiversityConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetPeerDiversityConstMeta =>
+      const TaskConstMeta(debugName: "get_peer_diversity", argNames: []);
+
+  @override
+  Future<PeerDiversityPolicy> crateApiGetPeerDiversityPolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_peer_diversity_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetPeerDiversityPolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetPeerDiversityPolicyConstMeta =>
+      const TaskConstMeta(debugName: "get_peer_diversity_policy", argNames: []);
+
+  @override
+  Future<List<BlockFeeStatsInfo>> crateApiGetRecentBlockFeeStats({
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_block_fee_stats_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetRecentBlockFeeStatsConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetRecentBlockFeeStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_recent_block_fee_stats",
+        argNames: ["limit"],
+      );
+
+  @override
+  Future<RecentBlocksPage> crateApiGetRecentBlocks({
+    required int offset,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(offset, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_recent_blocks_page,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetRecentBlocksConstMeta,
+        argValues: [offset, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetRecentBlocksConstMeta => const TaskConstMeta(
+    debugName: "get_recent_blocks",
+    argNames: ["offset", "limit"],
+  );
+
+  @override
+  Future<List<ReorgInfo>> crateApiGetRecentReorgs() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_reorg_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetRecentReorgsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetRecentReorgsConstMeta =>
+      const TaskConstMeta(debugName: "get_recent_reorgs", argNames: []);
+
+  @override
+  Future<SyncProgressInfo> crateApiGetSyncProgress() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_progress_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetSyncProgressConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetSyncProgressConstMeta =>
+      const TaskConstMeta(debugName: "get_sync_progress", argNames: []);
+
+  @override
+  Future<IndexedTransactionInfo?> crateApiGetTransactionDetail({
+    required String txid,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(txid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_opt_box_autoadd_indexed_transaction_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetTransactionDetailConstMeta,
+        argValues: [txid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetTransactionDetailConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_transaction_detail",
+        argNames: ["txid"],
+      );
+
+  @override
+  Future<List<TrustedPeerConfig>> crateApiGetTrustedPeers({
+    required String dataDir,
+    required String network,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(network, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_trusted_peer_config,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetTrustedPeersConstMeta,
+        argValues: [dataDir, network],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetTrustedPeersConstMeta => const TaskConstMeta(
+    debugName: "get_trusted_peers",
+    argNames: ["dataDir", "network"],
+  );
+
+  @override
+  Future<List<TrustedPeerStatus>> crateApiGetTrustedPeersStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_trusted_peer_status,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetTrustedPeersStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetTrustedPeersStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_trusted_peers_status", argNames: []);
+
+  @override
+  Future<WalletInfo?> crateApiGetWalletInfo() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_wallet_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetWalletInfoConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWalletInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_wallet_info", argNames: []);
+
+  @override
+  Future<String?> crateApiGetWalletMnemonic({required String dataDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetWalletMnemonicConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWalletMnemonicConstMeta => const TaskConstMeta(
+    debugName: "get_wallet_mnemonic",
+    argNames: ["dataDir"],
+  );
+
+  @override
+  Future<WalletRescanProgress?> crateApiGetWalletRescanProgress() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_wallet_rescan_progress,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetWalletRescanProgressConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWalletRescanProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_wallet_rescan_progress",
+        argNames: [],
+      );
+
+  @override
+  Future<List<WalletTransactionInfo>> crateApiGetWalletTransactions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_wallet_transaction_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetWalletTransactionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWalletTransactionsConstMeta =>
+      const TaskConstMeta(debugName: "get_wallet_transactions", argNames: []);
+
+  @override
+  Future<List<WatchMatchInfo>> crateApiGetWatchMatches({required int limit}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_watch_match_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetWatchMatchesConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWatchMatchesConstMeta =>
+      const TaskConstMeta(debugName: "get_watch_matches", argNames: ["limit"]);
+
+  @override
+  Future<List<WatchEntryInfo>> crateApiGetWatchlist() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_watch_entry_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiGetWatchlistConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWatchlistConstMeta =>
+      const TaskConstMeta(debugName: "get_watchlist", argNames: []);
+
+  @override
+  Future<void> crateApiImportAccumulatorPackage({
+    required String package,
+    required bool acceptTrust,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(package, serializer);
+          sse_encode_bool(acceptTrust, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiImportAccumulatorPackageConstMeta,
+        argValues: [package, acceptTrust],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImportAccumulatorPackageConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_accumulator_package",
+        argNames: ["package", "acceptTrust"],
+      );
+
+  @override
+  Future<GeoIpDatabaseStatus> crateApiImportGeoipDatabase({
+    required String sourcePath,
+    required String geoipDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sourcePath, serializer);
+          sse_encode_String(geoipDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_geo_ip_database_status,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiImportGeoipDatabaseConstMeta,
+        argValues: [sourcePath, geoipDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImportGeoipDatabaseConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_geoip_database",
+        argNames: ["sourcePath", "geoipDir"],
+      );
+
+  @override
+  Future<void> crateApiImportWalletMnemonic({
+    required String dataDir,
+    required String mnemonic,
+    BigInt? birthday,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(mnemonic, serializer);
+          sse_encode_opt_box_autoadd_u_64(birthday, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiImportWalletMnemonicConstMeta,
+        argValues: [dataDir, mnemonic, birthday],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImportWalletMnemonicConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_wallet_mnemonic",
+        argNames: ["dataDir", "mnemonic", "birthday"],
+      );
+
+  @override
+  Future<void> crateApiInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<AccumulatorImportPreview> crateApiInspectAccumulatorPackage({
+    required String package,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(package, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_accumulator_import_preview,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiInspectAccumulatorPackageConstMeta,
+        argValues: [package],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInspectAccumulatorPackageConstMeta =>
+      const TaskConstMeta(
+        debugName: "inspect_accumulator_package",
+        argNames: ["package"],
+      );
+
+  @override
+  Future<bool> crateApiIsNodeRunning() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsNodeRunningConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsNodeRunningConstMeta =>
+      const TaskConstMeta(debugName: "is_node_running", argNames: []);
+
+  @override
+  Future<List<BannedPeerInfo>> crateApiListBannedPeers() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_banned_peer_info,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiListBannedPeersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListBannedPeersConstMeta =>
+      const TaskConstMeta(debugName: "list_banned_peers", argNames: []);
+
+  @override
+  Future<GeoIpDatabaseStatus> crateApiLoadGeoipDatabase({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_geo_ip_database_status,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiLoadGeoipDatabaseConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadGeoipDatabaseConstMeta =>
+      const TaskConstMeta(debugName: "load_geoip_database", argNames: ["path"]);
+
+  @override
+  Future<List<GeoIpDatabaseStatus>> crateApiLoadGeoipDatabases({
+    required String dir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_geo_ip_database_status,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiLoadGeoipDatabasesConstMeta,
+        argValues: [dir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadGeoipDatabasesConstMeta =>
+      const TaskConstMeta(debugName: "load_geoip_databases", argNames: ["dir"]);
+
+  @override
+  Future<QrScanProgress> crateApiReceiveQrFrame({required String frame}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(frame, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_qr_scan_progress,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiReceiveQrFrameConstMeta,
+        argValues: [frame],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveQrFrameConstMeta =>
+      const TaskConstMeta(debugName: "receive_qr_frame", argNames: ["frame"]);
+
+  @override
+  Future<bool> crateApiRemoveImportedAccumulator({
+    required String dataDir,
+    required String network,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(network, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiRemoveImportedAccumulatorConstMeta,
+        argValues: [dataDir, network],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveImportedAccumulatorConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_imported_accumulator",
+        argNames: ["dataDir", "network"],
+      );
+
+  @override
+  Future<bool> crateApiRemoveWatch({required String scriptOrAddress}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(scriptOrAddress, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiRemoveWatchConstMeta,
+        argValues: [scriptOrAddress],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveWatchConstMeta => const TaskConstMeta(
+    debugName: "remove_watch",
+    argNames: ["scriptOrAddress"],
+  );
+
+  @override
+  Stream<ReorgInfo> crateApiReorgStream() {
+    final sink = RustStreamSink<ReorgInfo>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_reorg_info_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 52,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiReorgStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiReorgStreamConstMeta =>
+      const TaskConstMeta(debugName: "reorg_stream", argNames: ["sink"]);
+
+  @override
+  Future<void> crateApiResetNodeChainState({
+    required String dataDir,
+    required String network,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(network, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiResetNodeChainStateConstMeta,
+        argValues: [dataDir, network],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResetNodeChainStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_node_chain_state",
+        argNames: ["dataDir", "network"],
+      );
+
+  @override
+  Future<SendTransactionResult> crateApiSendTransaction({
+    required String address,
+    required BigInt amountSats,
+    required double feeRate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(address, serializer);
+          sse_encode_u_64(amountSats, serializer);
+          sse_encode_f_32(feeRate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_send_transaction_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSendTransactionConstMeta,
+        argValues: [address, amountSats, feeRate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSendTransactionConstMeta => const TaskConstMeta(
+    debugName: "send_transaction",
+    argNames: ["address", "amountSats", "feeRate"],
+  );
+
+  @override
+  Future<void> crateApiSetBlockConsumerEnabled({
+    required String name,
+    required bool enabled,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiSetBlockConsumerEnabledConstMeta,
+        argValues: [name, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetBlockConsumerEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_block_consumer_enabled",
+        argNames: ["name", "enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetPeerDiversityPolicy({
+    required PeerDiversityPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_peer_diversity_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiSetPeerDiversityPolicyConstMeta,
+        argValues: [policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetPeerDiversityPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_peer_diversity_policy",
+        argNames: ["policy"],
+      );
+
+  @override
+  Future<void> crateApiSetTrustedPeers({
+    required String dataDir,
+    required String network,
+    required List<TrustedPeerConfig> peers,
+    required TrustedPeerMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(network, serializer);
+          sse_encode_list_trusted_peer_config(peers, serializer);
+          sse_encode_trusted_peer_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiSetTrustedPeersConstMeta,
+        argValues: [dataDir, network, peers, mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetTrustedPeersConstMeta => const TaskConstMeta(
+    debugName: "set_trusted_peers",
+    argNames: ["dataDir", "network", "peers", "mode"],
+  );
+
+  @override
+  Future<void> crateApiStartNodeService({
+    required String dataDir,
+    required String network,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(network, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiStartNodeServiceConstMeta,
+        argValues: [dataDir, network],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartNodeServiceConstMeta => const TaskConstMeta(
+    debugName: "start_node_service",
+    argNames: ["dataDir", "network"],
+  );
+
+  @override
+  Future<void> crateApiStartQrScan() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiStartQrScanConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartQrScanConstMeta =>
+      const TaskConstMeta(debugName: "start_qr_scan", argNames: []);
+
+  @override
+  Future<int> crateApiStartWalletRescan({int? fromHeight}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(fromHeight, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiStartWalletRescanConstMeta,
+        argValues: [fromHeight],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartWalletRescanConstMeta => const TaskConstMeta(
+    debugName: "start_wallet_rescan",
+    argNames: ["fromHeight"],
+  );
+
+  @override
+  Future<void> crateApiStopNodeService() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiStopNodeServiceConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStopNodeServiceConstMeta =>
+      const TaskConstMeta(debugName: "stop_node_service", argNames: []);
+
+  @override
+  Future<int> crateApiSyncWallet() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSyncWalletConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncWalletConstMeta =>
+      const TaskConstMeta(debugName: "sync_wallet", argNames: []);
+
+  @override
+  Future<bool> crateApiUnbanPeer({required String address}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(address, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_bonsai_node_error,
+        ),
+        constMeta: kCrateApiUnbanPeerConstMeta,
+        argValues: [address],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUnbanPeerConstMeta =>
+      const TaskConstMeta(debugName: "unban_peer", argNames: ["address"]);
+
+  @override
+  Future<void> crateApiUnloadGeoipDatabases() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUnloadGeoipDatabasesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUnloadGeoipDatabasesConstMeta =>
+      const TaskConstMeta(debugName: "unload_geoip_databases", argNames: []);
+
+  @override
+  Stream<WatchMatchInfo> crateApiWatchActivityStream() {
+    final sink = RustStreamSink<WatchMatchInfo>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_watch_match_info_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 65,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWatchActivityStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWatchActivityStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_activity_stream",
+        argNames: ["sink"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<ReorgInfo> dco_decode_StreamSink_reorg_info_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<WatchMatchInfo> dco_decode_StreamSink_watch_match_info_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AccumulatorDeltaInfo dco_decode_accumulator_delta_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AccumulatorDeltaInfo(
+      height: dco_decode_u_32(arr[0]),
+      hash: dco_decode_String(arr[1]),
+      leavesAdded: dco_decode_u_32(arr[2]),
+      leavesDeleted: dco_decode_u_32(arr[3]),
+      leavesAfter: dco_decode_opt_box_autoadd_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  AccumulatorImportPreview dco_decode_accumulator_import_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AccumulatorImportPreview(
+      package: dco_decode_accumulator_package_info(arr[0]),
+      inHeaderChain: dco_decode_bool(arr[1]),
+      problem: dco_decode_opt_String(arr[2]),
+      trustWarning: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  AccumulatorInfo dco_decode_accumulator_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return AccumulatorInfo(
+      height: dco_decode_u_32(arr[0]),
+      blockHash: dco_decode_String(arr[1]),
+      leaves: dco_decode_u_64(arr[2]),
+      forestRows: dco_decode_u_8(arr[3]),
+      roots: dco_decode_list_accumulator_root_info(arr[4]),
+      rootsSizeBytes: dco_decode_u_32(arr[5]),
+      serializedSizeBytes: dco_decode_u_32(arr[6]),
+      commitment: dco_decode_String(arr[7]),
+      history: dco_decode_list_accumulator_delta_info(arr[8]),
+    );
+  }
+
+  @protected
+  AccumulatorPackageInfo dco_decode_accumulator_package_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AccumulatorPackageInfo(
+      package: dco_decode_String(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      blockHash: dco_decode_String(arr[2]),
+      leaves: dco_decode_u_64(arr[3]),
+      roots: dco_decode_list_String(arr[4]),
+      network: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  AccumulatorRootInfo dco_decode_accumulator_root_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AccumulatorRootInfo(
+      hash: dco_decode_String(arr[0]),
+      row: dco_decode_u_8(arr[1]),
+      position: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  AnimatedQr dco_decode_animated_qr(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AnimatedQr(
+      urType: dco_decode_String(arr[0]),
+      fragmentCount: dco_decode_u_32(arr[1]),
+      frames: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  BannedPeerInfo dco_decode_banned_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BannedPeerInfo(
+      address: dco_decode_String(arr[0]),
+      createdAt: dco_decode_u_64(arr[1]),
+      bannedUntil: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  BlockConsumerMetrics dco_decode_block_consumer_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return BlockConsumerMetrics(
+      name: dco_decode_String(arr[0]),
+      delivered: dco_decode_u_64(arr[1]),
+      processed: dco_decode_u_64(arr[2]),
+      lag: dco_decode_u_64(arr[3]),
+      lastHeight: dco_decode_u_32(arr[4]),
+      stalls: dco_decode_u_64(arr[5]),
+      avgProcessMicros: dco_decode_u_64(arr[6]),
+      catchingUp: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  BlockFeeStatsInfo dco_decode_block_fee_stats_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return BlockFeeStatsInfo(
+      height: dco_decode_u_32(arr[0]),
+      hash: dco_decode_String(arr[1]),
+      txCount: dco_decode_u_32(arr[2]),
+      totalFeesSats: dco_decode_u_64(arr[3]),
+      minFeeRate: dco_decode_f_64(arr[4]),
+      medianFeeRate: dco_decode_f_64(arr[5]),
+      maxFeeRate: dco_decode_f_64(arr[6]),
+      feeRateHistogram: dco_decode_list_prim_u_32_strict(arr[7]),
+      segwitPercent: dco_decode_f_64(arr[8]),
+      taprootPercent: dco_decode_f_64(arr[9]),
+      missingPrevouts: dco_decode_u_32(arr[10]),
+    );
+  }
+
+  @protected
+  BlockHeaderInfo dco_decode_block_header_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return BlockHeaderInfo(
+      hash: dco_decode_String(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      previousHash: dco_decode_String(arr[2]),
+      merkleRoot: dco_decode_String(arr[3]),
+      version: dco_decode_i_32(arr[4]),
+      timestamp: dco_decode_u_32(arr[5]),
+      bits: dco_decode_u_32(arr[6]),
+      nonce: dco_decode_u_32(arr[7]),
+      difficulty: dco_decode_f_64(arr[8]),
+    );
+  }
+
+  @protected
+  BlockInfo dco_decode_block_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return BlockInfo(
+      header: dco_decode_block_header_info(arr[0]),
+      size: dco_decode_u_32(arr[1]),
+      weight: dco_decode_u_64(arr[2]),
+      txCount: dco_decode_u_32(arr[3]),
+      transactions: dco_decode_list_transaction_info(arr[4]),
+      subsidySats: dco_decode_u_64(arr[5]),
+      feesSats: dco_decode_u_64(arr[6]),
+      coinbaseValueSats: dco_decode_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  BonsaiNodeError dco_decode_bonsai_node_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BonsaiNodeError(
+      kind: dco_decode_bonsai_node_error_kind(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  BonsaiNodeErrorKind dco_decode_bonsai_node_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BonsaiNodeErrorKind.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BlockFeeStatsInfo dco_decode_box_autoadd_block_fee_stats_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_block_fee_stats_info(raw);
+  }
+
+  @protected
+  IndexedTransactionInfo dco_decode_box_autoadd_indexed_transaction_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_indexed_transaction_info(raw);
+  }
+
+  @protected
+  InscriptionInfo dco_decode_box_autoadd_inscription_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_inscription_info(raw);
+  }
+
+  @protected
+  OpReturnInfo dco_decode_box_autoadd_op_return_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_op_return_info(raw);
+  }
+
+  @protected
+  PeerDiversityPolicy dco_decode_box_autoadd_peer_diversity_policy(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_peer_diversity_policy(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  WalletInfo dco_decode_box_autoadd_wallet_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_info(raw);
+  }
+
+  @protected
+  WalletRescanProgress dco_decode_box_autoadd_wallet_rescan_progress(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_rescan_progress(raw);
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  GeoIpDatabaseStatus dco_decode_geo_ip_database_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return GeoIpDatabaseStatus(
+      kind: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      databaseType: dco_decode_String(arr[2]),
+      buildEpoch: dco_decode_u_64(arr[3]),
+      searchTreeNodes: dco_decode_u_32(arr[4]),
+      ipVersion: dco_decode_u_16(arr[5]),
+      description: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  IndexedTransactionInfo dco_decode_indexed_transaction_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return IndexedTransactionInfo(
+      blockHash: dco_decode_String(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      position: dco_decode_u_32(arr[2]),
+      rawTxHex: dco_decode_String(arr[3]),
+      transaction: dco_decode_transaction_info(arr[4]),
+    );
+  }
+
+  @protected
+  InscriptionInfo dco_decode_inscription_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return InscriptionInfo(
+      contentType: dco_decode_opt_String(arr[0]),
+      contentLength: dco_decode_u_32(arr[1]),
+      text: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AccumulatorDeltaInfo> dco_decode_list_accumulator_delta_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_accumulator_delta_info)
+        .toList();
+  }
+
+  @protected
+  List<AccumulatorRootInfo> dco_decode_list_accumulator_root_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_accumulator_root_info)
+        .toList();
+  }
+
+  @protected
+  List<BannedPeerInfo> dco_decode_list_banned_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_banned_peer_info).toList();
+  }
+
+  @protected
+  List<BlockConsumerMetrics> dco_decode_list_block_consumer_metrics(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_block_consumer_metrics)
+        .toList();
+  }
+
+  @protected
+  List<BlockFeeStatsInfo> dco_decode_list_block_fee_stats_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_block_fee_stats_info).toList();
+  }
+
+  @protected
+  List<GeoIpDatabaseStatus> dco_decode_list_geo_ip_database_status(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_geo_ip_database_status)
+        .toList();
+  }
+
+  @protected
+  List<NodeStatsSample> dco_decode_list_node_stats_sample(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_node_stats_sample).toList();
+  }
+
+  @protected
+  List<PeerCensusBucket> dco_decode_list_peer_census_bucket(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_census_bucket).toList();
+  }
+
+  @protected
+  List<PeerDetailedInfo> dco_decode_list_peer_detailed_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_detailed_info).toList();
+  }
+
+  @protected
+  List<PeerGroupShare> dco_decode_list_peer_group_share(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_group_share).toList();
+  }
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float64List;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RecentBlockInfo> dco_decode_list_recent_block_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_recent_block_info).toList();
+  }
+
+  @protected
+  List<ReorgInfo> dco_decode_list_reorg_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reorg_info).toList();
+  }
+
+  @protected
+  List<StatFieldStatus> dco_decode_list_stat_field_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_stat_field_status).toList();
+  }
+
+  @protected
+  List<TransactionInfo> dco_decode_list_transaction_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_transaction_info).toList();
+  }
+
+  @protected
+  List<TrustedPeerConfig> dco_decode_list_trusted_peer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_trusted_peer_config).toList();
+  }
+
+  @protected
+  List<TrustedPeerStatus> dco_decode_list_trusted_peer_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_trusted_peer_status).toList();
+  }
+
+  @protected
+  List<TxInputInfo> dco_decode_list_tx_input_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tx_input_info).toList();
+  }
+
+  @protected
+  List<TxOutputInfo> dco_decode_list_tx_output_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tx_output_info).toList();
+  }
+
+  @protected
+  List<WalletTransactionInfo> dco_decode_list_wallet_transaction_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_wallet_transaction_info)
+        .toList();
+  }
+
+  @protected
+  List<WatchEntryInfo> dco_decode_list_watch_entry_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_watch_entry_info).toList();
+  }
+
+  @protected
+  List<WatchMatchInfo> dco_decode_list_watch_match_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_watch_match_info).toList();
+  }
+
+  @protected
+  NodeStats dco_decode_node_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return NodeStats(
+      inIbd: dco_decode_bool(arr[0]),
+      headers: dco_decode_u_32(arr[1]),
+      blocks: dco_decode_u_32(arr[2]),
+      userAgent: dco_decode_String(arr[3]),
+      uptimeSecs: dco_decode_u_64(arr[4]),
+      peersCount: dco_decode_usize(arr[5]),
+      peers: dco_decode_list_peer_detailed_info(arr[6]),
+      fieldStatus: dco_decode_list_stat_field_status(arr[7]),
+      sampledAt: dco_decode_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  NodeStatsSample dco_decode_node_stats_sample(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NodeStatsSample(
+      timestamp: dco_decode_u_64(arr[0]),
+      blocks: dco_decode_u_32(arr[1]),
+      headers: dco_decode_u_32(arr[2]),
+      peers: dco_decode_u_32(arr[3]),
+      blocksPerSec: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  OpReturnInfo dco_decode_op_return_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return OpReturnInfo(
+      dataHex: dco_decode_String(arr[0]),
+      text: dco_decode_opt_String(arr[1]),
+      protocol: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  BlockFeeStatsInfo? dco_decode_opt_box_autoadd_block_fee_stats_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_block_fee_stats_info(raw);
+  }
+
+  @protected
+  IndexedTransactionInfo? dco_decode_opt_box_autoadd_indexed_transaction_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_indexed_transaction_info(raw);
+  }
+
+  @protected
+  InscriptionInfo? dco_decode_opt_box_autoadd_inscription_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_inscription_info(raw);
+  }
+
+  @protected
+  OpReturnInfo? dco_decode_opt_box_autoadd_op_return_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_op_return_info(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  WalletInfo? dco_decode_opt_box_autoadd_wallet_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_wallet_info(raw);
+  }
+
+  @protected
+  WalletRescanProgress? dco_decode_opt_box_autoadd_wallet_rescan_progress(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_wallet_rescan_progress(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  PeerCensusBucket dco_decode_peer_census_bucket(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PeerCensusBucket(
+      key: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      peers: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  PeerCensusInfo dco_decode_peer_census_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PeerCensusInfo(
+      totalPeers: dco_decode_u_32(arr[0]),
+      byCountry: dco_decode_list_peer_census_bucket(arr[1]),
+      byAsn: dco_decode_list_peer_census_bucket(arr[2]),
+      byImplementation: dco_decode_list_peer_census_bucket(arr[3]),
+      byVersion: dco_decode_list_peer_census_bucket(arr[4]),
+      utreexoPeers: dco_decode_u_32(arr[5]),
+      compactFilterPeers: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  PeerDetailedInfo dco_decode_peer_detailed_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 20)
+      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    return PeerDetailedInfo(
+      address: dco_decode_String(arr[0]),
+      userAgent: dco_decode_String(arr[1]),
+      implementation: dco_decode_String(arr[2]),
+      height: dco_decode_u_32(arr[3]),
+      isInbound: dco_decode_bool(arr[4]),
+      services: dco_decode_u_64(arr[5]),
+      serviceNames: dco_decode_list_String(arr[6]),
+      status: dco_decode_String(arr[7]),
+      connectionKind: dco_decode_String(arr[8]),
+      transport: dco_decode_String(arr[9]),
+      firstSeen: dco_decode_u_64(arr[10]),
+      pingMs: dco_decode_opt_box_autoadd_u_64(arr[11]),
+      bytesSent: dco_decode_u_64(arr[12]),
+      bytesReceived: dco_decode_u_64(arr[13]),
+      addressType: dco_decode_String(arr[14]),
+      asn: dco_decode_opt_box_autoadd_u_32(arr[15]),
+      asnOrganization: dco_decode_opt_String(arr[16]),
+      city: dco_decode_opt_String(arr[17]),
+      country: dco_decode_opt_String(arr[18]),
+      countryCode: dco_decode_opt_String(arr[19]),
+    );
+  }
+
+  @protected
+  PeerDiversityAction dco_decode_peer_diversity_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PeerDiversityAction.values[raw as int];
+  }
+
+  @protected
+  PeerDiversityInfo dco_decode_peer_diversity_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PeerDiversityInfo(
+      score: dco_decode_f_64(arr[0]),
+      outboundPeers: dco_decode_u_32(arr[1]),
+      asnGroups: dco_decode_list_peer_group_share(arr[2]),
+      countryGroups: dco_decode_list_peer_group_share(arr[3]),
+      concentratedPeers: dco_decode_list_String(arr[4]),
+      rotatedPeers: dco_decode_list_String(arr[5]),
+      warnings: dco_decode_list_String(arr[6]),
+    );
+  }
+
+  @protected
+  PeerDiversityPolicy dco_decode_peer_diversity_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PeerDiversityPolicy(
+      maxAsnShare: dco_decode_f_64(arr[0]),
+      maxCountryShare: dco_decode_f_64(arr[1]),
+      minPeers: dco_decode_u_32(arr[2]),
+      action: dco_decode_peer_diversity_action(arr[3]),
+    );
+  }
+
+  @protected
+  PeerGroupShare dco_decode_peer_group_share(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PeerGroupShare(
+      key: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      peers: dco_decode_u_32(arr[2]),
+      share: dco_decode_f_64(arr[3]),
+      overLimit: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  QrScanProgress dco_decode_qr_scan_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return QrScanProgress(
+      urType: dco_decode_opt_String(arr[0]),
+      progress: dco_decode_f_64(arr[1]),
+      receivedFrames: dco_decode_u_32(arr[2]),
+      knownFragments: dco_decode_u_32(arr[3]),
+      fragmentCount: dco_decode_u_32(arr[4]),
+      complete: dco_decode_bool(arr[5]),
+      data: dco_decode_opt_list_prim_u_8_strict(arr[6]),
+    );
+  }
+
+  @protected
+  RecentBlockInfo dco_decode_recent_block_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return RecentBlockInfo(
+      height: dco_decode_u_32(arr[0]),
+      hash: dco_decode_String(arr[1]),
+      time: dco_decode_u_32(arr[2]),
+      txCount: dco_decode_u_32(arr[3]),
+      size: dco_decode_u_32(arr[4]),
+      weight: dco_decode_u_64(arr[5]),
+      totalFeesSats: dco_decode_u_64(arr[6]),
+      minerTag: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  RecentBlocksPage dco_decode_recent_blocks_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RecentBlocksPage(
+      blocks: dco_decode_list_recent_block_info(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ReorgInfo dco_decode_reorg_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReorgInfo(
+      forkHeight: dco_decode_u_32(arr[0]),
+      forkHash: dco_decode_String(arr[1]),
+      depth: dco_decode_u_32(arr[2]),
+      disconnectedHashes: dco_decode_list_String(arr[3]),
+      detectedAt: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  SendTransactionResult dco_decode_send_transaction_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SendTransactionResult(
+      txid: dco_decode_String(arr[0]),
+      rawTxHex: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  StatFieldStatus dco_decode_stat_field_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StatFieldStatus(
+      field: dco_decode_String(arr[0]),
+      available: dco_decode_bool(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  SyncPhaseKind dco_decode_sync_phase_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncPhaseKind.values[raw as int];
+  }
+
+  @protected
+  SyncProgressInfo dco_decode_sync_progress_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return SyncProgressInfo(
+      phase: dco_decode_sync_phase_kind(arr[0]),
+      percent: dco_decode_f_64(arr[1]),
+      heightPercent: dco_decode_f_64(arr[2]),
+      blocks: dco_decode_u_32(arr[3]),
+      headers: dco_decode_u_32(arr[4]),
+      filterHeight: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      backfillHeight: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      targetHeight: dco_decode_u_32(arr[7]),
+      blocksPerSec: dco_decode_f_64(arr[8]),
+      etaSecs: dco_decode_opt_box_autoadd_u_64(arr[9]),
+    );
+  }
+
+  @protected
+  TransactionInfo dco_decode_transaction_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return TransactionInfo(
+      txid: dco_decode_String(arr[0]),
+      isCoinbase: dco_decode_bool(arr[1]),
+      size: dco_decode_u_32(arr[2]),
+      vsize: dco_decode_u_32(arr[3]),
+      weight: dco_decode_u_64(arr[4]),
+      inputs: dco_decode_list_tx_input_info(arr[5]),
+      outputs: dco_decode_list_tx_output_info(arr[6]),
+      outputTotalSats: dco_decode_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  TrustedPeerConfig dco_decode_trusted_peer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TrustedPeerConfig(
+      address: dco_decode_String(arr[0]),
+      priority: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  TrustedPeerMode dco_decode_trusted_peer_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TrustedPeerMode.values[raw as int];
+  }
+
+  @protected
+  TrustedPeerStatus dco_decode_trusted_peer_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrustedPeerStatus(
+      address: dco_decode_String(arr[0]),
+      priority: dco_decode_u_32(arr[1]),
+      resolvedAddress: dco_decode_opt_String(arr[2]),
+      isConnected: dco_decode_bool(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  TxInputInfo dco_decode_tx_input_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TxInputInfo(
+      previousOutput: dco_decode_opt_String(arr[0]),
+      sequence: dco_decode_u_32(arr[1]),
+      scriptSigSize: dco_decode_u_32(arr[2]),
+      witnessItems: dco_decode_u_32(arr[3]),
+      inscription: dco_decode_opt_box_autoadd_inscription_info(arr[4]),
+    );
+  }
+
+  @protected
+  TxOutputInfo dco_decode_tx_output_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return TxOutputInfo(
+      valueSats: dco_decode_u_64(arr[0]),
+      scriptType: dco_decode_String(arr[1]),
+      multisigRequired: dco_decode_opt_box_autoadd_u_8(arr[2]),
+      multisigTotal: dco_decode_opt_box_autoadd_u_8(arr[3]),
+      address: dco_decode_opt_String(arr[4]),
+      scriptHex: dco_decode_String(arr[5]),
+      opReturn: dco_decode_opt_box_autoadd_op_return_info(arr[6]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WalletInfo dco_decode_wallet_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletInfo(
+      balanceSats: dco_decode_u_64(arr[0]),
+      address: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  WalletRescanProgress dco_decode_wallet_rescan_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return WalletRescanProgress(
+      startHeight: dco_decode_u_32(arr[0]),
+      currentHeight: dco_decode_u_32(arr[1]),
+      tipHeight: dco_decode_u_32(arr[2]),
+      progress: dco_decode_f_64(arr[3]),
+      matchedBlocks: dco_decode_u_32(arr[4]),
+      foundTransactions: dco_decode_u_32(arr[5]),
+      scriptCount: dco_decode_u_32(arr[6]),
+      running: dco_decode_bool(arr[7]),
+      waitingForFilters: dco_decode_bool(arr[8]),
+      filterHeight: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      cancelled: dco_decode_bool(arr[10]),
+      error: dco_decode_opt_String(arr[11]),
+    );
+  }
+
+  @protected
+  WalletTransactionInfo dco_decode_wallet_transaction_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return WalletTransactionInfo(
+      txid: dco_decode_String(arr[0]),
+      sent: dco_decode_u_64(arr[1]),
+      received: dco_decode_u_64(arr[2]),
+      fee: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      isConfirmed: dco_decode_bool(arr[4]),
+      confirmationHeight: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      timestamp: dco_decode_opt_box_autoadd_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  WatchEntryInfo dco_decode_watch_entry_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WatchEntryInfo(
+      scriptHex: dco_decode_String(arr[0]),
+      address: dco_decode_opt_String(arr[1]),
+      scriptType: dco_decode_String(arr[2]),
+      label: dco_decode_String(arr[3]),
+      addedAt: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  WatchMatchInfo dco_decode_watch_match_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_node_stats(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return WatchMatchInfo(
+      scriptHex: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      txid: dco_decode_String(arr[2]),
+      blockHash: dco_decode_String(arr[3]),
+      height: dco_decode_u_32(arr[4]),
+      incoming: dco_decode_bool(arr[5]),
+      amountSats: dco_decode_u_64(arr[6]),
+      memo: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<ReorgInfo> sse_decode_StreamSink_reorg_info_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<WatchMatchInfo> sse_decode_StreamSink_watch_match_info_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AccumulatorDeltaInfo sse_decode_accumulator_delta_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_height = sse_decode_u_32(deserializer);
+    var var_hash = sse_decode_String(deserializer);
+    var var_leavesAdded = sse_decode_u_32(deserializer);
+    var var_leavesDeleted = sse_decode_u_32(deserializer);
+    var var_leavesAfter = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return AccumulatorDeltaInfo(
+      height: var_height,
+      hash: var_hash,
+      leavesAdded: var_leavesAdded,
+      leavesDeleted: var_leavesDeleted,
+      leavesAfter: var_leavesAfter,
+    );
+  }
+
+  @protected
+  AccumulatorImportPreview sse_decode_accumulator_import_preview(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_package = sse_decode_accumulator_package_info(deserializer);
+    var var_inHeaderChain = sse_decode_bool(deserializer);
+    var var_problem = sse_decode_opt_String(deserializer);
+    var var_trustWarning = sse_decode_String(deserializer);
+    return AccumulatorImportPreview(
+      package: var_package,
+      inHeaderChain: var_inHeaderChain,
+      problem: var_problem,
+      trustWarning: var_trustWarning,
+    );
+  }
+
+  @protected
+  AccumulatorInfo sse_decode_accumulator_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_height = sse_decode_u_32(deserializer);
+    var var_blockHash = sse_decode_String(deserializer);
+    var var_leaves = sse_decode_u_64(deserializer);
+    var var_forestRows = sse_decode_u_8(deserializer);
+    var var_roots = sse_decode_list_accumulator_root_info(deserializer);
+    var var_rootsSizeBytes = sse_decode_u_32(deserializer);
+    var var_serializedSizeBytes = sse_decode_u_32(deserializer);
+    var var_commitment = sse_decode_String(deserializer);
+    var var_history = sse_decode_list_accumulator_delta_info(deserializer);
+    return AccumulatorInfo(
+      height: var_height,
+      blockHash: var_blockHash,
+      leaves: var_leaves,
+      forestRows: var_forestRows,
+      roots: var_roots,
+      rootsSizeBytes: var_rootsSizeBytes,
+      serializedSizeBytes: var_serializedSizeBytes,
+      commitment: var_commitment,
+      history: var_history,
+    );
+  }
+
+  @protected
+  AccumulatorPackageInfo sse_decode_accumulator_package_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_package = sse_decode_String(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_blockHash = sse_decode_String(deserializer);
+    var var_leaves = sse_decode_u_64(deserializer);
+    var var_roots = sse_decode_list_String(deserializer);
+    var var_network = sse_decode_opt_String(deserializer);
+    return AccumulatorPackageInfo(
+      package: var_package,
+      height: var_height,
+      blockHash: var_blockHash,
+      leaves: var_leaves,
+      roots: var_roots,
+      network: var_network,
+    );
+  }
+
+  @protected
+  AccumulatorRootInfo sse_decode_accumulator_root_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hash = sse_decode_String(deserializer);
+    var var_row = sse_decode_u_8(deserializer);
+    var var_position = sse_decode_u_64(deserializer);
+    return AccumulatorRootInfo(
+      hash: var_hash,
+      row: var_row,
+      position: var_position,
+    );
+  }
+
+  @protected
+  AnimatedQr sse_decode_animated_qr(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_urType = sse_decode_String(deserializer);
+    var var_fragmentCount = sse_decode_u_32(deserializer);
+    var var_frames = sse_decode_list_String(deserializer);
+    return AnimatedQr(
+      urType: var_urType,
+      fragmentCount: var_fragmentCount,
+      frames: var_frames,
+    );
+  }
+
+  @protected
+  BannedPeerInfo sse_decode_banned_peer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_u_64(deserializer);
+    var var_bannedUntil = sse_decode_u_64(deserializer);
+    return BannedPeerInfo(
+      address: var_address,
+      createdAt: var_createdAt,
+      bannedUntil: var_bannedUntil,
+    );
+  }
+
+  @protected
+  BlockConsumerMetrics sse_decode_block_consumer_metrics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_delivered = sse_decode_u_64(deserializer);
+    var var_processed = sse_decode_u_64(deserializer);
+    var var_lag = sse_decode_u_64(deserializer);
+    var var_lastHeight = sse_decode_u_32(deserializer);
+    var var_stalls = sse_decode_u_64(deserializer);
+    var var_avgProcessMicros = sse_decode_u_64(deserializer);
+    var var_catchingUp = sse_decode_bool(deserializer);
+    return BlockConsumerMetrics(
+      name: var_name,
+      delivered: var_delivered,
+      processed: var_processed,
+      lag: var_lag,
+      lastHeight: var_lastHeight,
+      stalls: var_stalls,
+      avgProcessMicros: var_avgProcessMicros,
+      catchingUp: var_catchingUp,
+    );
+  }
+
+  @protected
+  BlockFeeStatsInfo sse_decode_block_fee_stats_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_height = sse_decode_u_32(deserializer);
+    var var_hash = sse_decode_String(deserializer);
+    var var_txCount = sse_decode_u_32(deserializer);
+    var var_totalFeesSats = sse_decode_u_64(deserializer);
+    var var_minFeeRate = sse_decode_f_64(deserializer);
+    var var_medianFeeRate = sse_decode_f_64(deserializer);
+    var var_maxFeeRate = sse_decode_f_64(deserializer);
+    var var_feeRateHistogram = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_segwitPercent = sse_decode_f_64(deserializer);
+    var var_taprootPercent = sse_decode_f_64(deserializer);
+    var var_missingPrevouts = sse_decode_u_32(deserializer);
+    return BlockFeeStatsInfo(
+      height: var_height,
+      hash: var_hash,
+      txCount: var_txCount,
+      totalFeesSats: var_totalFeesSats,
+      minFeeRate: var_minFeeRate,
+      medianFeeRate: var_medianFeeRate,
+      maxFeeRate: var_maxFeeRate,
+      feeRateHistogram: var_feeRateHistogram,
+      segwitPercent: var_segwitPercent,
+      taprootPercent: var_taprootPercent,
+      missingPrevouts: var_missingPrevouts,
+    );
+  }
+
+  @protected
+  BlockHeaderInfo sse_decode_block_header_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hash = sse_decode_String(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_previousHash = sse_decode_String(deserializer);
+    var var_merkleRoot = sse_decode_String(deserializer);
+    var var_version = sse_decode_i_32(deserializer);
+    var var_timestamp = sse_decode_u_32(deserializer);
+    var var_bits = sse_decode_u_32(deserializer);
+    var var_nonce = sse_decode_u_32(deserializer);
+    var var_difficulty = sse_decode_f_64(deserializer);
+    return BlockHeaderInfo(
+      hash: var_hash,
+      height: var_height,
+      previousHash: var_previousHash,
+      merkleRoot: var_merkleRoot,
+      version: var_version,
+      timestamp: var_timestamp,
+      bits: var_bits,
+      nonce: var_nonce,
+      difficulty: var_difficulty,
+    );
+  }
+
+  @protected
+  BlockInfo sse_decode_block_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_header = sse_decode_block_header_info(deserializer);
+    var var_size = sse_decode_u_32(deserializer);
+    var var_weight = sse_decode_u_64(deserializer);
+    var var_txCount = sse_decode_u_32(deserializer);
+    var var_transactions = sse_decode_list_transaction_info(deserializer);
+    var var_subsidySats = sse_decode_u_64(deserializer);
+    var var_feesSats = sse_decode_u_64(deserializer);
+    var var_coinbaseValueSats = sse_decode_u_64(deserializer);
+    return BlockInfo(
+      header: var_header,
+      size: var_size,
+      weight: var_weight,
+      txCount: var_txCount,
+      transactions: var_transactions,
+      subsidySats: var_subsidySats,
+      feesSats: var_feesSats,
+      coinbaseValueSats: var_coinbaseValueSats,
+    );
+  }
+
+  @protected
+  BonsaiNodeError sse_decode_bonsai_node_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_bonsai_node_error_kind(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return BonsaiNodeError(kind: var_kind, message: var_message);
+  }
+
+  @protected
+  BonsaiNodeErrorKind sse_decode_bonsai_node_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BonsaiNodeErrorKind.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  BlockFeeStatsInfo sse_decode_box_autoadd_block_fee_stats_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_block_fee_stats_info(deserializer));
+  }
+
+  @protected
+  IndexedTransactionInfo sse_decode_box_autoadd_indexed_transaction_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_indexed_transaction_info(deserializer));
+  }
+
+  @protected
+  InscriptionInfo sse_decode_box_autoadd_inscription_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_inscription_info(deserializer));
+  }
+
+  @protected
+  OpReturnInfo sse_decode_box_autoadd_op_return_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_op_return_info(deserializer));
+  }
+
+  @protected
+  PeerDiversityPolicy sse_decode_box_autoadd_peer_diversity_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_peer_diversity_policy(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_8(deserializer));
+  }
+
+  @protected
+  WalletInfo sse_decode_box_autoadd_wallet_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_info(deserializer));
+  }
+
+  @protected
+  WalletRescanProgress sse_decode_box_autoadd_wallet_rescan_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_rescan_progress(deserializer));
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  GeoIpDatabaseStatus sse_decode_geo_ip_database_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_databaseType = sse_decode_String(deserializer);
+    var var_buildEpoch = sse_decode_u_64(deserializer);
+    var var_searchTreeNodes = sse_decode_u_32(deserializer);
+    var var_ipVersion = sse_decode_u_16(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    return GeoIpDatabaseStatus(
+      kind: var_kind,
+      path: var_path,
+      databaseType: var_databaseType,
+      buildEpoch: var_buildEpoch,
+      searchTreeNodes: var_searchTreeNodes,
+      ipVersion: var_ipVersion,
+      description: var_description,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  IndexedTransactionInfo sse_decode_indexed_transaction_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blockHash = sse_decode_String(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_position = sse_decode_u_32(deserializer);
+    var var_rawTxHex = sse_decode_String(deserializer);
+    var var_transaction = sse_decode_transaction_info(deserializer);
+    return IndexedTransactionInfo(
+      blockHash: var_blockHash,
+      height: var_height,
+      position: var_position,
+      rawTxHex: var_rawTxHex,
+      transaction: var_transaction,
+    );
+  }
+
+  @protected
+  InscriptionInfo sse_decode_inscription_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_contentType = sse_decode_opt_String(deserializer);
+    var var_contentLength = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_opt_String(deserializer);
+    return InscriptionInfo(
+      contentType: var_contentType,
+      contentLength: var_contentLength,
+      text: var_text,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AccumulatorDeltaInfo> sse_decode_list_accumulator_delta_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccumulatorDeltaInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_accumulator_delta_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AccumulatorRootInfo> sse_decode_list_accumulator_root_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccumulatorRootInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_accumulator_root_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BannedPeerInfo> sse_decode_list_banned_peer_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BannedPeerInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_banned_peer_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BlockConsumerMetrics> sse_decode_list_block_consumer_metrics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BlockConsumerMetrics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_block_consumer_metrics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BlockFeeStatsInfo> sse_decode_list_block_fee_stats_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BlockFeeStatsInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_block_fee_stats_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GeoIpDatabaseStatus> sse_decode_list_geo_ip_database_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GeoIpDatabaseStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_geo_ip_database_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NodeStatsSample> sse_decode_list_node_stats_sample(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NodeStatsSample>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_node_stats_sample(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerCensusBucket> sse_decode_list_peer_census_bucket(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerCensusBucket>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_census_bucket(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerDetailedInfo> sse_decode_list_peer_detailed_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerDetailedInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_detailed_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerGroupShare> sse_decode_list_peer_group_share(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerGroupShare>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_group_share(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RecentBlockInfo> sse_decode_list_recent_block_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RecentBlockInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_recent_block_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReorgInfo> sse_decode_list_reorg_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReorgInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reorg_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<StatFieldStatus> sse_decode_list_stat_field_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StatFieldStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_stat_field_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TransactionInfo> sse_decode_list_transaction_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TransactionInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_transaction_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrustedPeerConfig> sse_decode_list_trusted_peer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrustedPeerConfig>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_trusted_peer_config(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrustedPeerStatus> sse_decode_list_trusted_peer_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrustedPeerStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_trusted_peer_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TxInputInfo> sse_decode_list_tx_input_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TxInputInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tx_input_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TxOutputInfo> sse_decode_list_tx_output_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TxOutputInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tx_output_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WalletTransactionInfo> sse_decode_list_wallet_transaction_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WalletTransactionInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_wallet_transaction_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WatchEntryInfo> sse_decode_list_watch_entry_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WatchEntryInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_watch_entry_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WatchMatchInfo> sse_decode_list_watch_match_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WatchMatchInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_watch_match_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  NodeStats sse_decode_node_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_inIbd = sse_decode_bool(deserializer);
+    var var_headers = sse_decode_u_32(deserializer);
+    var var_blocks = sse_decode_u_32(deserializer);
+    var var_userAgent = sse_decode_String(deserializer);
+    var var_uptimeSecs = sse_decode_u_64(deserializer);
+    var var_peersCount = sse_decode_usize(deserializer);
+    var var_peers = sse_decode_list_peer_detailed_info(deserializer);
+    var var_fieldStatus = sse_decode_list_stat_field_status(deserializer);
+    var var_sampledAt = sse_decode_u_64(deserializer);
+    return NodeStats(
+      inIbd: var_inIbd,
+      headers: var_headers,
+      blocks: var_blocks,
+      userAgent: var_userAgent,
+      uptimeSecs: var_uptimeSecs,
+      peersCount: var_peersCount,
+      peers: var_peers,
+      fieldStatus: var_fieldStatus,
+      sampledAt: var_sampledAt,
+    );
+  }
+
+  @protected
+  NodeStatsSample sse_decode_node_stats_sample(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_blocks = sse_decode_u_32(deserializer);
+    var var_headers = sse_decode_u_32(deserializer);
+    var var_peers = sse_decode_u_32(deserializer);
+    var var_blocksPerSec = sse_decode_f_64(deserializer);
+    return NodeStatsSample(
+      timestamp: var_timestamp,
+      blocks: var_blocks,
+      headers: var_headers,
+      peers: var_peers,
+      blocksPerSec: var_blocksPerSec,
+    );
+  }
+
+  @protected
+  OpReturnInfo sse_decode_op_return_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dataHex = sse_decode_String(deserializer);
+    var var_text = sse_decode_opt_String(deserializer);
+    var var_protocol = sse_decode_opt_String(deserializer);
+    return OpReturnInfo(
+      dataHex: var_dataHex,
+      text: var_text,
+      protocol: var_protocol,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BlockFeeStatsInfo? sse_decode_opt_box_autoadd_block_fee_stats_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_block_fee_stats_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  IndexedTransactionInfo? sse_decode_opt_box_autoadd_indexed_transaction_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_indexed_transaction_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  InscriptionInfo? sse_decode_opt_box_autoadd_inscription_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_inscription_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OpReturnInfo? sse_decode_opt_box_autoadd_op_return_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_op_return_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_8(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WalletInfo? sse_decode_opt_box_autoadd_wallet_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_wallet_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WalletRescanProgress? sse_decode_opt_box_autoadd_wallet_rescan_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_wallet_rescan_progress(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PeerCensusBucket sse_decode_peer_census_bucket(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_peers = sse_decode_u_32(deserializer);
+    return PeerCensusBucket(key: var_key, label: var_label, peers: var_peers);
+  }
+
+  @protected
+  PeerCensusInfo sse_decode_peer_census_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalPeers = sse_decode_u_32(deserializer);
+    var var_byCountry = sse_decode_list_peer_census_bucket(deserializer);
+    var var_byAsn = sse_decode_list_peer_census_bucket(deserializer);
+    var var_byImplementation = sse_decode_list_peer_census_bucket(deserializer);
+    var var_byVersion = sse_decode_list_peer_census_bucket(deserializer);
+    var var_utreexoPeers = sse_decode_u_32(deserializer);
+    var var_compactFilterPeers = sse_decode_u_32(deserializer);
+    return PeerCensusInfo(
+      totalPeers: var_totalPeers,
+      byCountry: var_byCountry,
+      byAsn: var_byAsn,
+      byImplementation: var_byImplementation,
+      byVersion: var_byVersion,
+      utreexoPeers: var_utreexoPeers,
+      compactFilterPeers: var_compactFilterPeers,
+    );
+  }
+
+  @protected
+  PeerDetailedInfo sse_decode_peer_detailed_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_userAgent = sse_decode_String(deserializer);
+    var var_implementation = sse_decode_String(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_isInbound = sse_decode_bool(deserializer);
+    var var_services = sse_decode_u_64(deserializer);
+    var var_serviceNames = sse_decode_list_String(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_connectionKind = sse_decode_String(deserializer);
+    var var_transport = sse_decode_String(deserializer);
+    var var_firstSeen = sse_decode_u_64(deserializer);
+    var var_pingMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_addressType = sse_decode_String(deserializer);
+    var var_asn = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_asnOrganization = sse_decode_opt_String(deserializer);
+    var var_city = sse_decode_opt_String(deserializer);
+    var var_country = sse_decode_opt_String(deserializer);
+    var var_countryCode = sse_decode_opt_String(deserializer);
+    return PeerDetailedInfo(
+      address: var_address,
+      userAgent: var_userAgent,
+      implementation: var_implementation,
+      height: var_height,
+      isInbound: var_isInbound,
+      services: var_services,
+      serviceNames: var_serviceNames,
+      status: var_status,
+      connectionKind: var_connectionKind,
+      transport: var_transport,
+      firstSeen: var_firstSeen,
+      pingMs: var_pingMs,
+      bytesSent: var_bytesSent,
+      bytesReceived: var_bytesReceived,
+      addressType: var_addressType,
+      asn: var_asn,
+      asnOrganization: var_asnOrganization,
+      city: var_city,
+      country: var_country,
+      countryCode: var_countryCode,
+    );
+  }
+
+  @protected
+  PeerDiversityAction sse_decode_peer_diversity_action(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PeerDiversityAction.values[inner];
+  }
+
+  @protected
+  PeerDiversityInfo sse_decode_peer_diversity_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_score = sse_decode_f_64(deserializer);
+    var var_outboundPeers = sse_decode_u_32(deserializer);
+    var var_asnGroups = sse_decode_list_peer_group_share(deserializer);
+    var var_countryGroups = sse_decode_list_peer_group_share(deserializer);
+    var var_concentratedPeers = sse_decode_list_String(deserializer);
+    var var_rotatedPeers = sse_decode_list_String(deserializer);
+    var var_warnings = sse_decode_list_String(deserializer);
+    return PeerDiversityInfo(
+      score: var_score,
+      outboundPeers: var_outboundPeers,
+      asnGroups: var_asnGroups,
+      countryGroups: var_countryGroups,
+      concentratedPeers: var_concentratedPeers,
+      rotatedPeers: var_rotatedPeers,
+      warnings: var_warnings,
+    );
+  }
+
+  @protected
+  PeerDiversityPolicy sse_decode_peer_diversity_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxAsnShare = sse_decode_f_64(deserializer);
+    var var_maxCountryShare = sse_decode_f_64(deserializer);
+    var var_minPeers = sse_decode_u_32(deserializer);
+    var var_action = sse_decode_peer_diversity_action(deserializer);
+    return PeerDiversityPolicy(
+      maxAsnShare: var_maxAsnShare,
+      maxCountryShare: var_maxCountryShare,
+      minPeers: var_minPeers,
+      action: var_action,
+    );
+  }
+
+  @protected
+  PeerGroupShare sse_decode_peer_group_share(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_peers = sse_decode_u_32(deserializer);
+    var var_share = sse_decode_f_64(deserializer);
+    var var_overLimit = sse_decode_bool(deserializer);
+    return PeerGroupShare(
+      key: var_key,
+      label: var_label,
+      peers: var_peers,
+      share: var_share,
+      overLimit: var_overLimit,
+    );
+  }
+
+  @protected
+  QrScanProgress sse_decode_qr_scan_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_urType = sse_decode_opt_String(deserializer);
+    var var_progress = sse_decode_f_64(deserializer);
+    var var_receivedFrames = sse_decode_u_32(deserializer);
+    var var_knownFragments = sse_decode_u_32(deserializer);
+    var var_fragmentCount = sse_decode_u_32(deserializer);
+    var var_complete = sse_decode_bool(deserializer);
+    var var_data = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return QrScanProgress(
+      urType: var_urType,
+      progress: var_progress,
+      receivedFrames: var_receivedFrames,
+      knownFragments: var_knownFragments,
+      fragmentCount: var_fragmentCount,
+      complete: var_complete,
+      data: var_data,
+    );
+  }
+
+  @protected
+  RecentBlockInfo sse_decode_recent_block_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_height = sse_decode_u_32(deserializer);
+    var var_hash = sse_decode_String(deserializer);
+    var var_time = sse_decode_u_32(deserializer);
+    var var_txCount = sse_decode_u_32(deserializer);
+    var var_size = sse_decode_u_32(deserializer);
+    var var_weight = sse_decode_u_64(deserializer);
+    var var_totalFeesSats = sse_decode_u_64(deserializer);
+    var var_minerTag = sse_decode_opt_String(deserializer);
+    return RecentBlockInfo(
+      height: var_height,
+      hash: var_hash,
+      time: var_time,
+      txCount: var_txCount,
+      size: var_size,
+      weight: var_weight,
+      totalFeesSats: var_totalFeesSats,
+      minerTag: var_minerTag,
+    );
+  }
+
+  @protected
+  RecentBlocksPage sse_decode_recent_blocks_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blocks = sse_decode_list_recent_block_info(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return RecentBlocksPage(blocks: var_blocks, total: var_total);
+  }
+
+  @protected
+  ReorgInfo sse_decode_reorg_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_forkHeight = sse_decode_u_32(deserializer);
+    var var_forkHash = sse_decode_String(deserializer);
+    var var_depth = sse_decode_u_32(deserializer);
+    var var_disconnectedHashes = sse_decode_list_String(deserializer);
+    var var_detectedAt = sse_decode_u_64(deserializer);
+    return ReorgInfo(
+      forkHeight: var_forkHeight,
+      forkHash: var_forkHash,
+      depth: var_depth,
+      disconnectedHashes: var_disconnectedHashes,
+      detectedAt: var_detectedAt,
+    );
+  }
+
+  @protected
+  SendTransactionResult sse_decode_send_transaction_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txid = sse_decode_String(deserializer);
+    var var_rawTxHex = sse_decode_String(deserializer);
+    return SendTransactionResult(txid: var_txid, rawTxHex: var_rawTxHex);
+  }
+
+  @protected
+  StatFieldStatus sse_decode_stat_field_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_String(deserializer);
+    var var_available = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return StatFieldStatus(
+      field: var_field,
+      available: var_available,
+      error: var_error,
+    );
+  }
+
+  @protected
+  SyncPhaseKind sse_decode_sync_phase_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncPhaseKind.values[inner];
+  }
+
+  @protected
+  SyncProgressInfo sse_decode_sync_progress_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_phase = sse_decode_sync_phase_kind(deserializer);
+    var var_percent = sse_decode_f_64(deserializer);
+    var var_heightPercent = sse_decode_f_64(deserializer);
+    var var_blocks = sse_decode_u_32(deserializer);
+    var var_headers = sse_decode_u_32(deserializer);
+    var var_filterHeight = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_backfillHeight = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_targetHeight = sse_decode_u_32(deserializer);
+    var var_blocksPerSec = sse_decode_f_64(deserializer);
+    var var_etaSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return SyncProgressInfo(
+      phase: var_phase,
+      percent: var_percent,
+      heightPercent: var_heightPercent,
+      blocks: var_blocks,
+      headers: var_headers,
+      filterHeight: var_filterHeight,
+      backfillHeight: var_backfillHeight,
+      targetHeight: var_targetHeight,
+      blocksPerSec: var_blocksPerSec,
+      etaSecs: var_etaSecs,
+    );
+  }
+
+  @protected
+  TransactionInfo sse_decode_transaction_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txid = sse_decode_String(deserializer);
+    var var_isCoinbase = sse_decode_bool(deserializer);
+    var var_size = sse_decode_u_32(deserializer);
+    var var_vsize = sse_decode_u_32(deserializer);
+    var var_weight = sse_decode_u_64(deserializer);
+    var var_inputs = sse_decode_list_tx_input_info(deserializer);
+    var var_outputs = sse_decode_list_tx_output_info(deserializer);
+    var var_outputTotalSats = sse_decode_u_64(deserializer);
+    return TransactionInfo(
+      txid: var_txid,
+      isCoinbase: var_isCoinbase,
+      size: var_size,
+      vsize: var_vsize,
+      weight: var_weight,
+      inputs: var_inputs,
+      outputs: var_outputs,
+      outputTotalSats: var_outputTotalSats,
+    );
+  }
+
+  @protected
+  TrustedPeerConfig sse_decode_trusted_peer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_priority = sse_decode_u_32(deserializer);
+    return TrustedPeerConfig(address: var_address, priority: var_priority);
+  }
+
+  @protected
+  TrustedPeerMode sse_decode_trusted_peer_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TrustedPeerMode.values[inner];
+  }
+
+  @protected
+  TrustedPeerStatus sse_decode_trusted_peer_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_priority = sse_decode_u_32(deserializer);
+    var var_resolvedAddress = sse_decode_opt_String(deserializer);
+    var var_isConnected = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return TrustedPeerStatus(
+      address: var_address,
+      priority: var_priority,
+      resolvedAddress: var_resolvedAddress,
+      isConnected: var_isConnected,
+      error: var_error,
+    );
+  }
+
+  @protected
+  TxInputInfo sse_decode_tx_input_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_previousOutput = sse_decode_opt_String(deserializer);
+    var var_sequence = sse_decode_u_32(deserializer);
+    var var_scriptSigSize = sse_decode_u_32(deserializer);
+    var var_witnessItems = sse_decode_u_32(deserializer);
+    var var_inscription =
+        sse_decode_opt_box_autoadd_inscription_info(deserializer);
+    return TxInputInfo(
+      previousOutput: var_previousOutput,
+      sequence: var_sequence,
+      scriptSigSize: var_scriptSigSize,
+      witnessItems: var_witnessItems,
+      inscription: var_inscription,
+    );
+  }
+
+  @protected
+  TxOutputInfo sse_decode_tx_output_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_valueSats = sse_decode_u_64(deserializer);
+    var var_scriptType = sse_decode_String(deserializer);
+    var var_multisigRequired = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_multisigTotal = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_address = sse_decode_opt_String(deserializer);
+    var var_scriptHex = sse_decode_String(deserializer);
+    var var_opReturn = sse_decode_opt_box_autoadd_op_return_info(deserializer);
+    return TxOutputInfo(
+      valueSats: var_valueSats,
+      scriptType: var_scriptType,
+      multisigRequired: var_multisigRequired,
+      multisigTotal: var_multisigTotal,
+      address: var_address,
+      scriptHex: var_scriptHex,
+      opReturn: var_opReturn,
+    );
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WalletInfo sse_decode_wallet_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_balanceSats = sse_decode_u_64(deserializer);
+    var var_address = sse_decode_String(deserializer);
+    return WalletInfo(balanceSats: var_balanceSats, address: var_address);
+  }
+
+  @protected
+  WalletRescanProgress sse_decode_wallet_rescan_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startHeight = sse_decode_u_32(deserializer);
+    var var_currentHeight = sse_decode_u_32(deserializer);
+    var var_tipHeight = sse_decode_u_32(deserializer);
+    var var_progress = sse_decode_f_64(deserializer);
+    var var_matchedBlocks = sse_decode_u_32(deserializer);
+    var var_foundTransactions = sse_decode_u_32(deserializer);
+    var var_scriptCount = sse_decode_u_32(deserializer);
+    var var_running = sse_decode_bool(deserializer);
+    var var_waitingForFilters = sse_decode_bool(deserializer);
+    var var_filterHeight = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_cancelled = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return WalletRescanProgress(
+      startHeight: var_startHeight,
+      currentHeight: var_currentHeight,
+      tipHeight: var_tipHeight,
+      progress: var_progress,
+      matchedBlocks: var_matchedBlocks,
+      foundTransactions: var_foundTransactions,
+      scriptCount: var_scriptCount,
+      running: var_running,
+      waitingForFilters: var_waitingForFilters,
+      filterHeight: var_filterHeight,
+      cancelled: var_cancelled,
+      error: var_error,
+    );
+  }
+
+  @protected
+  WalletTransactionInfo sse_decode_wallet_transaction_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txid = sse_decode_String(deserializer);
+    var var_sent = sse_decode_u_64(deserializer);
+    var var_received = sse_decode_u_64(deserializer);
+    var var_fee = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_isConfirmed = sse_decode_bool(deserializer);
+    var var_confirmationHeight = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_timestamp = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return WalletTransactionInfo(
+      txid: var_txid,
+      sent: var_sent,
+      received: var_received,
+      fee: var_fee,
+      isConfirmed: var_isConfirmed,
+      confirmationHeight: var_confirmationHeight,
+      timestamp: var_timestamp,
+    );
+  }
+
+  @protected
+  WatchEntryInfo sse_decode_watch_entry_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_scriptHex = sse_decode_String(deserializer);
+    var var_address = sse_decode_opt_String(deserializer);
+    var var_scriptType = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_addedAt = sse_decode_u_64(deserializer);
+    return WatchEntryInfo(
+      scriptHex: var_scriptHex,
+      address: var_address,
+      scriptType: var_scriptType,
+      label: var_label,
+      addedAt: var_addedAt,
+    );
+  }
+
+  @protected
+  WatchMatchInfo sse_decode_watch_match_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_scriptHex = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_txid = sse_decode_String(deserializer);
+    var var_blockHash = sse_decode_String(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_incoming = sse_decode_bool(deserializer);
+    var var_amountSats = sse_decode_u_64(deserializer);
+    var var_memo = sse_decode_opt_String(deserializer);
+    return WatchMatchInfo(
+      scriptHex: var_scriptHex,
+      label: var_label,
+      txid: var_txid,
+      blockHash: var_blockHash,
+      height: var_height,
+      incoming: var_incoming,
+      amountSats: var_amountSats,
+      memo: var_memo,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_reorg_info_Sse(
+    RustStreamSink<ReorgInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reorg_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_StreamSink_watch_match_info_Sse(
+    RustStreamSink<WatchMatchInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_watch_match_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  WalletInfo dco_decode_box_autoadd_wallet_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_wallet_info(raw);
+  void sse_encode_accumulator_delta_info(
+    AccumulatorDeltaInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_String(self.hash, serializer);
+    sse_encode_u_32(self.leavesAdded, serializer);
+    sse_encode_u_32(self.leavesDeleted, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.leavesAfter, serializer);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_accumulator_import_preview(
+    AccumulatorImportPreview self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_accumulator_package_info(self.package, serializer);
+    sse_encode_bool(self.inHeaderChain, serializer);
+    sse_encode_opt_String(self.problem, serializer);
+    sse_encode_String(self.trustWarning, serializer);
   }
 
   @protected
-  List<PeerDetailedInfo> dco_decode_list_peer_detailed_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_peer_detailed_info).toList();
+  void sse_encode_accumulator_info(
+    AccumulatorInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_String(self.blockHash, serializer);
+    sse_encode_u_64(self.leaves, serializer);
+    sse_encode_u_8(self.forestRows, serializer);
+    sse_encode_list_accumulator_root_info(self.roots, serializer);
+    sse_encode_u_32(self.rootsSizeBytes, serializer);
+    sse_encode_u_32(self.serializedSizeBytes, serializer);
+    sse_encode_String(self.commitment, serializer);
+    sse_encode_list_accumulator_delta_info(self.history, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_accumulator_package_info(
+    AccumulatorPackageInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.package, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_String(self.blockHash, serializer);
+    sse_encode_u_64(self.leaves, serializer);
+    sse_encode_list_String(self.roots, serializer);
+    sse_encode_opt_String(self.network, serializer);
   }
 
   @protected
-  List<WalletTransactionInfo> dco_decode_list_wallet_transaction_info(
-    dynamic raw,
+  void sse_encode_accumulator_root_info(
+    AccumulatorRootInfo self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_wallet_transaction_info)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.hash, serializer);
+    sse_encode_u_8(self.row, serializer);
+    sse_encode_u_64(self.position, serializer);
   }
 
   @protected
-  NodeStats dco_decode_node_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return NodeStats(
-      inIbd: dco_decode_bool(arr[0]),
-      headers: dco_decode_u_32(arr[1]),
-      blocks: dco_decode_u_32(arr[2]),
-      userAgent: dco_decode_String(arr[3]),
-      uptimeSecs: dco_decode_u_64(arr[4]),
-      peersCount: dco_decode_usize(arr[5]),
-      peers: dco_decode_list_peer_detailed_info(arr[6]),
-    );
+  void sse_encode_animated_qr(AnimatedQr self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.urType, serializer);
+    sse_encode_u_32(self.fragmentCount, serializer);
+    sse_encode_list_String(self.frames, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_banned_peer_info(
+    BannedPeerInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_u_64(self.bannedUntil, serializer);
+  }
+
+  @protected
+  void sse_encode_block_consumer_metrics(
+    BlockConsumerMetrics self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_64(self.delivered, serializer);
+    sse_encode_u_64(self.processed, serializer);
+    sse_encode_u_64(self.lag, serializer);
+    sse_encode_u_32(self.lastHeight, serializer);
+    sse_encode_u_64(self.stalls, serializer);
+    sse_encode_u_64(self.avgProcessMicros, serializer);
+    sse_encode_bool(self.catchingUp, serializer);
+  }
+
+  @protected
+  void sse_encode_block_fee_stats_info(
+    BlockFeeStatsInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_String(self.hash, serializer);
+    sse_encode_u_32(self.txCount, serializer);
+    sse_encode_u_64(self.totalFeesSats, serializer);
+    sse_encode_f_64(self.minFeeRate, serializer);
+    sse_encode_f_64(self.medianFeeRate, serializer);
+    sse_encode_f_64(self.maxFeeRate, serializer);
+    sse_encode_list_prim_u_32_strict(self.feeRateHistogram, serializer);
+    sse_encode_f_64(self.segwitPercent, serializer);
+    sse_encode_f_64(self.taprootPercent, serializer);
+    sse_encode_u_32(self.missingPrevouts, serializer);
+  }
+
+  @protected
+  void sse_encode_block_header_info(
+    BlockHeaderInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.hash, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_String(self.previousHash, serializer);
+    sse_encode_String(self.merkleRoot, serializer);
+    sse_encode_i_32(self.version, serializer);
+    sse_encode_u_32(self.timestamp, serializer);
+    sse_encode_u_32(self.bits, serializer);
+    sse_encode_u_32(self.nonce, serializer);
+    sse_encode_f_64(self.difficulty, serializer);
+  }
+
+  @protected
+  void sse_encode_block_info(BlockInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_block_header_info(self.header, serializer);
+    sse_encode_u_32(self.size, serializer);
+    sse_encode_u_64(self.weight, serializer);
+    sse_encode_u_32(self.txCount, serializer);
+    sse_encode_list_transaction_info(self.transactions, serializer);
+    sse_encode_u_64(self.subsidySats, serializer);
+    sse_encode_u_64(self.feesSats, serializer);
+    sse_encode_u_64(self.coinbaseValueSats, serializer);
+  }
+
+  @protected
+  void sse_encode_bonsai_node_error(
+    BonsaiNodeError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bonsai_node_error_kind(self.kind, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_bonsai_node_error_kind(
+    BonsaiNodeErrorKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_block_fee_stats_info(
+    BlockFeeStatsInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_block_fee_stats_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_indexed_transaction_info(
+    IndexedTransactionInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_indexed_transaction_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_inscription_info(
+    InscriptionInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_inscription_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_op_return_info(
+    OpReturnInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_op_return_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_peer_diversity_policy(
+    PeerDiversityPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_peer_diversity_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_info(
+    WalletInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_rescan_progress(
+    WalletRescanProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_rescan_progress(self, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_geo_ip_database_status(
+    GeoIpDatabaseStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.databaseType, serializer);
+    sse_encode_u_64(self.buildEpoch, serializer);
+    sse_encode_u_32(self.searchTreeNodes, serializer);
+    sse_encode_u_16(self.ipVersion, serializer);
+    sse_encode_opt_String(self.description, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_indexed_transaction_info(
+    IndexedTransactionInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.blockHash, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.position, serializer);
+    sse_encode_String(self.rawTxHex, serializer);
+    sse_encode_transaction_info(self.transaction, serializer);
+  }
+
+  @protected
+  void sse_encode_inscription_info(
+    InscriptionInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.contentType, serializer);
+    sse_encode_u_32(self.contentLength, serializer);
+    sse_encode_opt_String(self.text, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_accumulator_delta_info(
+    List<AccumulatorDeltaInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_accumulator_delta_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_accumulator_root_info(
+    List<AccumulatorRootInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_accumulator_root_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_banned_peer_info(
+    List<BannedPeerInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_banned_peer_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_block_consumer_metrics(
+    List<BlockConsumerMetrics> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_block_consumer_metrics(item, serializer);
+    }
   }
 
   @protected
-  NodeStats? dco_decode_opt_box_autoadd_node_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_node_stats(raw);
+  void sse_encode_list_block_fee_stats_info(
+    List<BlockFeeStatsInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_block_fee_stats_info(item, serializer);
+    }
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  void sse_encode_list_geo_ip_database_status(
+    List<GeoIpDatabaseStatus> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_geo_ip_database_status(item, serializer);
+    }
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  void sse_encode_list_node_stats_sample(
+    List<NodeStatsSample> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_node_stats_sample(item, serializer);
+    }
   }
 
   @protected
-  WalletInfo? dco_decode_opt_box_autoadd_wallet_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_wallet_info(raw);
+  void sse_encode_list_peer_census_bucket(
+    List<PeerCensusBucket> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_census_bucket(item, serializer);
+    }
   }
 
   @protected
-  PeerDetailedInfo dco_decode_peer_detailed_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return PeerDetailedInfo(
-      address: dco_decode_String(arr[0]),
-      userAgent: dco_decode_String(arr[1]),
-      height: dco_decode_u_32(arr[2]),
-      isInbound: dco_decode_bool(arr[3]),
-    );
+  void sse_encode_list_peer_detailed_info(
+    List<PeerDetailedInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_detailed_info(item, serializer);
+    }
   }
 
   @protected
-  SendTransactionResult dco_decode_send_transaction_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SendTransactionResult(
-      txid: dco_decode_String(arr[0]),
-      rawTxHex: dco_decode_String(arr[1]),
-    );
+  void sse_encode_list_peer_group_share(
+    List<PeerGroupShare> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_group_share(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(self);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_list_recent_block_info(
+    List<RecentBlockInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_recent_block_info(item, serializer);
+    }
   }
 
   @protected
-  WalletInfo dco_decode_wallet_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return WalletInfo(
-      balanceSats: dco_decode_u_64(arr[0]),
-      address: dco_decode_String(arr[1]),
-    );
+  void sse_encode_list_reorg_info(
+    List<ReorgInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_reorg_info(item, serializer);
+    }
   }
 
   @protected
-  WalletTransactionInfo dco_decode_wallet_transaction_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return WalletTransactionInfo(
-      txid: dco_decode_String(arr[0]),
-      sent: dco_decode_u_64(arr[1]),
-      received: dco_decode_u_64(arr[2]),
-      fee: dco_decode_opt_box_autoadd_u_64(arr[3]),
-      isConfirmed: dco_decode_bool(arr[4]),
-      confirmationHeight: dco_decode_opt_box_autoadd_u_32(arr[5]),
-      timestamp: dco_decode_opt_box_autoadd_u_64(arr[6]),
-    );
+  void sse_encode_list_stat_field_status(
+    List<StatFieldStatus> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_stat_field_status(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_transaction_info(
+    List<TransactionInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_transaction_info(item, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_trusted_peer_config(
+    List<TrustedPeerConfig> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_trusted_peer_config(item, serializer);
+    }
   }
 
   @protected
-  NodeStats sse_decode_box_autoadd_node_stats(SseDeserializer deserializer) {
+  void sse_encode_list_trusted_peer_status(
+    List<TrustedPeerStatus> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_node_stats(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_trusted_peer_status(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_tx_input_info(
+    List<TxInputInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tx_input_info(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_tx_output_info(
+    List<TxOutputInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tx_output_info(item, serializer);
+    }
   }
 
   @protected
-  WalletInfo sse_decode_box_autoadd_wallet_info(SseDeserializer deserializer) {
+  void sse_encode_list_wallet_transaction_info(
+    List<WalletTransactionInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_wallet_info(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_wallet_transaction_info(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_list_watch_entry_info(
+    List<WatchEntryInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_watch_entry_info(item, serializer);
+    }
   }
 
   @protected
-  List<PeerDetailedInfo> sse_decode_list_peer_detailed_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_watch_match_info(
+    List<WatchMatchInfo> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PeerDetailedInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_peer_detailed_info(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_watch_match_info(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_node_stats(NodeStats self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_bool(self.inIbd, serializer);
+    sse_encode_u_32(self.headers, serializer);
+    sse_encode_u_32(self.blocks, serializer);
+    sse_encode_String(self.userAgent, serializer);
+    sse_encode_u_64(self.uptimeSecs, serializer);
+    sse_encode_usize(self.peersCount, serializer);
+    sse_encode_list_peer_detailed_info(self.peers, serializer);
+    sse_encode_list_stat_field_status(self.fieldStatus, serializer);
+    sse_encode_u_64(self.sampledAt, serializer);
   }
 
   @protected
-  List<WalletTransactionInfo> sse_decode_list_wallet_transaction_info(
-    SseDeserializer deserializer,
+  void sse_encode_node_stats_sample(
+    NodeStatsSample self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WalletTransactionInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_wallet_transaction_info(deserializer));
-    }
-    return ans_;
+    sse_encode_u_64(self.timestamp, serializer);
+    sse_encode_u_32(self.blocks, serializer);
+    sse_encode_u_32(self.headers, serializer);
+    sse_encode_u_32(self.peers, serializer);
+    sse_encode_f_64(self.blocksPerSec, serializer);
   }
 
   @protected
-  NodeStats sse_decode_node_stats(SseDeserializer deserializer) {
+  void sse_encode_op_return_info(OpReturnInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_inIbd = sse_decode_bool(deserializer);
-    var var_headers = sse_decode_u_32(deserializer);
-    var var_blocks = sse_decode_u_32(deserializer);
-    var var_userAgent = sse_decode_String(deserializer);
-    var var_uptimeSecs = sse_decode_u_64(deserializer);
-    var var_peersCount = sse_decode_usize(deserializer);
-    var var_peers = sse_decode_list_peer_detailed_info(deserializer);
-    return NodeStats(
-      inIbd: var_inIbd,
-      headers: var_headers,
-      blocks: var_blocks,
-      userAgent: var_userAgent,
-      uptimeSecs: var_uptimeSecs,
-      peersCount: var_peersCount,
-      peers: var_peers,
-    );
+    sse_encode_String(self.dataHex, serializer);
+    sse_encode_opt_String(self.text, serializer);
+    sse_encode_opt_String(self.protocol, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
   }
 
   @protected
-  NodeStats? sse_decode_opt_box_autoadd_node_stats(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_block_fee_stats_info(
+    BlockFeeStatsInfo? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_node_stats(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_block_fee_stats_info(self, serializer);
     }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_indexed_transaction_info(
+    IndexedTransactionInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_indexed_transaction_info(self, serializer);
     }
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_inscription_info(
+    InscriptionInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_inscription_info(self, serializer);
     }
   }
 
   @protected
-  WalletInfo? sse_decode_opt_box_autoadd_wallet_info(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_op_return_info(
+    OpReturnInfo? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_wallet_info(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_op_return_info(self, serializer);
     }
   }
 
   @protected
-  PeerDetailedInfo sse_decode_peer_detailed_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_address = sse_decode_String(deserializer);
-    var var_userAgent = sse_decode_String(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_isInbound = sse_decode_bool(deserializer);
-    return PeerDetailedInfo(
-      address: var_address,
-      userAgent: var_userAgent,
-      height: var_height,
-      isInbound: var_isInbound,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  SendTransactionResult sse_decode_send_transaction_result(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_txid = sse_decode_String(deserializer);
-    var var_rawTxHex = sse_decode_String(deserializer);
-    return SendTransactionResult(txid: var_txid, rawTxHex: var_rawTxHex);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_8(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_wallet_info(
+    WalletInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_wallet_info(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_wallet_rescan_progress(
+    WalletRescanProgress? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_wallet_rescan_progress(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_peer_census_bucket(
+    PeerCensusBucket self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_u_32(self.peers, serializer);
   }
 
   @protected
-  WalletInfo sse_decode_wallet_info(SseDeserializer deserializer) {
+  void sse_encode_peer_census_info(
+    PeerCensusInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_balanceSats = sse_decode_u_64(deserializer);
-    var var_address = sse_decode_String(deserializer);
-    return WalletInfo(balanceSats: var_balanceSats, address: var_address);
+    sse_encode_u_32(self.totalPeers, serializer);
+    sse_encode_list_peer_census_bucket(self.byCountry, serializer);
+    sse_encode_list_peer_census_bucket(self.byAsn, serializer);
+    sse_encode_list_peer_census_bucket(self.byImplementation, serializer);
+    sse_encode_list_peer_census_bucket(self.byVersion, serializer);
+    sse_encode_u_32(self.utreexoPeers, serializer);
+    sse_encode_u_32(self.compactFilterPeers, serializer);
   }
 
   @protected
-  WalletTransactionInfo sse_decode_wallet_transaction_info(
-    SseDeserializer deserializer,
+  void sse_encode_peer_detailed_info(
+    PeerDetailedInfo self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_txid = sse_decode_String(deserializer);
-    var var_sent = sse_decode_u_64(deserializer);
-    var var_received = sse_decode_u_64(deserializer);
-    var var_fee = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_isConfirmed = sse_decode_bool(deserializer);
-    var var_confirmationHeight = sse_decode_opt_box_autoadd_u_32(deserializer);
-    var var_timestamp = sse_decode_opt_box_autoadd_u_64(deserializer);
-    return WalletTransactionInfo(
-      txid: var_txid,
-      sent: var_sent,
-      received: var_received,
-      fee: var_fee,
-      isConfirmed: var_isConfirmed,
-      confirmationHeight: var_confirmationHeight,
-      timestamp: var_timestamp,
-    );
+    sse_encode_String(self.address, serializer);
+    sse_encode_String(self.userAgent, serializer);
+    sse_encode_String(self.implementation, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_bool(self.isInbound, serializer);
+    sse_encode_u_64(self.services, serializer);
+    sse_encode_list_String(self.serviceNames, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_String(self.connectionKind, serializer);
+    sse_encode_String(self.transport, serializer);
+    sse_encode_u_64(self.firstSeen, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.pingMs, serializer);
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_String(self.addressType, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.asn, serializer);
+    sse_encode_opt_String(self.asnOrganization, serializer);
+    sse_encode_opt_String(self.city, serializer);
+    sse_encode_opt_String(self.country, serializer);
+    sse_encode_opt_String(self.countryCode, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_peer_diversity_action(
+    PeerDiversityAction self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_peer_diversity_info(
+    PeerDiversityInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_f_64(self.score, serializer);
+    sse_encode_u_32(self.outboundPeers, serializer);
+    sse_encode_list_peer_group_share(self.asnGroups, serializer);
+    sse_encode_list_peer_group_share(self.countryGroups, serializer);
+    sse_encode_list_String(self.concentratedPeers, serializer);
+    sse_encode_list_String(self.rotatedPeers, serializer);
+    sse_encode_list_String(self.warnings, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_peer_diversity_policy(
+    PeerDiversityPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_f_64(self.maxAsnShare, serializer);
+    sse_encode_f_64(self.maxCountryShare, serializer);
+    sse_encode_u_32(self.minPeers, serializer);
+    sse_encode_peer_diversity_action(self.action, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_node_stats(
-    NodeStats self,
+  void sse_encode_peer_group_share(
+    PeerGroupShare self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_node_stats(self, serializer);
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_u_32(self.peers, serializer);
+    sse_encode_f_64(self.share, serializer);
+    sse_encode_bool(self.overLimit, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_qr_scan_progress(
+    QrScanProgress self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+    sse_encode_opt_String(self.urType, serializer);
+    sse_encode_f_64(self.progress, serializer);
+    sse_encode_u_32(self.receivedFrames, serializer);
+    sse_encode_u_32(self.knownFragments, serializer);
+    sse_encode_u_32(self.fragmentCount, serializer);
+    sse_encode_bool(self.complete, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.data, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_recent_block_info(
+    RecentBlockInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_String(self.hash, serializer);
+    sse_encode_u_32(self.time, serializer);
+    sse_encode_u_32(self.txCount, serializer);
+    sse_encode_u_32(self.size, serializer);
+    sse_encode_u_64(self.weight, serializer);
+    sse_encode_u_64(self.totalFeesSats, serializer);
+    sse_encode_opt_String(self.minerTag, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_wallet_info(
-    WalletInfo self,
+  void sse_encode_recent_blocks_page(
+    RecentBlocksPage self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_wallet_info(self, serializer);
+    sse_encode_list_recent_block_info(self.blocks, serializer);
+    sse_encode_u_32(self.total, serializer);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_reorg_info(ReorgInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_u_32(self.forkHeight, serializer);
+    sse_encode_String(self.forkHash, serializer);
+    sse_encode_u_32(self.depth, serializer);
+    sse_encode_list_String(self.disconnectedHashes, serializer);
+    sse_encode_u_64(self.detectedAt, serializer);
   }
 
   @protected
-  void sse_encode_list_peer_detailed_info(
-    List<PeerDetailedInfo> self,
+  void sse_encode_send_transaction_result(
+    SendTransactionResult self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_peer_detailed_info(item, serializer);
-    }
+    sse_encode_String(self.txid, serializer);
+    sse_encode_String(self.rawTxHex, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_stat_field_status(
+    StatFieldStatus self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_String(self.field, serializer);
+    sse_encode_bool(self.available, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  void sse_encode_list_wallet_transaction_info(
-    List<WalletTransactionInfo> self,
+  void sse_encode_sync_phase_kind(
+    SyncPhaseKind self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_wallet_transaction_info(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_node_stats(NodeStats self, SseSerializer serializer) {
+  void sse_encode_sync_progress_info(
+    SyncProgressInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.inIbd, serializer);
-    sse_encode_u_32(self.headers, serializer);
+    sse_encode_sync_phase_kind(self.phase, serializer);
+    sse_encode_f_64(self.percent, serializer);
+    sse_encode_f_64(self.heightPercent, serializer);
     sse_encode_u_32(self.blocks, serializer);
-    sse_encode_String(self.userAgent, serializer);
-    sse_encode_u_64(self.uptimeSecs, serializer);
-    sse_encode_usize(self.peersCount, serializer);
-    sse_encode_list_peer_detailed_info(self.peers, serializer);
+    sse_encode_u_32(self.headers, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.filterHeight, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.backfillHeight, serializer);
+    sse_encode_u_32(self.targetHeight, serializer);
+    sse_encode_f_64(self.blocksPerSec, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.etaSecs, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_transaction_info(
+    TransactionInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sse_encode_String(self.txid, serializer);
+    sse_encode_bool(self.isCoinbase, serializer);
+    sse_encode_u_32(self.size, serializer);
+    sse_encode_u_32(self.vsize, serializer);
+    sse_encode_u_64(self.weight, serializer);
+    sse_encode_list_tx_input_info(self.inputs, serializer);
+    sse_encode_list_tx_output_info(self.outputs, serializer);
+    sse_encode_u_64(self.outputTotalSats, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_node_stats(
-    NodeStats? self,
+  void sse_encode_trusted_peer_config(
+    TrustedPeerConfig self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_node_stats(self, serializer);
-    }
+    sse_encode_String(self.address, serializer);
+    sse_encode_u_32(self.priority, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+  void sse_encode_trusted_peer_mode(
+    TrustedPeerMode self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+  void sse_encode_trusted_peer_status(
+    TrustedPeerStatus self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
+    sse_encode_String(self.address, serializer);
+    sse_encode_u_32(self.priority, serializer);
+    sse_encode_opt_String(self.resolvedAddress, serializer);
+    sse_encode_bool(self.isConnected, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_wallet_info(
-    WalletInfo? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_tx_input_info(TxInputInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_wallet_info(self, serializer);
-    }
+    sse_encode_opt_String(self.previousOutput, serializer);
+    sse_encode_u_32(self.sequence, serializer);
+    sse_encode_u_32(self.scriptSigSize, serializer);
+    sse_encode_u_32(self.witnessItems, serializer);
+    sse_encode_opt_box_autoadd_inscription_info(self.inscription, serializer);
   }
 
   @protected
-  void sse_encode_peer_detailed_info(
-    PeerDetailedInfo self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_tx_output_info(TxOutputInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.address, serializer);
-    sse_encode_String(self.userAgent, serializer);
-    sse_encode_u_32(self.height, serializer);
-    sse_encode_bool(self.isInbound, serializer);
+    sse_encode_u_64(self.valueSats, serializer);
+    sse_encode_String(self.scriptType, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.multisigRequired, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.multisigTotal, serializer);
+    sse_encode_opt_String(self.address, serializer);
+    sse_encode_String(self.scriptHex, serializer);
+    sse_encode_opt_box_autoadd_op_return_info(self.opReturn, serializer);
   }
 
   @protected
-  void sse_encode_send_transaction_result(
-    SendTransactionResult self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.txid, serializer);
-    sse_encode_String(self.rawTxHex, serializer);
+    serializer.buffer.putUint16(self);
   }
 
   @protected
@@ -1160,6 +5924,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.address, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_rescan_progress(
+    WalletRescanProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.startHeight, serializer);
+    sse_encode_u_32(self.currentHeight, serializer);
+    sse_encode_u_32(self.tipHeight, serializer);
+    sse_encode_f_64(self.progress, serializer);
+    sse_encode_u_32(self.matchedBlocks, serializer);
+    sse_encode_u_32(self.foundTransactions, serializer);
+    sse_encode_u_32(self.scriptCount, serializer);
+    sse_encode_bool(self.running, serializer);
+    sse_encode_bool(self.waitingForFilters, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.filterHeight, serializer);
+    sse_encode_bool(self.cancelled, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_wallet_transaction_info(
     WalletTransactionInfo self,
@@ -1176,8 +5960,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_watch_entry_info(
+    WatchEntryInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_String(self.scriptHex, serializer);
+    sse_encode_opt_String(self.address, serializer);
+    sse_encode_String(self.scriptType, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_u_64(self.addedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_watch_match_info(
+    WatchMatchInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.scriptHex, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_String(self.txid, serializer);
+    sse_encode_String(self.blockHash, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_bool(self.incoming, serializer);
+    sse_encode_u_64(self.amountSats, serializer);
+    sse_encode_opt_String(self.memo, serializer);
   }
 }
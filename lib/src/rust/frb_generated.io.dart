@@ -8,6 +8,7 @@ import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
+import 'node/error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
@@ -18,227 +19,1120 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  RustStreamSink<ReorgInfo> dco_decode_StreamSink_reorg_info_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<WatchMatchInfo> dco_decode_StreamSink_watch_match_info_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AccumulatorDeltaInfo dco_decode_accumulator_delta_info(dynamic raw);
+
+  @protected
+  AccumulatorImportPreview dco_decode_accumulator_import_preview(dynamic raw);
+
+  @protected
+  AccumulatorInfo dco_decode_accumulator_info(dynamic raw);
+
+  @protected
+  AccumulatorPackageInfo dco_decode_accumulator_package_info(dynamic raw);
+
+  @protected
+  AccumulatorRootInfo dco_decode_accumulator_root_info(dynamic raw);
+
+  @protected
+  AnimatedQr dco_decode_animated_qr(dynamic raw);
+
+  @protected
+  BannedPeerInfo dco_decode_banned_peer_info(dynamic raw);
+
+  @protected
+  BlockConsumerMetrics dco_decode_block_consumer_metrics(dynamic raw);
+
+  @protected
+  BlockFeeStatsInfo dco_decode_block_fee_stats_info(dynamic raw);
+
+  @protected
+  BlockHeaderInfo dco_decode_block_header_info(dynamic raw);
+
+  @protected
+  BlockInfo dco_decode_block_info(dynamic raw);
+
+  @protected
+  BonsaiNodeError dco_decode_bonsai_node_error(dynamic raw);
+
+  @protected
+  BonsaiNodeErrorKind dco_decode_bonsai_node_error_kind(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
   @protected
-  NodeStats dco_decode_box_autoadd_node_stats(dynamic raw);
+  BlockFeeStatsInfo dco_decode_box_autoadd_block_fee_stats_info(dynamic raw);
+
+  @protected
+  IndexedTransactionInfo dco_decode_box_autoadd_indexed_transaction_info(
+    dynamic raw,
+  );
+
+  @protected
+  InscriptionInfo dco_decode_box_autoadd_inscription_info(dynamic raw);
+
+  @protected
+  OpReturnInfo dco_decode_box_autoadd_op_return_info(dynamic raw);
+
+  @protected
+  PeerDiversityPolicy dco_decode_box_autoadd_peer_diversity_policy(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw);
+
+  @protected
+  WalletInfo dco_decode_box_autoadd_wallet_info(dynamic raw);
+
+  @protected
+  WalletRescanProgress dco_decode_box_autoadd_wallet_rescan_progress(
+    dynamic raw,
+  );
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  GeoIpDatabaseStatus dco_decode_geo_ip_database_status(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  IndexedTransactionInfo dco_decode_indexed_transaction_info(dynamic raw);
+
+  @protected
+  InscriptionInfo dco_decode_inscription_info(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<AccumulatorDeltaInfo> dco_decode_list_accumulator_delta_info(
+    dynamic raw,
+  );
+
+  @protected
+  List<AccumulatorRootInfo> dco_decode_list_accumulator_root_info(dynamic raw);
+
+  @protected
+  List<BannedPeerInfo> dco_decode_list_banned_peer_info(dynamic raw);
+
+  @protected
+  List<BlockConsumerMetrics> dco_decode_list_block_consumer_metrics(
+    dynamic raw,
+  );
+
+  @protected
+  List<BlockFeeStatsInfo> dco_decode_list_block_fee_stats_info(dynamic raw);
+
+  @protected
+  List<GeoIpDatabaseStatus> dco_decode_list_geo_ip_database_status(dynamic raw);
+
+  @protected
+  List<NodeStatsSample> dco_decode_list_node_stats_sample(dynamic raw);
+
+  @protected
+  List<PeerCensusBucket> dco_decode_list_peer_census_bucket(dynamic raw);
+
+  @protected
+  List<PeerDetailedInfo> dco_decode_list_peer_detailed_info(dynamic raw);
+
+  @protected
+  List<PeerGroupShare> dco_decode_list_peer_group_share(dynamic raw);
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<RecentBlockInfo> dco_decode_list_recent_block_info(dynamic raw);
+
+  @protected
+  List<ReorgInfo> dco_decode_list_reorg_info(dynamic raw);
+
+  @protected
+  List<StatFieldStatus> dco_decode_list_stat_field_status(dynamic raw);
+
+  @protected
+  List<TransactionInfo> dco_decode_list_transaction_info(dynamic raw);
+
+  @protected
+  List<TrustedPeerConfig> dco_decode_list_trusted_peer_config(dynamic raw);
+
+  @protected
+  List<TrustedPeerStatus> dco_decode_list_trusted_peer_status(dynamic raw);
+
+  @protected
+  List<TxInputInfo> dco_decode_list_tx_input_info(dynamic raw);
+
+  @protected
+  List<TxOutputInfo> dco_decode_list_tx_output_info(dynamic raw);
+
+  @protected
+  List<WalletTransactionInfo> dco_decode_list_wallet_transaction_info(
+    dynamic raw,
+  );
+
+  @protected
+  List<WatchEntryInfo> dco_decode_list_watch_entry_info(dynamic raw);
+
+  @protected
+  List<WatchMatchInfo> dco_decode_list_watch_match_info(dynamic raw);
+
+  @protected
+  NodeStats dco_decode_node_stats(dynamic raw);
+
+  @protected
+  NodeStatsSample dco_decode_node_stats_sample(dynamic raw);
+
+  @protected
+  OpReturnInfo dco_decode_op_return_info(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  BlockFeeStatsInfo? dco_decode_opt_box_autoadd_block_fee_stats_info(
+    dynamic raw,
+  );
+
+  @protected
+  IndexedTransactionInfo? dco_decode_opt_box_autoadd_indexed_transaction_info(
+    dynamic raw,
+  );
+
+  @protected
+  InscriptionInfo? dco_decode_opt_box_autoadd_inscription_info(dynamic raw);
+
+  @protected
+  OpReturnInfo? dco_decode_opt_box_autoadd_op_return_info(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+  @protected
+  WalletInfo? dco_decode_opt_box_autoadd_wallet_info(dynamic raw);
+
+  @protected
+  WalletRescanProgress? dco_decode_opt_box_autoadd_wallet_rescan_progress(
+    dynamic raw,
+  );
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  PeerCensusBucket dco_decode_peer_census_bucket(dynamic raw);
+
+  @protected
+  PeerCensusInfo dco_decode_peer_census_info(dynamic raw);
+
+  @protected
+  PeerDetailedInfo dco_decode_peer_detailed_info(dynamic raw);
+
+  @protected
+  PeerDiversityAction dco_decode_peer_diversity_action(dynamic raw);
+
+  @protected
+  PeerDiversityInfo dco_decode_peer_diversity_info(dynamic raw);
+
+  @protected
+  PeerDiversityPolicy dco_decode_peer_diversity_policy(dynamic raw);
+
+  @protected
+  PeerGroupShare dco_decode_peer_group_share(dynamic raw);
+
+  @protected
+  QrScanProgress dco_decode_qr_scan_progress(dynamic raw);
+
+  @protected
+  RecentBlockInfo dco_decode_recent_block_info(dynamic raw);
+
+  @protected
+  RecentBlocksPage dco_decode_recent_blocks_page(dynamic raw);
+
+  @protected
+  ReorgInfo dco_decode_reorg_info(dynamic raw);
+
+  @protected
+  SendTransactionResult dco_decode_send_transaction_result(dynamic raw);
+
+  @protected
+  StatFieldStatus dco_decode_stat_field_status(dynamic raw);
+
+  @protected
+  SyncPhaseKind dco_decode_sync_phase_kind(dynamic raw);
+
+  @protected
+  SyncProgressInfo dco_decode_sync_progress_info(dynamic raw);
+
+  @protected
+  TransactionInfo dco_decode_transaction_info(dynamic raw);
+
+  @protected
+  TrustedPeerConfig dco_decode_trusted_peer_config(dynamic raw);
+
+  @protected
+  TrustedPeerMode dco_decode_trusted_peer_mode(dynamic raw);
+
+  @protected
+  TrustedPeerStatus dco_decode_trusted_peer_status(dynamic raw);
+
+  @protected
+  TxInputInfo dco_decode_tx_input_info(dynamic raw);
+
+  @protected
+  TxOutputInfo dco_decode_tx_output_info(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WalletInfo dco_decode_wallet_info(dynamic raw);
+
+  @protected
+  WalletRescanProgress dco_decode_wallet_rescan_progress(dynamic raw);
+
+  @protected
+  WalletTransactionInfo dco_decode_wallet_transaction_info(dynamic raw);
+
+  @protected
+  WatchEntryInfo dco_decode_watch_entry_info(dynamic raw);
+
+  @protected
+  WatchMatchInfo dco_decode_watch_match_info(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ReorgInfo> sse_decode_StreamSink_reorg_info_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<WatchMatchInfo> sse_decode_StreamSink_watch_match_info_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AccumulatorDeltaInfo sse_decode_accumulator_delta_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AccumulatorImportPreview sse_decode_accumulator_import_preview(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AccumulatorInfo sse_decode_accumulator_info(SseDeserializer deserializer);
+
+  @protected
+  AccumulatorPackageInfo sse_decode_accumulator_package_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AccumulatorRootInfo sse_decode_accumulator_root_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AnimatedQr sse_decode_animated_qr(SseDeserializer deserializer);
+
+  @protected
+  BannedPeerInfo sse_decode_banned_peer_info(SseDeserializer deserializer);
+
+  @protected
+  BlockConsumerMetrics sse_decode_block_consumer_metrics(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BlockFeeStatsInfo sse_decode_block_fee_stats_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BlockHeaderInfo sse_decode_block_header_info(SseDeserializer deserializer);
+
+  @protected
+  BlockInfo sse_decode_block_info(SseDeserializer deserializer);
+
+  @protected
+  BonsaiNodeError sse_decode_bonsai_node_error(SseDeserializer deserializer);
+
+  @protected
+  BonsaiNodeErrorKind sse_decode_bonsai_node_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  BlockFeeStatsInfo sse_decode_box_autoadd_block_fee_stats_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IndexedTransactionInfo sse_decode_box_autoadd_indexed_transaction_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InscriptionInfo sse_decode_box_autoadd_inscription_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OpReturnInfo sse_decode_box_autoadd_op_return_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerDiversityPolicy sse_decode_box_autoadd_peer_diversity_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  WalletInfo sse_decode_box_autoadd_wallet_info(SseDeserializer deserializer);
+
+  @protected
+  WalletRescanProgress sse_decode_box_autoadd_wallet_rescan_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  GeoIpDatabaseStatus sse_decode_geo_ip_database_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  IndexedTransactionInfo sse_decode_indexed_transaction_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InscriptionInfo sse_decode_inscription_info(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AccumulatorDeltaInfo> sse_decode_list_accumulator_delta_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AccumulatorRootInfo> sse_decode_list_accumulator_root_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<BannedPeerInfo> sse_decode_list_banned_peer_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<BlockConsumerMetrics> sse_decode_list_block_consumer_metrics(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<BlockFeeStatsInfo> sse_decode_list_block_fee_stats_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<GeoIpDatabaseStatus> sse_decode_list_geo_ip_database_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NodeStatsSample> sse_decode_list_node_stats_sample(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerCensusBucket> sse_decode_list_peer_census_bucket(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerDetailedInfo> sse_decode_list_peer_detailed_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerGroupShare> sse_decode_list_peer_group_share(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<RecentBlockInfo> sse_decode_list_recent_block_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ReorgInfo> sse_decode_list_reorg_info(SseDeserializer deserializer);
+
+  @protected
+  List<StatFieldStatus> sse_decode_list_stat_field_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TransactionInfo> sse_decode_list_transaction_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TrustedPeerConfig> sse_decode_list_trusted_peer_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TrustedPeerStatus> sse_decode_list_trusted_peer_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TxInputInfo> sse_decode_list_tx_input_info(SseDeserializer deserializer);
+
+  @protected
+  List<TxOutputInfo> sse_decode_list_tx_output_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WalletTransactionInfo> sse_decode_list_wallet_transaction_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WatchEntryInfo> sse_decode_list_watch_entry_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WatchMatchInfo> sse_decode_list_watch_match_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NodeStats sse_decode_node_stats(SseDeserializer deserializer);
+
+  @protected
+  NodeStatsSample sse_decode_node_stats_sample(SseDeserializer deserializer);
+
+  @protected
+  OpReturnInfo sse_decode_op_return_info(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  BlockFeeStatsInfo? sse_decode_opt_box_autoadd_block_fee_stats_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IndexedTransactionInfo? sse_decode_opt_box_autoadd_indexed_transaction_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InscriptionInfo? sse_decode_opt_box_autoadd_inscription_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OpReturnInfo? sse_decode_opt_box_autoadd_op_return_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  WalletInfo? sse_decode_opt_box_autoadd_wallet_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletRescanProgress? sse_decode_opt_box_autoadd_wallet_rescan_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  PeerCensusBucket sse_decode_peer_census_bucket(SseDeserializer deserializer);
+
+  @protected
+  PeerCensusInfo sse_decode_peer_census_info(SseDeserializer deserializer);
+
+  @protected
+  PeerDetailedInfo sse_decode_peer_detailed_info(SseDeserializer deserializer);
+
+  @protected
+  PeerDiversityAction sse_decode_peer_diversity_action(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerDiversityInfo sse_decode_peer_diversity_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerDiversityPolicy sse_decode_peer_diversity_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerGroupShare sse_decode_peer_group_share(SseDeserializer deserializer);
+
+  @protected
+  QrScanProgress sse_decode_qr_scan_progress(SseDeserializer deserializer);
+
+  @protected
+  RecentBlockInfo sse_decode_recent_block_info(SseDeserializer deserializer);
+
+  @protected
+  RecentBlocksPage sse_decode_recent_blocks_page(SseDeserializer deserializer);
+
+  @protected
+  ReorgInfo sse_decode_reorg_info(SseDeserializer deserializer);
+
+  @protected
+  SendTransactionResult sse_decode_send_transaction_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StatFieldStatus sse_decode_stat_field_status(SseDeserializer deserializer);
+
+  @protected
+  SyncPhaseKind sse_decode_sync_phase_kind(SseDeserializer deserializer);
+
+  @protected
+  SyncProgressInfo sse_decode_sync_progress_info(SseDeserializer deserializer);
+
+  @protected
+  TransactionInfo sse_decode_transaction_info(SseDeserializer deserializer);
+
+  @protected
+  TrustedPeerConfig sse_decode_trusted_peer_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TrustedPeerMode sse_decode_trusted_peer_mode(SseDeserializer deserializer);
+
+  @protected
+  TrustedPeerStatus sse_decode_trusted_peer_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TxInputInfo sse_decode_tx_input_info(SseDeserializer deserializer);
+
+  @protected
+  TxOutputInfo sse_decode_tx_output_info(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  WalletInfo sse_decode_wallet_info(SseDeserializer deserializer);
+
+  @protected
+  WalletRescanProgress sse_decode_wallet_rescan_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletTransactionInfo sse_decode_wallet_transaction_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatchEntryInfo sse_decode_watch_entry_info(SseDeserializer deserializer);
+
+  @protected
+  WatchMatchInfo sse_decode_watch_match_info(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_reorg_info_Sse(
+    RustStreamSink<ReorgInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_watch_match_info_Sse(
+    RustStreamSink<WatchMatchInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_accumulator_delta_info(
+    AccumulatorDeltaInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_accumulator_import_preview(
+    AccumulatorImportPreview self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+  void sse_encode_accumulator_info(
+    AccumulatorInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+  void sse_encode_accumulator_package_info(
+    AccumulatorPackageInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WalletInfo dco_decode_box_autoadd_wallet_info(dynamic raw);
+  void sse_encode_accumulator_root_info(
+    AccumulatorRootInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  void sse_encode_animated_qr(AnimatedQr self, SseSerializer serializer);
 
   @protected
-  List<PeerDetailedInfo> dco_decode_list_peer_detailed_info(dynamic raw);
+  void sse_encode_banned_peer_info(
+    BannedPeerInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_block_consumer_metrics(
+    BlockConsumerMetrics self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<WalletTransactionInfo> dco_decode_list_wallet_transaction_info(
-    dynamic raw,
+  void sse_encode_block_fee_stats_info(
+    BlockFeeStatsInfo self,
+    SseSerializer serializer,
   );
 
   @protected
-  NodeStats dco_decode_node_stats(dynamic raw);
+  void sse_encode_block_header_info(
+    BlockHeaderInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_block_info(BlockInfo self, SseSerializer serializer);
 
   @protected
-  NodeStats? dco_decode_opt_box_autoadd_node_stats(dynamic raw);
+  void sse_encode_bonsai_node_error(
+    BonsaiNodeError self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  void sse_encode_bonsai_node_error_kind(
+    BonsaiNodeErrorKind self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+  void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  WalletInfo? dco_decode_opt_box_autoadd_wallet_info(dynamic raw);
+  void sse_encode_box_autoadd_block_fee_stats_info(
+    BlockFeeStatsInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PeerDetailedInfo dco_decode_peer_detailed_info(dynamic raw);
+  void sse_encode_box_autoadd_indexed_transaction_info(
+    IndexedTransactionInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SendTransactionResult dco_decode_send_transaction_result(dynamic raw);
+  void sse_encode_box_autoadd_inscription_info(
+    InscriptionInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_box_autoadd_op_return_info(
+    OpReturnInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  void sse_encode_box_autoadd_peer_diversity_policy(
+    PeerDiversityPolicy self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
 
   @protected
-  WalletInfo dco_decode_wallet_info(dynamic raw);
+  void sse_encode_box_autoadd_wallet_info(
+    WalletInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WalletTransactionInfo dco_decode_wallet_transaction_info(dynamic raw);
+  void sse_encode_box_autoadd_wallet_rescan_progress(
+    WalletRescanProgress self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_f_32(double self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  NodeStats sse_decode_box_autoadd_node_stats(SseDeserializer deserializer);
+  void sse_encode_geo_ip_database_status(
+    GeoIpDatabaseStatus self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  void sse_encode_indexed_transaction_info(
+    IndexedTransactionInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WalletInfo sse_decode_box_autoadd_wallet_info(SseDeserializer deserializer);
+  void sse_encode_inscription_info(
+    InscriptionInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  List<PeerDetailedInfo> sse_decode_list_peer_detailed_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_accumulator_delta_info(
+    List<AccumulatorDeltaInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_list_accumulator_root_info(
+    List<AccumulatorRootInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<WalletTransactionInfo> sse_decode_list_wallet_transaction_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_banned_peer_info(
+    List<BannedPeerInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  NodeStats sse_decode_node_stats(SseDeserializer deserializer);
+  void sse_encode_list_block_consumer_metrics(
+    List<BlockConsumerMetrics> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_list_block_fee_stats_info(
+    List<BlockFeeStatsInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NodeStats? sse_decode_opt_box_autoadd_node_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_geo_ip_database_status(
+    List<GeoIpDatabaseStatus> self,
+    SseSerializer serializer,
   );
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  void sse_encode_list_node_stats_sample(
+    List<NodeStatsSample> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+  void sse_encode_list_peer_census_bucket(
+    List<PeerCensusBucket> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WalletInfo? sse_decode_opt_box_autoadd_wallet_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_peer_detailed_info(
+    List<PeerDetailedInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  PeerDetailedInfo sse_decode_peer_detailed_info(SseDeserializer deserializer);
+  void sse_encode_list_peer_group_share(
+    List<PeerGroupShare> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SendTransactionResult sse_decode_send_transaction_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_list_recent_block_info(
+    List<RecentBlockInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_list_reorg_info(
+    List<ReorgInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WalletInfo sse_decode_wallet_info(SseDeserializer deserializer);
+  void sse_encode_list_stat_field_status(
+    List<StatFieldStatus> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WalletTransactionInfo sse_decode_wallet_transaction_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_transaction_info(
+    List<TransactionInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_list_trusted_peer_config(
+    List<TrustedPeerConfig> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_list_trusted_peer_status(
+    List<TrustedPeerStatus> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_list_tx_input_info(
+    List<TxInputInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_node_stats(
-    NodeStats self,
+  void sse_encode_list_tx_output_info(
+    List<TxOutputInfo> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  void sse_encode_list_wallet_transaction_info(
+    List<WalletTransactionInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+  void sse_encode_list_watch_entry_info(
+    List<WatchEntryInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_wallet_info(
-    WalletInfo self,
+  void sse_encode_list_watch_match_info(
+    List<WatchMatchInfo> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_node_stats(NodeStats self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_peer_detailed_info(
-    List<PeerDetailedInfo> self,
+  void sse_encode_node_stats_sample(
+    NodeStatsSample self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+  void sse_encode_op_return_info(OpReturnInfo self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_wallet_transaction_info(
-    List<WalletTransactionInfo> self,
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_block_fee_stats_info(
+    BlockFeeStatsInfo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_node_stats(NodeStats self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_indexed_transaction_info(
+    IndexedTransactionInfo? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_inscription_info(
+    InscriptionInfo? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_node_stats(
-    NodeStats? self,
+  void sse_encode_opt_box_autoadd_op_return_info(
+    OpReturnInfo? self,
     SseSerializer serializer,
   );
 
@@ -248,24 +1142,144 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_wallet_info(
     WalletInfo? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_wallet_rescan_progress(
+    WalletRescanProgress? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_peer_census_bucket(
+    PeerCensusBucket self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_peer_census_info(
+    PeerCensusInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_peer_detailed_info(
     PeerDetailedInfo self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_peer_diversity_action(
+    PeerDiversityAction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_peer_diversity_info(
+    PeerDiversityInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_peer_diversity_policy(
+    PeerDiversityPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_peer_group_share(
+    PeerGroupShare self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_qr_scan_progress(
+    QrScanProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recent_block_info(
+    RecentBlockInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recent_blocks_page(
+    RecentBlocksPage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reorg_info(ReorgInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_send_transaction_result(
     SendTransactionResult self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_stat_field_status(
+    StatFieldStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sync_phase_kind(SyncPhaseKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_progress_info(
+    SyncProgressInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transaction_info(
+    TransactionInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_trusted_peer_config(
+    TrustedPeerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_trusted_peer_mode(
+    TrustedPeerMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_trusted_peer_status(
+    TrustedPeerStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_tx_input_info(TxInputInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tx_output_info(TxOutputInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -284,6 +1298,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_wallet_info(WalletInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_wallet_rescan_progress(
+    WalletRescanProgress self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_transaction_info(
     WalletTransactionInfo self,
@@ -291,7 +1311,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_watch_entry_info(
+    WatchEntryInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watch_match_info(
+    WatchMatchInfo self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Errors from the embedded node.
+class BonsaiNodeError implements FrbException {
+  final BonsaiNodeErrorKind kind;
+  final String message;
+
+  const BonsaiNodeError({required this.kind, required this.message});
+
+  @override
+  int get hashCode => kind.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BonsaiNodeError &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          message == other.message;
+}
+
+/// What went wrong, for the app to tell errors apart without parsing the message.
+enum BonsaiNodeErrorKind { generic, nodeBuildError, nodeExecError, notRunning }
@@ -1,13 +1,71 @@
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::UNIX_EPOCH;
 use tokio::sync::RwLock;
 use tokio::runtime::Handle;
+use tokio::sync::broadcast::error::RecvError;
 use once_cell::sync::Lazy;
-use bitcoin::Network;
 use bdk_floresta::UtreexoNodeConfig;
-use crate::node::control::{start_node, stop_node};
-use crate::node::stats_fetcher::fetch_stats;
+use crate::frb_generated::StreamSink;
+use crate::common::multipart_qr::{
+    DEFAULT_MAX_FRAGMENT_LEN, DEFAULT_UR_TYPE, MultipartDecoder, MultipartEncoder, QR_SCAN,
+};
+use crate::common::util::{
+    default_p2p_port, migrate_legacy_chain_state, network_data_dir, parse_network,
+    reset_chain_state, resolve_peer, unix_now,
+};
+use crate::node::accumulator_package::{
+    AccumulatorPackage, TRUST_WARNING, export_accumulator, load_imported, remove_imported,
+    save_imported, verify_package,
+};
+use crate::node::accumulator_stats::{
+    AccumulatorDelta, AccumulatorShape, accumulator_history, clear_accumulator_history,
+};
+use crate::node::ban_list::{BAN_LIST, BanList};
+use crate::node::block_explorer::{
+    BlockQuery, DecodedTransaction, HeaderSummary, decode_transaction, fetch_block, fetch_header,
+};
+use crate::node::block_pipeline::{
+    clear_block_pipeline, pipeline_metrics, register_handler, registered_handlers,
+    unregister_handler,
+};
+use crate::node::control::{
+    add_peer, builtin_block_handler, disconnect_peer, enforce_bans, start_node, stop_node,
+};
+pub use crate::node::error::BonsaiNodeError;
+use crate::node::fee_stats::{
+    BlockFeeStats, FEE_RATE_BUCKETS, clear_fee_stats, fee_stats_at, recent_fee_stats,
+};
+use crate::node::fixed_peers::{
+    FIXED_PEER_KEEPER, FIXED_PEERS, FixedPeer, FixedPeerKeeper, FixedPeerMode, FixedPeerState,
+    FixedPeers,
+};
+use crate::node::geoip::{
+    AddressKind, GEOIP_READER, GeoIpDatabase, GeoIpDatabaseInfo, GeoIpReader, classify_address,
+    import_database,
+};
+use crate::node::peer_census::{CensusBucket, take_census};
+use crate::node::peer_diversity::{DIVERSITY_POLICY, DiversityAction, DiversityPolicy, GroupShare};
+use crate::node::recent_blocks::{
+    BlockSummary, clear_recent_blocks, recent_blocks, recent_blocks_len,
+};
+use crate::node::reorg::{ReorgEvent, clear_reorg_history, recent_reorgs, subscribe_reorgs};
+use crate::node::stats_fetcher::{
+    STAT_FIELDS, connection_kind_name, service_flag_names, transport_name, variant_name,
+};
+use crate::node::script_analyzer::{InscriptionEnvelope, OpReturnPayload, ScriptKind};
+use crate::node::stats_sampler::{STATS_SAMPLER, StatsSampler, StatsSnapshot};
+use crate::node::watchlist::{
+    Direction, WATCHLIST, WatchEntry, WatchMatch, Watchlist, close_watchlist,
+    subscribe_watch_events,
+};
+use crate::node::sync_progress::{SyncPhase, estimate};
 use crate::node::message::NodeMessage;
-use crate::wallet::manager::{WALLET_MANAGER, WalletManager};
+use crate::wallet::manager::{WALLET_MANAGER, WalletManager, WalletMetadata};
+use crate::wallet::rescan::{Rescan, RescanProgress, RescanStatus, WALLET_RESCAN, birthday_height};
+use crate::wallet::tx_index::{TX_INDEX, TxIndex, close_tx_index, indexed_tx};
 
 #[derive(Debug, Clone)]
 pub struct WalletInfo {
@@ -24,46 +82,103 @@ pub fn init_app() {
 }
 
 pub async fn start_node_service(data_dir: String, network: String) -> Result<(), String> {
-    let mut handle = NODE_HANDLE.write().await;
-    if handle.is_some() {
-        return Err("Node already running".to_string());
-    }
+    let network = parse_network(&network)?;
 
-    let network = match network.to_lowercase().as_str() {
-        "bitcoin" => Network::Bitcoin,
-        "testnet" | "testnet3" => Network::Testnet,
-        "signet" => Network::Signet,
-        "regtest" => Network::Regtest,
-        _ => return Err("Invalid network".to_string()),
-    };
+    // Chain state lives in a per-network subdirectory, the wallet mnemonic stays at the root.
+    migrate_legacy_chain_state(Path::new(&data_dir), network)?;
+    let node_data_dir = network_data_dir(Path::new(&data_dir), network);
 
     let config = UtreexoNodeConfig {
         network,
-        datadir: data_dir.clone(),
+        datadir: node_data_dir.to_string_lossy().to_string(),
+        // Block filters, for wallet rescans.
+        compact_filters: true,
+        filter_start_height: Some(0),
         ..Default::default()
     };
 
-    match start_node(config).await {
+    start_services(&data_dir, node_data_dir, config).await
+}
+
+async fn start_services(
+    data_dir: &str,
+    node_data_dir: PathBuf,
+    mut config: UtreexoNodeConfig,
+) -> Result<(), String> {
+    let mut handle = NODE_HANDLE.write().await;
+    if handle.is_some() {
+        return Err("Node already running".to_string());
+    }
+
+    std::fs::create_dir_all(&node_data_dir)
+        .map_err(|e| format!("Failed to create data dir: {}", e))?;
+
+    // An accumulator imported from another device.
+    if let Some(imported) = load_imported(&node_data_dir) {
+        config.assume_utreexo = Some(imported.to_value()?);
+    }
+
+    // Trusted peers go first, in their fallback order.
+    let fixed_peers = FixedPeers::load(&node_data_dir);
+    let peers = fixed_peers.apply(&mut config).await;
+
+    let network = config.network;
+
+    // The wallet and its transaction index go first, the node feeds them blocks from the start.
+    let manager = WalletManager::init(data_dir, network)
+        .map_err(|e| format!("Wallet init error: {}", e))?;
+    *WALLET_MANAGER.write().await = Some(manager);
+    if let Ok(mut index) = TX_INDEX.lock() {
+        *index = Some(TxIndex::load(&node_data_dir, network));
+    }
+    if let Ok(mut watchlist) = WATCHLIST.lock() {
+        *watchlist = Some(Watchlist::load(&node_data_dir, network));
+    }
+
+    match start_node(config, peers).await {
         Ok(node) => {
-            *handle = Some(node);
-            
-            // Initialize wallet
-            let mut wallet_handle = WALLET_MANAGER.write().await;
-            match WalletManager::init(&data_dir, network) {
-                Ok(manager) => {
-                    *wallet_handle = Some(manager);
-                    Ok(())
-                }
-                Err(e) => Err(format!("Wallet init error: {}", e)),
+            *STATS_SAMPLER.write().await = Some(StatsSampler::spawn(&node, network));
+            if !fixed_peers.is_empty() {
+                let keeper =
+                    FixedPeerKeeper::spawn(&node, fixed_peers.clone(), default_p2p_port(network));
+                *FIXED_PEER_KEEPER.write().await = Some(keeper);
             }
+            *handle = Some(node);
+            *BAN_LIST.write().await = Some(BanList::load(&node_data_dir));
+            *FIXED_PEERS.write().await = Some(fixed_peers);
+            Ok(())
+        }
+        Err(e) => {
+            *WALLET_MANAGER.write().await = None;
+            close_tx_index();
+            close_watchlist();
+            Err(e)
         }
-        Err(e) => Err(e),
     }
 }
 
 pub async fn stop_node_service() -> Result<(), String> {
     let mut handle = NODE_HANDLE.write().await;
     if let Some(node) = handle.take() {
+        // The sampler has to let go of the node before it can be shut down.
+        if let Some(sampler) = STATS_SAMPLER.write().await.take() {
+            sampler.stop().await;
+        }
+        if let Some(keeper) = FIXED_PEER_KEEPER.write().await.take() {
+            keeper.stop().await;
+        }
+        if let Some(rescan) = WALLET_RESCAN.write().await.take() {
+            rescan.stop().await;
+        }
+        *BAN_LIST.write().await = None;
+        *FIXED_PEERS.write().await = None;
+        clear_fee_stats();
+        clear_accumulator_history();
+        clear_recent_blocks();
+        clear_reorg_history();
+        clear_block_pipeline();
+        close_tx_index();
+        close_watchlist();
         match stop_node(node).await {
             Ok(_) => Ok(()),
             Err(e) => Err(e),
@@ -81,8 +196,33 @@ pub async fn is_node_running() -> bool {
 pub struct PeerDetailedInfo {
     pub address: String,
     pub user_agent: String,
+    /// Implementation parsed from the user agent, e.g. "Bitcoin Core"
+    pub implementation: String,
     pub height: u32,
+    /// Derived from the connection kind
     pub is_inbound: bool,
+    pub services: u64,
+    pub service_names: Vec<String>,
+    /// Handshake state, e.g. "Ready"
+    pub status: String,
+    /// "Regular", "Feeler", "Extra" or "Manual"
+    pub connection_kind: String,
+    /// "v1" (plaintext) or "v2" (BIP-0324 encrypted)
+    pub transport: String,
+    /// UNIX timestamp of when the stats sampler first saw the peer connected. Not the connection
+    /// time: it starts over when the app restarts
+    pub first_seen: u64,
+    /// Round trip of the last ping, `None` until the peer has answered one
+    pub ping_ms: Option<u64>,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    /// "public", "private", "loopback", "tor", "i2p" or "cjdns"
+    pub address_type: String,
+    pub asn: Option<u32>,
+    pub asn_organization: Option<String>,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub country_code: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -94,34 +234,1493 @@ pub struct NodeStats {
     pub uptime_secs: u64,
     pub peers_count: usize,
     pub peers: Vec<PeerDetailedInfo>,
+    /// Which fields could be fetched. A failed field keeps its default value.
+    pub field_status: Vec<StatFieldStatus>,
+    /// UNIX timestamp of when these stats were sampled
+    pub sampled_at: u64,
+}
+
+/// Whether a field of [`NodeStats`] could be fetched, and why not
+#[derive(Debug, Clone)]
+pub struct StatFieldStatus {
+    pub field: String,
+    pub available: bool,
+    pub error: Option<String>,
+}
+
+/// Node statistics. Fields the node failed to report are flagged in `field_status`
+/// rather than failing the whole call.
+///
+/// Served from the background sampler's latest snapshot, so this never touches the node.
+pub async fn get_node_stats() -> Result<NodeStats, BonsaiNodeError> {
+    let snapshot = latest_snapshot().await?;
+    let stats = snapshot.statistics;
+
+    let geoip_reader = GEOIP_READER.read().await;
+    let peers = stats.peer_informations.iter().map(|p| {
+        let address_kind = classify_address(p.socket.ip());
+        let geo_info = match (address_kind, geoip_reader.as_ref()) {
+            (AddressKind::Public, Some(reader)) => Some(reader.lookup_all(p.socket.ip())),
+            _ => None,
+        };
+        let asn = geo_info.as_ref().and_then(|g| g.asn.clone());
+        let city = geo_info.and_then(|g| g.city);
+
+        PeerDetailedInfo {
+            address: p.socket.to_string(),
+            user_agent: p.user_agent.clone(),
+            implementation: p.node_impl.to_string(),
+            height: p.initial_height,
+            is_inbound: p.is_inbound,
+            services: p.services.to_u64(),
+            service_names: service_flag_names(p.services),
+            status: variant_name(&p.peer_status),
+            connection_kind: connection_kind_name(&p.connection_kind).to_string(),
+            transport: transport_name(&p.transport_protocol).to_string(),
+            first_seen: p
+                .first_seen
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            ping_ms: p.ping.map(|ping| ping.as_millis() as u64),
+            bytes_sent: p.bytes_sent,
+            bytes_received: p.bytes_received,
+            address_type: address_kind.to_string(),
+            asn: asn.as_ref().map(|a| a.number),
+            asn_organization: asn.map(|a| a.organization),
+            city: city.as_ref().and_then(|c| c.city.clone()),
+            country: city.as_ref().and_then(|c| c.country.clone()),
+            country_code: city.and_then(|c| c.country_code),
+        }
+    }).collect();
+
+    let field_status = STAT_FIELDS.iter().map(|field| {
+        let failure = stats.failures.iter().find(|f| f.field == *field);
+        StatFieldStatus {
+            field: field.to_string(),
+            available: failure.is_none(),
+            error: failure.map(|f| f.error.to_string()),
+        }
+    }).collect();
+
+    Ok(NodeStats {
+        in_ibd: stats.in_ibd,
+        headers: stats.headers,
+        blocks: stats.blocks,
+        user_agent: stats.user_agent,
+        uptime_secs: stats.uptime.as_secs(),
+        peers_count: stats.peer_informations.len(),
+        peers,
+        field_status,
+        sampled_at: snapshot
+            .taken_at
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    })
+}
+
+/// One point of the node's stats history
+#[derive(Debug, Clone)]
+pub struct NodeStatsSample {
+    /// UNIX timestamp of the sample
+    pub timestamp: u64,
+    pub blocks: u32,
+    pub headers: u32,
+    pub peers: u32,
+    pub blocks_per_sec: f64,
+}
+
+/// The stats history of the running node, oldest first, for drawing charts.
+/// Holds up to an hour of samples, one per second.
+pub async fn get_node_stats_history() -> Result<Vec<NodeStatsSample>, BonsaiNodeError> {
+    let sampler = STATS_SAMPLER.read().await;
+    let sampler = sampler.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+
+    Ok(sampler
+        .history()
+        .into_iter()
+        .map(|sample| NodeStatsSample {
+            timestamp: sample
+                .timestamp
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            blocks: sample.blocks,
+            headers: sample.headers,
+            peers: sample.peers as u32,
+            blocks_per_sec: sample.blocks_per_sec,
+        })
+        .collect())
+}
+
+/// UNIX timestamp of when the running node was started
+pub async fn get_node_start_time() -> Result<u64, BonsaiNodeError> {
+    let sampler = STATS_SAMPLER.read().await;
+    let sampler = sampler.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+
+    Ok(sampler
+        .started_at()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0))
+}
+
+/// What the node is currently syncing
+#[derive(Debug, Clone)]
+pub enum SyncPhaseKind {
+    Connecting,
+    Headers,
+    Blocks,
+    /// Validating the blocks below the assume-utreexo snapshot
+    Backfill,
+    /// Downloading compact filters
+    Filters,
+    Synced,
 }
 
-pub async fn get_node_stats() -> Option<NodeStats> {
+#[derive(Debug, Clone)]
+pub struct SyncProgressInfo {
+    pub phase: SyncPhaseKind,
+    /// Percent complete of the current phase, weighted by transaction count on mainnet and by
+    /// block time on the test networks
+    pub percent: f64,
+    /// Validated blocks over the target height, unweighted
+    pub height_percent: f64,
+    pub blocks: u32,
+    pub headers: u32,
+    /// The highest block with a downloaded compact filter
+    pub filter_height: Option<u32>,
+    /// How far the assume-utreexo backfill has validated, while it runs
+    pub backfill_height: Option<u32>,
+    pub target_height: u32,
+    /// Blocks per second the current phase advanced at over the last minute
+    pub blocks_per_sec: f64,
+    /// Estimated seconds left in the current phase, while it advances
+    pub eta_secs: Option<u64>,
+}
+
+/// Sync phase, progress and ETA of the running node
+pub async fn get_sync_progress() -> Result<SyncProgressInfo, BonsaiNodeError> {
+    let snapshot = latest_snapshot().await?;
+    let (network, history) = match STATS_SAMPLER.read().await.as_ref() {
+        Some(sampler) => (sampler.network(), sampler.history()),
+        None => return Err(BonsaiNodeError::not_running()),
+    };
+    let progress = estimate(network, &snapshot.statistics, &history);
+
+    Ok(SyncProgressInfo {
+        phase: match progress.phase {
+            SyncPhase::Connecting => SyncPhaseKind::Connecting,
+            SyncPhase::Headers => SyncPhaseKind::Headers,
+            SyncPhase::Blocks => SyncPhaseKind::Blocks,
+            SyncPhase::Backfill => SyncPhaseKind::Backfill,
+            SyncPhase::Filters => SyncPhaseKind::Filters,
+            SyncPhase::Synced => SyncPhaseKind::Synced,
+        },
+        percent: progress.progress * 100.0,
+        height_percent: progress.height_progress * 100.0,
+        blocks: progress.blocks,
+        headers: progress.headers,
+        filter_height: progress.filter_height,
+        backfill_height: progress.backfill_height,
+        target_height: progress.target_height,
+        blocks_per_sec: progress.blocks_per_sec,
+        eta_secs: progress.eta.map(|eta| eta.as_secs()),
+    })
+}
+
+/// The sampler's latest snapshot, waiting for the first one right after start.
+async fn latest_snapshot() -> Result<StatsSnapshot, BonsaiNodeError> {
+    // Don't hold the sampler lock while waiting, or stopping the node would have to wait too.
+    let mut snapshot = match STATS_SAMPLER.read().await.as_ref() {
+        Some(sampler) => sampler.subscribe(),
+        None => return Err(BonsaiNodeError::not_running()),
+    };
+
+    let latest = snapshot
+        .wait_for(Option::is_some)
+        .await
+        .map_err(|_| BonsaiNodeError::not_running())?
+        .clone();
+    latest.ok_or_else(BonsaiNodeError::not_running)
+}
+
+/// A banned peer address for Flutter
+#[derive(Debug, Clone)]
+pub struct BannedPeerInfo {
+    pub address: String,
+    pub created_at: u64,
+    pub banned_until: u64,
+}
+
+/// Parse a peer given as `host:port` or `host`, using the running network's default port.
+async fn resolve_node_peer(
+    node: &Arc<RwLock<bdk_floresta::Node>>,
+    peer: &str,
+) -> Result<SocketAddr, BonsaiNodeError> {
+    let network = node.read().await.get_config().await?.network;
+    Ok(resolve_peer(peer, default_p2p_port(network)).await?)
+}
+
+/// Parse a ban target, which may be a bare IP or a socket address.
+fn parse_ban_address(address: &str) -> Result<IpAddr, BonsaiNodeError> {
+    let address = address.trim();
+    address
+        .parse::<IpAddr>()
+        .or_else(|_| address.parse::<SocketAddr>().map(|socket| socket.ip()))
+        .map_err(|_| BonsaiNodeError::generic(format!("Invalid peer address: {}", address)))
+}
+
+/// Connect to a peer at `host:port` (or `host`, on the network's default port)
+pub async fn connect_peer(address: String) -> Result<String, BonsaiNodeError> {
+    let handle = NODE_HANDLE.read().await;
+    let node = handle.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+    let peer = resolve_node_peer(node, &address).await?;
+
+    match add_peer(node.clone(), peer).await {
+        NodeMessage::PeerConnected(peer) => Ok(peer),
+        NodeMessage::Error(e) => Err(e),
+        other => Err(BonsaiNodeError::generic(format!("Unexpected reply: {:?}", other))),
+    }
+}
+
+/// Disconnect the peer at the given socket address
+pub async fn disconnect_node_peer(address: String) -> Result<(), BonsaiNodeError> {
+    let peer = address
+        .trim()
+        .parse::<SocketAddr>()
+        .map_err(|e| format!("Invalid peer address '{}': {}", address, e))?;
+
+    let handle = NODE_HANDLE.read().await;
+    let node = handle.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+
+    match disconnect_peer(node.clone(), peer).await {
+        NodeMessage::PeerDisconnected(_) => Ok(()),
+        NodeMessage::Error(e) => Err(e),
+        other => Err(BonsaiNodeError::generic(format!("Unexpected reply: {:?}", other))),
+    }
+}
+
+/// Ban a peer's IP for `duration_secs` and disconnect it if connected
+pub async fn ban_peer(address: String, duration_secs: u64) -> Result<(), BonsaiNodeError> {
+    let ip = parse_ban_address(&address)?;
+
+    {
+        let mut ban_list = BAN_LIST.write().await;
+        let ban_list = ban_list.as_mut().ok_or_else(BonsaiNodeError::not_running)?;
+        ban_list.ban(ip, duration_secs);
+        ban_list.save()?;
+    }
+
     let handle = NODE_HANDLE.read().await;
     if let Some(node) = handle.as_ref() {
-        if let NodeMessage::Statistics(stats) = fetch_stats(node.clone(), None).await {
-            let peers = stats.peer_informations.iter().map(|p| {
-                PeerDetailedInfo {
-                    address: p.socket.to_string(),
-                    user_agent: p.user_agent.clone(),
-                    height: p.initial_height,
-                    is_inbound: !matches!(p.connection_kind, bdk_floresta::ConnectionKind::Feeler),
+        enforce_bans(node.clone()).await;
+    }
+    Ok(())
+}
+
+/// Lift the ban on a peer's IP
+pub async fn unban_peer(address: String) -> Result<bool, BonsaiNodeError> {
+    let ip = parse_ban_address(&address)?;
+
+    let mut ban_list = BAN_LIST.write().await;
+    let ban_list = ban_list.as_mut().ok_or_else(BonsaiNodeError::not_running)?;
+    let was_banned = ban_list.unban(ip);
+    ban_list.save()?;
+    Ok(was_banned)
+}
+
+/// List the currently banned peers
+pub async fn list_banned_peers() -> Result<Vec<BannedPeerInfo>, BonsaiNodeError> {
+    let mut ban_list = BAN_LIST.write().await;
+    let ban_list = ban_list.as_mut().ok_or_else(BonsaiNodeError::not_running)?;
+    ban_list.prune_expired();
+
+    Ok(ban_list
+        .entries()
+        .iter()
+        .map(|ban| BannedPeerInfo {
+            address: ban.address.to_string(),
+            created_at: ban.created_at,
+            banned_until: ban.banned_until,
+        })
+        .collect())
+}
+
+/// Remove every ban
+pub async fn clear_banned_peers() -> Result<(), BonsaiNodeError> {
+    let mut ban_list = BAN_LIST.write().await;
+    let ban_list = ban_list.as_mut().ok_or_else(BonsaiNodeError::not_running)?;
+    ban_list.clear();
+    Ok(ban_list.save()?)
+}
+
+/// Whether the node may connect to peers other than the trusted ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustedPeerMode {
+    Prefer,
+    Only,
+}
+
+/// A trusted peer, as `host:port`, with its fallback order (lowest first)
+#[derive(Debug, Clone)]
+pub struct TrustedPeerConfig {
+    pub address: String,
+    pub priority: u32,
+}
+
+/// Connection state of a trusted peer
+#[derive(Debug, Clone)]
+pub struct TrustedPeerStatus {
+    pub address: String,
+    pub priority: u32,
+    pub resolved_address: Option<String>,
+    pub is_connected: bool,
+    pub error: Option<String>,
+}
+
+/// Save the trusted peers for `network`. They are applied the next time the node starts.
+pub fn set_trusted_peers(
+    data_dir: String,
+    network: String,
+    peers: Vec<TrustedPeerConfig>,
+    mode: TrustedPeerMode,
+) -> Result<(), BonsaiNodeError> {
+    let network = parse_network(&network)?;
+    let fixed_peers = FixedPeers {
+        mode: match mode {
+            TrustedPeerMode::Prefer => FixedPeerMode::Prefer,
+            TrustedPeerMode::Only => FixedPeerMode::Only,
+        },
+        peers: peers
+            .into_iter()
+            .map(|peer| FixedPeer {
+                address: peer.address.trim().to_string(),
+                priority: peer.priority,
+            })
+            .collect(),
+    };
+    fixed_peers.validate()?;
+
+    Ok(fixed_peers.save(&network_data_dir(Path::new(&data_dir), network))?)
+}
+
+/// Get the saved trusted peers for `network`, in fallback order
+pub fn get_trusted_peers(
+    data_dir: String,
+    network: String,
+) -> Result<Vec<TrustedPeerConfig>, BonsaiNodeError> {
+    let network = parse_network(&network)?;
+    let fixed_peers = FixedPeers::load(&network_data_dir(Path::new(&data_dir), network));
+
+    Ok(fixed_peers
+        .ordered()
+        .into_iter()
+        .map(|peer| TrustedPeerConfig {
+            address: peer.address.clone(),
+            priority: peer.priority,
+        })
+        .collect())
+}
+
+/// Connection status of each trusted peer of the running node
+pub async fn get_trusted_peers_status() -> Result<Vec<TrustedPeerStatus>, BonsaiNodeError> {
+    let handle = NODE_HANDLE.read().await;
+    let node = handle.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+    let fixed_peers = FIXED_PEERS.read().await;
+    let fixed_peers = fixed_peers
+        .as_ref()
+        .ok_or_else(BonsaiNodeError::not_running)?;
+
+    let node = node.read().await;
+    let config = node.get_config().await?;
+    let connected = node.get_peer_info().await?;
+
+    Ok(fixed_peers
+        .status(default_p2p_port(config.network), &connected)
+        .await
+        .into_iter()
+        .map(|(peer, state)| {
+            let (resolved_address, is_connected, error) = match state {
+                FixedPeerState::Connected(addr) => (Some(addr.to_string()), true, None),
+                FixedPeerState::Disconnected(addr) => (Some(addr.to_string()), false, None),
+                FixedPeerState::Unresolved(e) => (None, false, Some(e)),
+            };
+            TrustedPeerStatus {
+                address: peer.address,
+                priority: peer.priority,
+                resolved_address,
+                is_connected,
+                error,
+            }
+        })
+        .collect())
+}
+
+/// Metadata of a loaded GeoIP database
+#[derive(Debug, Clone)]
+pub struct GeoIpDatabaseStatus {
+    /// "ASN" or "City"
+    pub kind: String,
+    pub path: String,
+    pub database_type: String,
+    /// UNIX timestamp of when the database was built
+    pub build_epoch: u64,
+    /// Nodes in the database's search tree, which grows with the number of networks it holds
+    pub search_tree_nodes: u32,
+    pub ip_version: u16,
+    pub description: Option<String>,
+}
+
+impl From<GeoIpDatabaseInfo> for GeoIpDatabaseStatus {
+    fn from(info: GeoIpDatabaseInfo) -> Self {
+        GeoIpDatabaseStatus {
+            kind: info.kind.to_string(),
+            path: info.path.to_string_lossy().to_string(),
+            database_type: info.database_type,
+            build_epoch: info.build_epoch,
+            search_tree_nodes: info.search_tree_nodes,
+            ip_version: info.ip_version,
+            description: info.description,
+        }
+    }
+}
+
+/// Swap `db` into the shared reader without restarting the node.
+async fn install_geoip_database(db: GeoIpDatabase) -> GeoIpDatabaseStatus {
+    let status = GeoIpDatabaseStatus::from(db.info());
+
+    let mut reader = GEOIP_READER.write().await;
+    let mut updated = reader.clone().unwrap_or_default();
+    updated.set_database(db);
+    *reader = Some(updated);
+
+    status
+}
+
+/// Load the GeoLite2 ASN and City databases from `dir`, used to enrich peers in [`get_node_stats`]
+pub async fn load_geoip_databases(
+    dir: String,
+) -> Result<Vec<GeoIpDatabaseStatus>, BonsaiNodeError> {
+    let reader = GeoIpReader::from_dir(Path::new(&dir)).map_err(|e| e.to_string())?;
+    let databases = reader.databases().into_iter().map(GeoIpDatabaseStatus::from).collect();
+    *GEOIP_READER.write().await = Some(reader);
+    Ok(databases)
+}
+
+/// Load a single ASN or City database from `path`, replacing the loaded one of the same kind
+pub async fn load_geoip_database(path: String) -> Result<GeoIpDatabaseStatus, BonsaiNodeError> {
+    let db = GeoIpDatabase::open(Path::new(&path))
+        .map_err(|e| format!("Invalid GeoIP database: {}", e))?;
+    Ok(install_geoip_database(db).await)
+}
+
+/// Validate the `.mmdb` file at `source_path`, copy it into `geoip_dir` and start using it
+pub async fn import_geoip_database(
+    source_path: String,
+    geoip_dir: String,
+) -> Result<GeoIpDatabaseStatus, BonsaiNodeError> {
+    let db = import_database(Path::new(&source_path), Path::new(&geoip_dir))?;
+    Ok(install_geoip_database(db).await)
+}
+
+/// Metadata of the loaded GeoIP databases, empty if none is loaded
+pub async fn get_geoip_databases() -> Vec<GeoIpDatabaseStatus> {
+    GEOIP_READER
+        .read()
+        .await
+        .as_ref()
+        .map(|reader| reader.databases().into_iter().map(GeoIpDatabaseStatus::from).collect())
+        .unwrap_or_default()
+}
+
+/// Unload the GeoIP databases; peers are then reported without location data
+pub async fn unload_geoip_databases() {
+    *GEOIP_READER.write().await = None;
+}
+
+/// What to do when outbound peers are concentrated in one ASN or country
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeerDiversityAction {
+    Warn,
+    Rotate,
+}
+
+/// Limits on how many outbound peers may share an ASN or a country
+#[derive(Debug, Clone)]
+pub struct PeerDiversityPolicy {
+    /// Largest share (0.0 - 1.0) of outbound peers in a single ASN
+    pub max_asn_share: f64,
+    /// Largest share (0.0 - 1.0) of outbound peers in a single country
+    pub max_country_share: f64,
+    pub min_peers: u32,
+    pub action: PeerDiversityAction,
+}
+
+/// Outbound peers in one ASN (key "AS1234") or country (key "US")
+#[derive(Debug, Clone)]
+pub struct PeerGroupShare {
+    pub key: String,
+    pub label: String,
+    pub peers: u32,
+    pub share: f64,
+    pub over_limit: bool,
+}
+
+/// Peer diversity of the running node
+#[derive(Debug, Clone)]
+pub struct PeerDiversityInfo {
+    /// 0.0 when every outbound peer shares one ASN, approaching 1.0 as they spread out
+    pub score: f64,
+    pub outbound_peers: u32,
+    pub asn_groups: Vec<PeerGroupShare>,
+    pub country_groups: Vec<PeerGroupShare>,
+    pub concentrated_peers: Vec<String>,
+    pub rotated_peers: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl From<GroupShare> for PeerGroupShare {
+    fn from(share: GroupShare) -> Self {
+        PeerGroupShare {
+            key: share.key,
+            label: share.label,
+            peers: share.peers as u32,
+            share: share.share,
+            over_limit: share.over_limit,
+        }
+    }
+}
+
+/// Set the peer diversity policy, applied on every stats refresh
+pub async fn set_peer_diversity_policy(policy: PeerDiversityPolicy) -> Result<(), BonsaiNodeError> {
+    if !(0.0..=1.0).contains(&policy.max_asn_share)
+        || !(0.0..=1.0).contains(&policy.max_country_share)
+    {
+        return Err(BonsaiNodeError::generic("Shares must be between 0.0 and 1.0"));
+    }
+
+    *DIVERSITY_POLICY.write().await = DiversityPolicy {
+        max_asn_share: policy.max_asn_share,
+        max_country_share: policy.max_country_share,
+        min_peers: policy.min_peers as usize,
+        action: match policy.action {
+            PeerDiversityAction::Warn => DiversityAction::Warn,
+            PeerDiversityAction::Rotate => DiversityAction::Rotate,
+        },
+    };
+    Ok(())
+}
+
+/// Get the current peer diversity policy
+pub async fn get_peer_diversity_policy() -> PeerDiversityPolicy {
+    let policy = DIVERSITY_POLICY.read().await;
+    PeerDiversityPolicy {
+        max_asn_share: policy.max_asn_share,
+        max_country_share: policy.max_country_share,
+        min_peers: policy.min_peers as u32,
+        action: match policy.action {
+            DiversityAction::Warn => PeerDiversityAction::Warn,
+            DiversityAction::Rotate => PeerDiversityAction::Rotate,
+        },
+    }
+}
+
+/// The outbound peers checked against the diversity policy, as of the latest sample
+pub async fn get_peer_diversity() -> Result<PeerDiversityInfo, BonsaiNodeError> {
+    let report = latest_snapshot().await?.diversity;
+
+    Ok(PeerDiversityInfo {
+        score: report.score,
+        outbound_peers: report.outbound_peers as u32,
+        asn_groups: report.asn_groups.into_iter().map(PeerGroupShare::from).collect(),
+        country_groups: report.country_groups.into_iter().map(PeerGroupShare::from).collect(),
+        concentrated_peers: report.concentrated_peers.iter().map(|p| p.to_string()).collect(),
+        rotated_peers: report.to_rotate.iter().map(|p| p.to_string()).collect(),
+        warnings: report.warnings,
+    })
+}
+
+/// Number of peers sharing a country, ASN, implementation or version
+#[derive(Debug, Clone)]
+pub struct PeerCensusBucket {
+    pub key: String,
+    pub label: String,
+    pub peers: u32,
+}
+
+/// Summary of the connected peers for the metrics screen
+#[derive(Debug, Clone)]
+pub struct PeerCensusInfo {
+    pub total_peers: u32,
+    pub by_country: Vec<PeerCensusBucket>,
+    pub by_asn: Vec<PeerCensusBucket>,
+    pub by_implementation: Vec<PeerCensusBucket>,
+    pub by_version: Vec<PeerCensusBucket>,
+    pub utreexo_peers: u32,
+    pub compact_filter_peers: u32,
+}
+
+impl From<CensusBucket> for PeerCensusBucket {
+    fn from(bucket: CensusBucket) -> Self {
+        PeerCensusBucket {
+            key: bucket.key,
+            label: bucket.label,
+            peers: bucket.peers as u32,
+        }
+    }
+}
+
+fn census_buckets(buckets: Vec<CensusBucket>) -> Vec<PeerCensusBucket> {
+    buckets.into_iter().map(PeerCensusBucket::from).collect()
+}
+
+/// Group the connected peers by country, ASN and software
+pub async fn get_peer_census() -> Result<PeerCensusInfo, BonsaiNodeError> {
+    let peers = latest_snapshot().await?.statistics.peer_informations;
+
+    let geoip_reader = GEOIP_READER.read().await;
+    let census = take_census(&peers, geoip_reader.as_ref());
+
+    Ok(PeerCensusInfo {
+        total_peers: census.total_peers as u32,
+        by_country: census_buckets(census.by_country),
+        by_asn: census_buckets(census.by_asn),
+        by_implementation: census_buckets(census.by_implementation),
+        by_version: census_buckets(census.by_version),
+        utreexo_peers: census.utreexo_peers as u32,
+        compact_filter_peers: census.compact_filter_peers as u32,
+    })
+}
+
+/// A block header, for the block explorer
+#[derive(Debug, Clone)]
+pub struct BlockHeaderInfo {
+    pub hash: String,
+    pub height: u32,
+    pub previous_hash: String,
+    pub merkle_root: String,
+    pub version: i32,
+    /// UNIX timestamp from the header
+    pub timestamp: u32,
+    pub bits: u32,
+    pub nonce: u32,
+    pub difficulty: f64,
+}
+
+impl From<HeaderSummary> for BlockHeaderInfo {
+    fn from(summary: HeaderSummary) -> Self {
+        BlockHeaderInfo {
+            hash: summary.hash.to_string(),
+            height: summary.height,
+            previous_hash: summary.header.prev_blockhash.to_string(),
+            merkle_root: summary.header.merkle_root.to_string(),
+            version: summary.header.version.to_consensus(),
+            timestamp: summary.header.time,
+            bits: summary.header.bits.to_consensus(),
+            nonce: summary.header.nonce,
+            difficulty: summary.difficulty,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TxInputInfo {
+    /// `txid:vout` being spent, `None` for the coinbase input
+    pub previous_output: Option<String>,
+    pub sequence: u32,
+    pub script_sig_size: u32,
+    pub witness_items: u32,
+    /// An inscription revealed by this input, if any
+    pub inscription: Option<InscriptionInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InscriptionInfo {
+    /// e.g. "text/plain;charset=utf-8", "image/png"
+    pub content_type: Option<String>,
+    pub content_length: u32,
+    /// The content, if it is text
+    pub text: Option<String>,
+}
+
+impl From<InscriptionEnvelope> for InscriptionInfo {
+    fn from(envelope: InscriptionEnvelope) -> Self {
+        InscriptionInfo {
+            content_type: envelope.content_type,
+            content_length: envelope.content_length as u32,
+            text: envelope.text,
+        }
+    }
+}
+
+/// What an OP_RETURN output carries
+#[derive(Debug, Clone)]
+pub struct OpReturnInfo {
+    pub data_hex: String,
+    /// The data, if it is printable text
+    pub text: Option<String>,
+    /// e.g. "runes", "omni", "witness_commitment"
+    pub protocol: Option<String>,
+}
+
+impl From<OpReturnPayload> for OpReturnInfo {
+    fn from(payload: OpReturnPayload) -> Self {
+        OpReturnInfo {
+            data_hex: payload.hex,
+            text: payload.text,
+            protocol: payload.protocol.map(str::to_string),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TxOutputInfo {
+    pub value_sats: u64,
+    /// e.g. "p2wpkh", "p2tr", "multisig", "op_return", "nonstandard"
+    pub script_type: String,
+    /// `m` of a bare `m`-of-`n` multisig output
+    pub multisig_required: Option<u8>,
+    /// `n` of a bare `m`-of-`n` multisig output
+    pub multisig_total: Option<u8>,
+    pub address: Option<String>,
+    pub script_hex: String,
+    pub op_return: Option<OpReturnInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransactionInfo {
+    pub txid: String,
+    pub is_coinbase: bool,
+    pub size: u32,
+    pub vsize: u32,
+    pub weight: u64,
+    pub inputs: Vec<TxInputInfo>,
+    pub outputs: Vec<TxOutputInfo>,
+    pub output_total_sats: u64,
+}
+
+impl From<DecodedTransaction> for TransactionInfo {
+    fn from(tx: DecodedTransaction) -> Self {
+        TransactionInfo {
+            txid: tx.txid,
+            is_coinbase: tx.is_coinbase,
+            size: tx.size as u32,
+            vsize: tx.vsize as u32,
+            weight: tx.weight,
+            inputs: tx.inputs.into_iter().map(|input| TxInputInfo {
+                previous_output: input.previous_output,
+                sequence: input.sequence,
+                script_sig_size: input.script_sig_size as u32,
+                witness_items: input.witness_items as u32,
+                inscription: input.inscription.map(Into::into),
+            }).collect(),
+            outputs: tx.outputs.into_iter().map(|output| {
+                let (multisig_required, multisig_total) = match output.script_kind {
+                    ScriptKind::Multisig { required, total } => (Some(required), Some(total)),
+                    _ => (None, None),
+                };
+                TxOutputInfo {
+                    value_sats: output.value.to_sat(),
+                    script_type: output.script_kind.name().to_string(),
+                    multisig_required,
+                    multisig_total,
+                    address: output.address,
+                    script_hex: output.script_hex,
+                    op_return: output.op_return.map(Into::into),
                 }
-            }).collect();
-
-            return Some(NodeStats {
-                in_ibd: stats.in_ibd,
-                headers: stats.headers,
-                blocks: stats.blocks,
-                user_agent: stats.user_agent,
-                uptime_secs: stats.uptime.as_secs(),
-                peers_count: stats.peer_informations.len(),
-                peers,
-            });
+            }).collect(),
+            output_total_sats: tx.output_total.to_sat(),
         }
     }
-    None
 }
+
+/// A decoded block, for the block explorer
+#[derive(Debug, Clone)]
+pub struct BlockInfo {
+    pub header: BlockHeaderInfo,
+    pub size: u32,
+    pub weight: u64,
+    pub tx_count: u32,
+    pub transactions: Vec<TransactionInfo>,
+    /// New coins created by this block
+    pub subsidy_sats: u64,
+    /// Coinbase value above the subsidy, i.e. the fees the miner collected
+    pub fees_sats: u64,
+    pub coinbase_value_sats: u64,
+}
+
+/// Fetch a block header by height or hash
+pub async fn get_block_header(query: String) -> Result<BlockHeaderInfo, BonsaiNodeError> {
+    let query = BlockQuery::from_str(&query)?;
+    let handle = NODE_HANDLE.read().await;
+    let node = handle.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+
+    fetch_header(node.clone(), query).await.map(BlockHeaderInfo::from)
+}
+
+/// Fetch a block by height or hash and decode its transactions
+pub async fn get_block(query: String) -> Result<BlockInfo, BonsaiNodeError> {
+    let query = BlockQuery::from_str(&query)?;
+    let handle = NODE_HANDLE.read().await;
+    let node = handle.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+    let block = fetch_block(node.clone(), query).await?;
+
+    Ok(BlockInfo {
+        header: BlockHeaderInfo::from(block.summary),
+        size: block.size as u32,
+        weight: block.weight,
+        tx_count: block.transactions.len() as u32,
+        transactions: block.transactions.into_iter().map(TransactionInfo::from).collect(),
+        subsidy_sats: block.subsidy.subsidy.to_sat(),
+        fees_sats: block.subsidy.fees.to_sat(),
+        coinbase_value_sats: block.subsidy.coinbase_value.to_sat(),
+    })
+}
+
+/// Fee statistics of one block, computed from the outputs it spends
+#[derive(Debug, Clone)]
+pub struct BlockFeeStatsInfo {
+    pub height: u32,
+    pub hash: String,
+    pub tx_count: u32,
+    pub total_fees_sats: u64,
+    /// Fee rates in sat/vB
+    pub min_fee_rate: f64,
+    pub median_fee_rate: f64,
+    pub max_fee_rate: f64,
+    /// Transaction count per bucket of `fee_rate_bucket_bounds`, plus a last one for everything above
+    pub fee_rate_histogram: Vec<u32>,
+    pub segwit_percent: f64,
+    pub taproot_percent: f64,
+    /// Transactions left out of the fee figures because a spent output was missing
+    pub missing_prevouts: u32,
+}
+
+impl From<BlockFeeStats> for BlockFeeStatsInfo {
+    fn from(stats: BlockFeeStats) -> Self {
+        BlockFeeStatsInfo {
+            height: stats.height,
+            hash: stats.hash.to_string(),
+            tx_count: stats.tx_count as u32,
+            total_fees_sats: stats.total_fees.to_sat(),
+            min_fee_rate: stats.min_fee_rate,
+            median_fee_rate: stats.median_fee_rate,
+            max_fee_rate: stats.max_fee_rate,
+            fee_rate_histogram: stats.fee_rate_histogram,
+            segwit_percent: stats.segwit_share * 100.0,
+            taproot_percent: stats.taproot_share * 100.0,
+            missing_prevouts: stats.missing_prevouts as u32,
+        }
+    }
+}
+
+/// Upper bounds in sat/vB of the fee-rate histogram buckets
+pub fn fee_rate_bucket_bounds() -> Vec<f64> {
+    FEE_RATE_BUCKETS.to_vec()
+}
+
+/// Fee statistics of up to `limit` of the most recent blocks, newest first
+pub fn get_recent_block_fee_stats(limit: u32) -> Vec<BlockFeeStatsInfo> {
+    recent_fee_stats(limit as usize)
+        .into_iter()
+        .map(BlockFeeStatsInfo::from)
+        .collect()
+}
+
+/// Fee statistics of the block at `height`, if it is among the recent ones
+pub fn get_block_fee_stats(height: u32) -> Option<BlockFeeStatsInfo> {
+    fee_stats_at(height).map(BlockFeeStatsInfo::from)
+}
+
+/// A recent block on the best chain
+#[derive(Debug, Clone)]
+pub struct RecentBlockInfo {
+    pub height: u32,
+    pub hash: String,
+    /// UNIX timestamp from the header
+    pub time: u32,
+    pub tx_count: u32,
+    pub size: u32,
+    pub weight: u64,
+    pub total_fees_sats: u64,
+    /// Text the miner left in the coinbase, e.g. "/ViaBTC/"
+    pub miner_tag: Option<String>,
+}
+
+impl From<BlockSummary> for RecentBlockInfo {
+    fn from(summary: BlockSummary) -> Self {
+        RecentBlockInfo {
+            height: summary.height,
+            hash: summary.hash.to_string(),
+            time: summary.time,
+            tx_count: summary.tx_count as u32,
+            size: summary.size as u32,
+            weight: summary.weight,
+            total_fees_sats: summary.total_fees.to_sat(),
+            miner_tag: summary.miner_tag,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecentBlocksPage {
+    pub blocks: Vec<RecentBlockInfo>,
+    /// How many recent blocks are kept in total
+    pub total: u32,
+}
+
+/// A page of the recent blocks, newest first
+pub fn get_recent_blocks(offset: u32, limit: u32) -> RecentBlocksPage {
+    RecentBlocksPage {
+        blocks: recent_blocks(offset as usize, limit as usize)
+            .into_iter()
+            .map(RecentBlockInfo::from)
+            .collect(),
+        total: recent_blocks_len() as u32,
+    }
+}
+
+/// A chain reorganization seen by the node
+#[derive(Debug, Clone)]
+pub struct ReorgInfo {
+    /// Height and hash of the last block both branches share
+    pub fork_height: u32,
+    pub fork_hash: String,
+    /// How many blocks were disconnected
+    pub depth: u32,
+    /// Hashes of the disconnected blocks, lowest first
+    pub disconnected_hashes: Vec<String>,
+    /// UNIX timestamp of when the reorg was detected
+    pub detected_at: u64,
+}
+
+impl From<ReorgEvent> for ReorgInfo {
+    fn from(event: ReorgEvent) -> Self {
+        ReorgInfo {
+            fork_height: event.fork_height,
+            fork_hash: event.fork_hash.to_string(),
+            depth: event.depth as u32,
+            disconnected_hashes: event
+                .disconnected
+                .iter()
+                .map(|(_, hash)| hash.to_string())
+                .collect(),
+            detected_at: event
+                .detected_at
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Reorgs seen since the node started, newest first
+pub fn get_recent_reorgs() -> Vec<ReorgInfo> {
+    recent_reorgs().into_iter().map(ReorgInfo::from).collect()
+}
+
+/// Stream every new reorg to the app, as the node detects it
+pub async fn reorg_stream(sink: StreamSink<ReorgInfo>) {
+    let mut events = subscribe_reorgs();
+    loop {
+        match events.recv().await {
+            Ok(event) => {
+                if sink.add(event.into()).is_err() {
+                    return;
+                }
+            }
+            Err(RecvError::Lagged(missed)) => {
+                tracing::warn!("Reorg stream missed {} reorg(s)", missed);
+            }
+            Err(RecvError::Closed) => return,
+        }
+    }
+}
+
+/// How one block consumer (wallet, fee stats, ...) is keeping up with the node
+#[derive(Debug, Clone)]
+pub struct BlockConsumerMetrics {
+    pub name: String,
+    pub delivered: u64,
+    pub processed: u64,
+    /// Blocks waiting in this consumer's queue
+    pub lag: u64,
+    /// Height of the last block processed
+    pub last_height: u32,
+    /// How often the node had to wait for this consumer
+    pub stalls: u64,
+    pub avg_process_micros: u64,
+    /// Still replaying past blocks from the node
+    pub catching_up: bool,
+}
+
+/// Per-consumer metrics of the block pipeline
+pub fn get_block_pipeline_metrics() -> Vec<BlockConsumerMetrics> {
+    pipeline_metrics()
+        .into_iter()
+        .map(|metrics| BlockConsumerMetrics {
+            name: metrics.name.to_string(),
+            delivered: metrics.delivered,
+            processed: metrics.processed,
+            lag: metrics.lag,
+            last_height: metrics.last_height,
+            stalls: metrics.stalls,
+            avg_process_micros: metrics.avg_process_micros,
+            catching_up: metrics.catching_up,
+        })
+        .collect()
+}
+
+/// Add or remove one of the built-in block consumers while the node runs
+pub fn set_block_consumer_enabled(name: String, enabled: bool) -> Result<(), BonsaiNodeError> {
+    if !enabled {
+        unregister_handler(&name);
+        return Ok(());
+    }
+    let handler = builtin_block_handler(&name)
+        .ok_or_else(|| format!("Unknown block consumer {}", name))?;
+    Ok(register_handler(handler)?)
+}
+
+/// Names of the block consumers currently receiving blocks
+pub fn get_block_consumers() -> Vec<String> {
+    registered_handlers()
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// A script or address on the watchlist
+#[derive(Debug, Clone)]
+pub struct WatchEntryInfo {
+    pub script_hex: String,
+    pub address: Option<String>,
+    /// e.g. "p2wpkh", "multisig", "op_return"
+    pub script_type: String,
+    pub label: String,
+    /// UNIX timestamp
+    pub added_at: u64,
+}
+
+impl From<WatchEntry> for WatchEntryInfo {
+    fn from(entry: WatchEntry) -> Self {
+        WatchEntryInfo {
+            script_hex: entry.script,
+            address: entry.address,
+            script_type: entry.script_type,
+            label: entry.label,
+            added_at: entry.added_at,
+        }
+    }
+}
+
+/// Activity of a watched script in a block
+#[derive(Debug, Clone)]
+pub struct WatchMatchInfo {
+    pub script_hex: String,
+    pub label: String,
+    pub txid: String,
+    pub block_hash: String,
+    pub height: u32,
+    /// Paid to the script, rather than spent from it
+    pub incoming: bool,
+    pub amount_sats: u64,
+    /// OP_RETURN data of the transaction, as text or hex
+    pub memo: Option<String>,
+}
+
+impl From<WatchMatch> for WatchMatchInfo {
+    fn from(matched: WatchMatch) -> Self {
+        WatchMatchInfo {
+            script_hex: matched.script,
+            label: matched.label,
+            txid: matched.txid,
+            block_hash: matched.block_hash,
+            height: matched.height,
+            incoming: matched.direction == Direction::Incoming,
+            amount_sats: matched.amount_sats,
+            memo: matched.memo,
+        }
+    }
+}
+
+/// Run `f` on the watchlist of the running node, saving it afterwards
+fn update_watchlist<T>(
+    f: impl FnOnce(&mut Watchlist) -> Result<T, String>,
+) -> Result<T, BonsaiNodeError> {
+    let mut watchlist = WATCHLIST.lock().map_err(|e| e.to_string())?;
+    let watchlist = watchlist.as_mut().ok_or_else(BonsaiNodeError::not_running)?;
+    let result = f(watchlist)?;
+    watchlist.save()?;
+    Ok(result)
+}
+
+/// Watch an address that isn't in the wallet for activity
+pub fn add_watch_address(
+    address: String,
+    label: String,
+) -> Result<WatchEntryInfo, BonsaiNodeError> {
+    update_watchlist(|watchlist| watchlist.add_address(&address, label)).map(Into::into)
+}
+
+/// Watch a raw output script, in hex, for activity
+pub fn add_watch_script(
+    script_hex: String,
+    label: String,
+) -> Result<WatchEntryInfo, BonsaiNodeError> {
+    update_watchlist(|watchlist| watchlist.add_script(&script_hex, label)).map(Into::into)
+}
+
+/// Stop watching an address or script hex. Returns whether it was watched
+pub fn remove_watch(script_or_address: String) -> Result<bool, BonsaiNodeError> {
+    update_watchlist(|watchlist| Ok(watchlist.remove(&script_or_address)))
+}
+
+pub fn get_watchlist() -> Result<Vec<WatchEntryInfo>, BonsaiNodeError> {
+    let watchlist = WATCHLIST.lock().map_err(|e| e.to_string())?;
+    let watchlist = watchlist.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+    Ok(watchlist.entries().iter().cloned().map(Into::into).collect())
+}
+
+/// Up to `limit` watchlist matches, newest first
+pub fn get_watch_matches(limit: u32) -> Result<Vec<WatchMatchInfo>, BonsaiNodeError> {
+    let watchlist = WATCHLIST.lock().map_err(|e| e.to_string())?;
+    let watchlist = watchlist.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+    Ok(watchlist
+        .matches(limit as usize)
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Stream every new watchlist match to the app, as blocks come in
+pub async fn watch_activity_stream(sink: StreamSink<WatchMatchInfo>) {
+    let mut events = subscribe_watch_events();
+    loop {
+        match events.recv().await {
+            Ok(matched) => {
+                if sink.add(matched.into()).is_err() {
+                    return;
+                }
+            }
+            Err(RecvError::Lagged(missed)) => {
+                tracing::warn!("Watch activity stream missed {} match(es)", missed);
+            }
+            Err(RecvError::Closed) => return,
+        }
+    }
+}
+
+/// The node's utreexo accumulator, packaged to bootstrap another device
+#[derive(Debug, Clone)]
+pub struct AccumulatorPackageInfo {
+    /// Checksummed text, for copying or a QR code
+    pub package: String,
+    pub height: u32,
+    pub block_hash: String,
+    pub leaves: u64,
+    pub roots: Vec<String>,
+    /// e.g. "bitcoin", "signet"; `None` if the magic is unknown
+    pub network: Option<String>,
+}
+
+impl AccumulatorPackageInfo {
+    fn new(package: &AccumulatorPackage, encoded: String) -> Self {
+        AccumulatorPackageInfo {
+            package: encoded,
+            height: package.height,
+            block_hash: package.block_hash.to_string(),
+            leaves: package.stump.leaves,
+            roots: package.stump.roots.iter().map(|root| root.to_string()).collect(),
+            network: package.network().map(|network| network.to_string()),
+        }
+    }
+}
+
+/// Export the accumulator at the node's validation tip
+pub async fn export_accumulator_package() -> Result<AccumulatorPackageInfo, BonsaiNodeError> {
+    let node = NODE_HANDLE
+        .read()
+        .await
+        .clone()
+        .ok_or_else(BonsaiNodeError::not_running)?;
+    let package = export_accumulator(node).await?;
+    let encoded = package.encode()?;
+    Ok(AccumulatorPackageInfo::new(&package, encoded))
+}
+
+/// What importing a package would mean, to show the user before they accept it
+#[derive(Debug, Clone)]
+pub struct AccumulatorImportPreview {
+    pub package: AccumulatorPackageInfo,
+    /// Whether the package's block is in our own header chain, at its height
+    pub in_header_chain: bool,
+    /// Why it can't be imported, if it can't
+    pub problem: Option<String>,
+    pub trust_warning: String,
+}
+
+/// Decode a package and check it against the running node, without importing it
+pub async fn inspect_accumulator_package(
+    package: String,
+) -> Result<AccumulatorImportPreview, BonsaiNodeError> {
+    let node = NODE_HANDLE
+        .read()
+        .await
+        .clone()
+        .ok_or_else(BonsaiNodeError::not_running)?;
+    let decoded = AccumulatorPackage::decode(&package)?;
+    let problem = verify_package(node, &decoded).await.err().map(|e| e.to_string());
+
+    Ok(AccumulatorImportPreview {
+        package: AccumulatorPackageInfo::new(&decoded, package.trim().to_string()),
+        in_header_chain: problem.is_none(),
+        problem,
+        trust_warning: TRUST_WARNING.to_string(),
+    })
+}
+
+/// Import a package as the assume-utreexo starting point of this network.
+/// `accept_trust` must be set once the user has seen the trust warning.
+/// Takes effect on the next start with an empty chain state, see [`reset_node_chain_state`]
+pub async fn import_accumulator_package(
+    package: String,
+    accept_trust: bool,
+) -> Result<(), BonsaiNodeError> {
+    if !accept_trust {
+        return Err(BonsaiNodeError::generic(TRUST_WARNING));
+    }
+    let node = NODE_HANDLE
+        .read()
+        .await
+        .clone()
+        .ok_or_else(BonsaiNodeError::not_running)?;
+    let decoded = AccumulatorPackage::decode(&package)?;
+    verify_package(node.clone(), &decoded).await?;
+
+    let data_dir = node.read().await.get_config().await?.datadir;
+    save_imported(Path::new(&data_dir), &decoded)?;
+    Ok(())
+}
+
+/// Forget an imported accumulator. Returns whether there was one.
+pub fn remove_imported_accumulator(
+    data_dir: String,
+    network: String,
+) -> Result<bool, BonsaiNodeError> {
+    let network = parse_network(&network)?;
+    Ok(remove_imported(&network_data_dir(Path::new(&data_dir), network)))
+}
+
+/// Delete the node's blocks, headers and filters for `network`, so the next start syncs again,
+/// from an imported accumulator if there is one. The node must be stopped first.
+pub async fn reset_node_chain_state(
+    data_dir: String,
+    network: String,
+) -> Result<(), BonsaiNodeError> {
+    if NODE_HANDLE.read().await.is_some() {
+        return Err(BonsaiNodeError::generic(
+            "Stop the node before resetting its chain state",
+        ));
+    }
+    let network = parse_network(&network)?;
+    Ok(reset_chain_state(&network_data_dir(Path::new(&data_dir), network))?)
+}
+
+/// One tree of the utreexo forest
+#[derive(Debug, Clone)]
+pub struct AccumulatorRootInfo {
+    pub hash: String,
+    /// Tree height; the tree holds 2^row leaves
+    pub row: u8,
+    /// Position in the forest, leaves numbered first, then each row above
+    pub position: u64,
+}
+
+/// How one block changed the accumulator
+#[derive(Debug, Clone)]
+pub struct AccumulatorDeltaInfo {
+    pub height: u32,
+    pub hash: String,
+    pub leaves_added: u32,
+    pub leaves_deleted: u32,
+    /// Leaf count after the block; `None` if the history doesn't reach the validation tip yet
+    pub leaves_after: Option<u64>,
+}
+
+impl From<AccumulatorDelta> for AccumulatorDeltaInfo {
+    fn from(delta: AccumulatorDelta) -> Self {
+        AccumulatorDeltaInfo {
+            height: delta.height,
+            hash: delta.hash.to_string(),
+            leaves_added: delta.leaves_added,
+            leaves_deleted: delta.leaves_deleted,
+            leaves_after: None,
+        }
+    }
+}
+
+/// The node's utreexo accumulator at its validation tip
+#[derive(Debug, Clone)]
+pub struct AccumulatorInfo {
+    pub height: u32,
+    pub block_hash: String,
+    /// Every leaf ever added; deletions don't lower it
+    pub leaves: u64,
+    pub forest_rows: u8,
+    /// Tallest tree first
+    pub roots: Vec<AccumulatorRootInfo>,
+    pub roots_size_bytes: u32,
+    pub serialized_size_bytes: u32,
+    /// SHA-256 of the serialized accumulator, in hex. Matches on any node at the same block
+    pub commitment: String,
+    /// Newest first
+    pub history: Vec<AccumulatorDeltaInfo>,
+}
+
+/// The accumulator's structure, and its changes over up to `history_len` recent blocks
+pub async fn get_accumulator_info(history_len: u32) -> Result<AccumulatorInfo, BonsaiNodeError> {
+    let node = NODE_HANDLE
+        .read()
+        .await
+        .clone()
+        .ok_or_else(BonsaiNodeError::not_running)?;
+    let node = node.read().await;
+    let height = node.get_validation_height()?;
+    let block_hash = node.get_block_hash(height)?;
+    let shape = AccumulatorShape::new(&node.get_accumulator()?)?;
+
+    let mut history: Vec<AccumulatorDeltaInfo> = accumulator_history(history_len as usize)
+        .into_iter()
+        .map(AccumulatorDeltaInfo::from)
+        .collect();
+    // Leaf counts can be worked back from the tip, as far as the history has no gaps.
+    let mut leaves = shape.leaves;
+    let mut expected = height;
+    for delta in history.iter_mut() {
+        if delta.height != expected {
+            break;
+        }
+        delta.leaves_after = Some(leaves);
+        leaves = leaves.saturating_sub(delta.leaves_added as u64);
+        expected = expected.saturating_sub(1);
+    }
+
+    Ok(AccumulatorInfo {
+        height,
+        block_hash: block_hash.to_string(),
+        leaves: shape.leaves,
+        forest_rows: shape.forest_rows,
+        roots: shape
+            .roots
+            .into_iter()
+            .map(|root| AccumulatorRootInfo {
+                hash: root.hash,
+                row: root.row,
+                position: root.position,
+            })
+            .collect(),
+        roots_size_bytes: shape.roots_size as u32,
+        serialized_size_bytes: shape.serialized_size as u32,
+        commitment: shape.commitment.to_string(),
+        history,
+    })
+}
+
+/// Frames of an animated QR code, to show in a loop
+#[derive(Debug, Clone)]
+pub struct AnimatedQr {
+    pub ur_type: String,
+    /// Frames needed at the least; the rest let a scanner make up for missed ones
+    pub fragment_count: u32,
+    /// `ur:` strings, one per QR code. A single one if the payload fits it
+    pub frames: Vec<String>,
+}
+
+/// Encode `data` as UR frames (BCR-2020-005), e.g. an accumulator package, a PSBT or a
+/// descriptor export. `ur_type` defaults to "bytes", `max_fragment_len` to 200 bytes,
+/// `frame_count` to twice the fragment count
+pub fn encode_animated_qr(
+    data: Vec<u8>,
+    ur_type: Option<String>,
+    max_fragment_len: Option<u32>,
+    frame_count: Option<u32>,
+) -> Result<AnimatedQr, BonsaiNodeError> {
+    let mut encoder = MultipartEncoder::new(
+        &data,
+        ur_type.as_deref().unwrap_or(DEFAULT_UR_TYPE),
+        max_fragment_len
+            .map(|len| len as usize)
+            .unwrap_or(DEFAULT_MAX_FRAGMENT_LEN),
+    )?;
+
+    let fragment_count = encoder.fragment_count();
+    let frame_count = if encoder.is_single_part() {
+        1
+    } else {
+        frame_count
+            .map(|count| count as usize)
+            .unwrap_or(fragment_count * 2)
+            .max(fragment_count)
+    };
+
+    Ok(AnimatedQr {
+        ur_type: encoder.ur_type().to_string(),
+        fragment_count: fragment_count as u32,
+        frames: (0..frame_count).map(|_| encoder.next_part()).collect(),
+    })
+}
+
+/// How far the QR scan has got
+#[derive(Debug, Clone)]
+pub struct QrScanProgress {
+    /// `None` until the first frame is read
+    pub ur_type: Option<String>,
+    /// 0.0 to 1.0
+    pub progress: f64,
+    pub received_frames: u32,
+    pub known_fragments: u32,
+    pub fragment_count: u32,
+    pub complete: bool,
+    /// The payload, once complete
+    pub data: Option<Vec<u8>>,
+}
+
+/// Start a new QR scan, dropping any scan in progress
+pub fn start_qr_scan() {
+    if let Ok(mut scan) = QR_SCAN.lock() {
+        *scan = Some(MultipartDecoder::default());
+    }
+}
+
+/// Feed one scanned QR code to the scan, in any order. Starts a scan if none is running
+pub fn receive_qr_frame(frame: String) -> Result<QrScanProgress, BonsaiNodeError> {
+    let mut scan = QR_SCAN.lock().map_err(|e| e.to_string())?;
+    let decoder = scan.get_or_insert_with(MultipartDecoder::default);
+    decoder.receive(&frame)?;
+
+    let progress = decoder.progress();
+    let data = decoder.payload().transpose()?;
+    Ok(QrScanProgress {
+        progress: progress.fraction(),
+        ur_type: progress.ur_type,
+        received_frames: progress.received_frames,
+        known_fragments: progress.known_fragments,
+        fragment_count: progress.fragment_count,
+        complete: progress.complete,
+        data,
+    })
+}
+
+/// Drop the scan in progress
+pub fn cancel_qr_scan() {
+    if let Ok(mut scan) = QR_SCAN.lock() {
+        scan.take();
+    }
+}
+
 pub async fn get_wallet_info() -> Option<WalletInfo> {
     tracing::info!("get_wallet_info called");
     let mut handle = WALLET_MANAGER.write().await;
@@ -171,16 +1770,150 @@ pub async fn get_wallet_transactions() -> Vec<WalletTransactionInfo> {
     Vec::new()
 }
 
-/// Sync wallet - process pending blocks from the node
+/// A confirmed wallet transaction, from the on-disk transaction index
+#[derive(Debug, Clone)]
+pub struct IndexedTransactionInfo {
+    pub block_hash: String,
+    pub height: u32,
+    /// Position in its block, the coinbase being 0
+    pub position: u32,
+    pub raw_tx_hex: String,
+    pub transaction: TransactionInfo,
+}
+
+/// Details of a confirmed wallet transaction, without fetching its block.
+/// `None` if it isn't in the index.
+pub fn get_transaction_detail(
+    txid: String,
+) -> Result<Option<IndexedTransactionInfo>, BonsaiNodeError> {
+    let Some((indexed, network)) = indexed_tx(&txid)? else {
+        return Ok(None);
+    };
+    let tx = indexed
+        .transaction()
+        .ok_or_else(|| format!("Transaction {} is corrupt in the index", indexed.txid))?;
+
+    Ok(Some(IndexedTransactionInfo {
+        transaction: decode_transaction(&tx, network).into(),
+        block_hash: indexed.block_hash,
+        height: indexed.height,
+        position: indexed.position,
+        raw_tx_hex: indexed.raw_tx,
+    }))
+}
+
+/// Sync wallet - blocks are applied as the node delivers them,
+/// this returns how many were applied since the last call
 pub async fn sync_wallet() -> Result<u32, String> {
     let mut handle = WALLET_MANAGER.write().await;
     if let Some(manager) = handle.as_mut() {
-        let processed = manager.process_pending_blocks();
+        let processed = manager.take_applied_blocks();
         return Ok(processed as u32);
     }
     Err("Wallet not initialized".to_string())
 }
 
+/// How a wallet rescan is going
+#[derive(Debug, Clone)]
+pub struct WalletRescanProgress {
+    pub start_height: u32,
+    /// Next block to check
+    pub current_height: u32,
+    pub tip_height: u32,
+    /// 0.0 - 1.0
+    pub progress: f64,
+    pub matched_blocks: u32,
+    pub found_transactions: u32,
+    pub script_count: u32,
+    pub running: bool,
+    /// Paused until the node has downloaded the filter of `current_height`
+    pub waiting_for_filters: bool,
+    /// The highest downloaded filter, while waiting for filters
+    pub filter_height: Option<u32>,
+    pub cancelled: bool,
+    pub error: Option<String>,
+}
+
+impl From<RescanProgress> for WalletRescanProgress {
+    fn from(progress: RescanProgress) -> Self {
+        WalletRescanProgress {
+            start_height: progress.start_height,
+            current_height: progress.current_height,
+            tip_height: progress.tip_height,
+            progress: progress.fraction(),
+            matched_blocks: progress.matched_blocks,
+            found_transactions: progress.found_transactions,
+            script_count: progress.script_count,
+            running: matches!(
+                progress.status,
+                RescanStatus::Running | RescanStatus::WaitingForFilters
+            ),
+            waiting_for_filters: progress.status == RescanStatus::WaitingForFilters,
+            filter_height: progress.filter_height,
+            cancelled: progress.status == RescanStatus::Cancelled,
+            error: match progress.status {
+                RescanStatus::Failed(e) => Some(e),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Rescan the wallet with the node's block filters, from `from_height`
+/// or else from the wallet's birthday. Returns the start height
+pub async fn start_wallet_rescan(from_height: Option<u32>) -> Result<u32, BonsaiNodeError> {
+    let node = NODE_HANDLE
+        .read()
+        .await
+        .clone()
+        .ok_or_else(BonsaiNodeError::not_running)?;
+
+    let mut rescan = WALLET_RESCAN.write().await;
+    if rescan.as_ref().is_some_and(Rescan::is_running) {
+        return Err(BonsaiNodeError::generic("A rescan is already running"));
+    }
+
+    let start_height = match from_height {
+        Some(height) => height,
+        None => {
+            let birthday = WALLET_MANAGER
+                .read()
+                .await
+                .as_ref()
+                .ok_or_else(|| BonsaiNodeError::generic("Wallet not initialized"))?
+                .metadata
+                .birthday;
+            match birthday {
+                Some(birthday) => birthday_height(node.clone(), birthday).await?,
+                None => 0,
+            }
+        }
+    };
+
+    *rescan = Some(Rescan::spawn(&node, start_height));
+    Ok(start_height)
+}
+
+/// Progress of the current or last wallet rescan
+pub async fn get_wallet_rescan_progress() -> Option<WalletRescanProgress> {
+    WALLET_RESCAN
+        .read()
+        .await
+        .as_ref()
+        .map(|rescan| rescan.progress().into())
+}
+
+/// Stop the running rescan, keeping what it found. Returns whether one was running
+pub async fn cancel_wallet_rescan() -> bool {
+    match WALLET_RESCAN.read().await.as_ref() {
+        Some(rescan) if rescan.is_running() => {
+            rescan.cancel();
+            true
+        }
+        _ => false,
+    }
+}
+
 /// Send transaction result
 #[derive(Debug, Clone)]
 pub struct SendTransactionResult {
@@ -251,12 +1984,19 @@ pub fn create_wallet_mnemonic(data_dir: String) -> Result<String, String> {
     // Save mnemonic to file
     std::fs::write(&mnemonic_path, mnemonic.to_string())
         .map_err(|e| format!("Failed to save mnemonic: {}", e))?;
+    WalletMetadata { birthday: Some(unix_now()) }.save(Path::new(&data_dir))?;
     
     Ok(mnemonic.to_string())
 }
 
 /// Import an existing mnemonic phrase
-pub fn import_wallet_mnemonic(data_dir: String, mnemonic: String) -> Result<(), String> {
+/// `birthday` is the UNIX time the wallet was first used, if known;
+/// without it a rescan has to start from the first block
+pub fn import_wallet_mnemonic(
+    data_dir: String,
+    mnemonic: String,
+    birthday: Option<u64>,
+) -> Result<(), String> {
     let mnemonic_path = std::path::Path::new(&data_dir).join("mnemonic.txt");
     
     // Validate the mnemonic
@@ -270,6 +2010,7 @@ pub fn import_wallet_mnemonic(data_dir: String, mnemonic: String) -> Result<(),
     // Save mnemonic to file
     std::fs::write(&mnemonic_path, mnemonic.trim())
         .map_err(|e| format!("Failed to save mnemonic: {}", e))?;
+    WalletMetadata { birthday }.save(Path::new(&data_dir))?;
     
     Ok(())
 }
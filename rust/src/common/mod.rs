@@ -1,3 +1,4 @@
 // pub(crate) mod interface;
 pub(crate) mod logger;
+pub(crate) mod multipart_qr;
 pub(crate) mod util;
@@ -0,0 +1,867 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use bitcoin::hashes::Hash;
+use bitcoin::hashes::sha256;
+use once_cell::sync::Lazy;
+
+/// The UR type of plain bytes.
+pub const DEFAULT_UR_TYPE: &str = "bytes";
+
+/// Fragment size for frames that most phone cameras read reliably.
+pub const DEFAULT_MAX_FRAGMENT_LEN: usize = 200;
+
+const MIN_FRAGMENT_LEN: usize = 10;
+
+/// Most fragments a scanned code may have. Every mixed frame shuffles all of them, at a cost
+/// that grows with the square of their count, so a forged frame must not pick it freely.
+const MAX_SEQ_LEN: u64 = 2_000;
+
+/// Largest payload a scanned code may carry, far beyond any PSBT worth animating.
+const MAX_MESSAGE_LEN: u64 = 1_000_000;
+
+const UR_SCHEME: &str = "ur:";
+
+/// Bytewords, in byte order. Frames use their minimal form, the first and last letter.
+const BYTEWORDS: [&str; 256] = [
+    "able", "acid", "also", "apex", "aqua", "arch", "atom", "aunt", "away", "axis", "back", "bald",
+    "barn", "belt", "beta", "bias", "blue", "body", "brag", "brew", "bulb", "buzz", "calm", "cash",
+    "cats", "chef", "city", "claw", "code", "cola", "cook", "cost", "crux", "curl", "cusp", "cyan",
+    "dark", "data", "days", "deli", "dice", "diet", "door", "down", "draw", "drop", "drum", "dull",
+    "duty", "each", "easy", "echo", "edge", "epic", "even", "exam", "exit", "eyes", "fact", "fair",
+    "fern", "figs", "film", "fish", "fizz", "flap", "flew", "flux", "foxy", "free", "frog", "fuel",
+    "fund", "gala", "game", "gear", "gems", "gift", "girl", "glow", "good", "gray", "grim", "guru",
+    "gush", "gyro", "half", "hang", "hard", "hawk", "heat", "help", "high", "hill", "holy", "hope",
+    "horn", "huts", "iced", "idea", "idle", "inch", "inky", "into", "iris", "iron", "item", "jade",
+    "jazz", "join", "jolt", "jowl", "judo", "jugs", "jump", "junk", "jury", "keep", "keno", "kept",
+    "keys", "kick", "kiln", "king", "kite", "kiwi", "knob", "lamb", "lava", "lazy", "leaf", "legs",
+    "liar", "limp", "lion", "list", "logo", "loud", "love", "luau", "luck", "lung", "main", "many",
+    "math", "maze", "memo", "menu", "meow", "mild", "mint", "miss", "monk", "nail", "navy", "need",
+    "news", "next", "noon", "note", "numb", "obey", "oboe", "omit", "onyx", "open", "oval", "owls",
+    "paid", "part", "peck", "play", "plus", "poem", "pool", "pose", "puff", "puma", "purr", "quad",
+    "quiz", "race", "ramp", "real", "redo", "rich", "road", "rock", "roof", "ruby", "ruin", "runs",
+    "rust", "safe", "saga", "scar", "sets", "silk", "skew", "slot", "soap", "solo", "song", "stub",
+    "surf", "swan", "taco", "task", "taxi", "tent", "tied", "time", "tiny", "toil", "tomb", "toys",
+    "trip", "tuna", "twin", "ugly", "undo", "unit", "urge", "user", "vast", "very", "veto", "vial",
+    "vibe", "view", "visa", "void", "vows", "wall", "wand", "warm", "wasp", "wave", "waxy", "webs",
+    "what", "when", "whiz", "wolf", "work", "yank", "yawn", "yell", "yoga", "yurt", "zaps", "zero",
+    "zest", "zinc", "zone", "zoom",
+];
+
+/// The scan in progress, fed one camera frame at a time.
+pub(crate) static QR_SCAN: Lazy<Mutex<Option<MultipartDecoder>>> = Lazy::new(|| Mutex::new(None));
+
+/// Splits a payload into animated QR frames, following the Uniform Resources spec (BCR-2020-005):
+/// `ur:<type>/<seq>-<count>/<bytewords>`.
+///
+/// The first `fragment_count` frames carry one fragment each. Frames after them are fountain
+/// coded, each the XOR of a pseudo-random set of fragments, so a scanner that missed some frames
+/// catches up from whichever ones it sees next, in any order.
+///
+/// The payload is wrapped as a CBOR byte string, which is what the `bytes` and `psbt` types
+/// expect.
+pub struct MultipartEncoder {
+    ur_type: String,
+    message: Vec<u8>,
+    fragments: Vec<Vec<u8>>,
+    checksum: u32,
+    seq_num: u32,
+}
+
+impl MultipartEncoder {
+    pub fn new(data: &[u8], ur_type: &str, max_fragment_len: usize) -> Result<Self, String> {
+        let ur_type = ur_type.trim().to_ascii_lowercase();
+        if !is_valid_type(&ur_type) {
+            return Err(format!("Invalid UR type: {}", ur_type));
+        }
+        if data.is_empty() {
+            return Err("Nothing to encode".to_string());
+        }
+        if max_fragment_len < MIN_FRAGMENT_LEN {
+            return Err(format!(
+                "Fragments must be at least {} bytes",
+                MIN_FRAGMENT_LEN
+            ));
+        }
+
+        let mut message = Vec::with_capacity(data.len() + 9);
+        write_header(&mut message, 2, data.len() as u64);
+        message.extend_from_slice(data);
+
+        let fragment_len = nominal_fragment_len(message.len(), max_fragment_len);
+        let fragments = message
+            .chunks(fragment_len)
+            .map(|chunk| {
+                let mut fragment = chunk.to_vec();
+                fragment.resize(fragment_len, 0);
+                fragment
+            })
+            .collect();
+
+        Ok(MultipartEncoder {
+            ur_type,
+            checksum: crc32(&message),
+            message,
+            fragments,
+            seq_num: 0,
+        })
+    }
+
+    pub fn ur_type(&self) -> &str {
+        &self.ur_type
+    }
+
+    pub fn fragment_count(&self) -> usize {
+        self.fragments.len()
+    }
+
+    /// A payload that fits one frame is shown as a still code.
+    pub fn is_single_part(&self) -> bool {
+        self.fragments.len() == 1
+    }
+
+    /// The next frame to show. There is no last one, fountain frames go on for as long as asked.
+    pub fn next_part(&mut self) -> String {
+        self.seq_num = self.seq_num.wrapping_add(1).max(1);
+        self.part(self.seq_num)
+    }
+
+    /// Frame `seq_num`, counting from 1.
+    pub fn part(&self, seq_num: u32) -> String {
+        if self.is_single_part() {
+            return format!(
+                "{}{}/{}",
+                UR_SCHEME,
+                self.ur_type,
+                bytewords_encode(&self.message)
+            )
+            .to_ascii_uppercase();
+        }
+
+        let seq_len = self.fragments.len();
+        let mut data = vec![0; self.fragments[0].len()];
+        for index in choose_fragments(seq_num, seq_len, self.checksum) {
+            xor_into(&mut data, &self.fragments[index]);
+        }
+
+        let mut part = Vec::with_capacity(data.len() + 24);
+        write_header(&mut part, 4, 5);
+        write_header(&mut part, 0, seq_num as u64);
+        write_header(&mut part, 0, seq_len as u64);
+        write_header(&mut part, 0, self.message.len() as u64);
+        write_header(&mut part, 0, self.checksum as u64);
+        write_header(&mut part, 2, data.len() as u64);
+        part.extend_from_slice(&data);
+
+        // Upper case fits the denser alphanumeric mode of QR codes.
+        format!(
+            "{}{}/{}-{}/{}",
+            UR_SCHEME,
+            self.ur_type,
+            seq_num,
+            seq_len,
+            bytewords_encode(&part)
+        )
+        .to_ascii_uppercase()
+    }
+}
+
+/// How far a scan has got.
+#[derive(Clone, Debug)]
+pub struct ScanProgress {
+    /// `None` until the first frame is read.
+    pub ur_type: Option<String>,
+    /// Distinct frames read so far.
+    pub received_frames: u32,
+    /// Fragments recovered so far, out of `fragment_count`.
+    pub known_fragments: u32,
+    /// 0 until the first frame is read.
+    pub fragment_count: u32,
+    pub complete: bool,
+}
+
+impl ScanProgress {
+    /// Done share of the scan, from 0.0 to 1.0.
+    pub fn fraction(&self) -> f64 {
+        if self.complete {
+            1.0
+        } else if self.fragment_count == 0 {
+            0.0
+        } else {
+            // Not done until the checksum says so.
+            (self.known_fragments as f64 / self.fragment_count as f64).min(0.99)
+        }
+    }
+}
+
+/// The parameters every frame of one payload shares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PartParams {
+    seq_len: usize,
+    message_len: usize,
+    checksum: u32,
+    fragment_len: usize,
+}
+
+/// Reassembles a payload from the frames of a [`MultipartEncoder`], or any other UR encoder,
+/// read in any order. Repeated frames are ignored.
+#[derive(Debug, Default)]
+pub struct MultipartDecoder {
+    ur_type: Option<String>,
+    params: Option<PartParams>,
+    seen: HashSet<u32>,
+    /// Fragments known on their own, by index.
+    fragments: HashMap<usize, Vec<u8>>,
+    /// Fountain parts that still mix more than one unknown fragment.
+    mixed: Vec<(BTreeSet<usize>, Vec<u8>)>,
+    message: Option<Vec<u8>>,
+}
+
+impl MultipartDecoder {
+    /// Read one scanned frame. Returns whether it told us anything new.
+    ///
+    /// Fails on anything that isn't a UR frame, or on a frame of another payload than the
+    /// frames before it.
+    pub fn receive(&mut self, frame: &str) -> Result<bool, String> {
+        if self.message.is_some() {
+            return Ok(false);
+        }
+
+        let frame = frame.trim().to_ascii_lowercase();
+        let rest = frame.strip_prefix(UR_SCHEME).ok_or("Not a UR code")?;
+        let components: Vec<&str> = rest.split('/').collect();
+        let (ur_type, sequence, body) = match components[..] {
+            [ur_type, body] => (ur_type, None, body),
+            [ur_type, sequence, body] => (ur_type, Some(sequence), body),
+            _ => return Err("Malformed UR code".to_string()),
+        };
+        if !is_valid_type(ur_type) {
+            return Err(format!("Invalid UR type: {}", ur_type));
+        }
+        if self
+            .ur_type
+            .as_deref()
+            .is_some_and(|known| known != ur_type)
+        {
+            return Err(format!(
+                "This code is a {}, not a {} like the frames before it",
+                ur_type,
+                self.ur_type.as_deref().unwrap_or_default()
+            ));
+        }
+        let body = bytewords_decode(body)?;
+
+        let Some(sequence) = sequence else {
+            if self.params.is_some() {
+                return Err("A single code in the middle of an animated one".to_string());
+            }
+            self.ur_type = Some(ur_type.to_string());
+            self.message = Some(body);
+            return Ok(true);
+        };
+
+        let (seq_num, seq_len) = sequence
+            .split_once('-')
+            .and_then(|(num, len)| Some((num.parse::<u32>().ok()?, len.parse::<usize>().ok()?)))
+            .ok_or("Malformed UR sequence")?;
+        let part = Part::decode(&body)?;
+        if part.seq_num != seq_num || part.seq_len != seq_len || seq_num == 0 || seq_len == 0 {
+            return Err("The frame's sequence doesn't match its content".to_string());
+        }
+
+        let params = PartParams {
+            seq_len: part.seq_len,
+            message_len: part.message_len,
+            checksum: part.checksum,
+            fragment_len: part.data.len(),
+        };
+        let capacity = params.seq_len.checked_mul(params.fragment_len);
+        if params.fragment_len == 0 || capacity.is_none_or(|capacity| capacity < params.message_len)
+        {
+            return Err("The frame's fragment doesn't fit the payload".to_string());
+        }
+        match self.params {
+            Some(known) if known != params => {
+                return Err("This frame belongs to another animated code".to_string());
+            }
+            Some(_) => {}
+            None => {
+                self.params = Some(params);
+                self.ur_type = Some(ur_type.to_string());
+            }
+        }
+
+        if !self.seen.insert(seq_num) {
+            return Ok(false);
+        }
+        let indexes = choose_fragments(seq_num, params.seq_len, params.checksum)
+            .into_iter()
+            .collect();
+        self.reduce(indexes, part.data);
+
+        if self.fragments.len() == params.seq_len {
+            let mut message: Vec<u8> = (0..params.seq_len)
+                .flat_map(|index| self.fragments[&index].iter().copied())
+                .collect();
+            message.truncate(params.message_len);
+            if crc32(&message) != params.checksum {
+                *self = MultipartDecoder::default();
+                return Err("Checksum mismatch, the scan starts over".to_string());
+            }
+            self.message = Some(message);
+        }
+        Ok(true)
+    }
+
+    /// Peel known fragments off `data`, and whatever that makes known off the mixed parts.
+    fn reduce(&mut self, indexes: BTreeSet<usize>, data: Vec<u8>) {
+        let mut queue = vec![(indexes, data)];
+        while let Some((mut indexes, mut data)) = queue.pop() {
+            indexes.retain(|index| match self.fragments.get(index) {
+                Some(fragment) => {
+                    xor_into(&mut data, fragment);
+                    false
+                }
+                None => true,
+            });
+
+            match indexes.len() {
+                0 => {}
+                1 => {
+                    let index = *indexes.first().unwrap_or(&0);
+                    self.fragments.insert(index, data);
+                    let (solvable, mixed) = std::mem::take(&mut self.mixed)
+                        .into_iter()
+                        .partition(|(mixed, _)| mixed.contains(&index));
+                    self.mixed = mixed;
+                    queue.extend(solvable);
+                }
+                _ => {
+                    if !self.mixed.iter().any(|(mixed, _)| *mixed == indexes) {
+                        self.mixed.push((indexes, data));
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn progress(&self) -> ScanProgress {
+        ScanProgress {
+            ur_type: self.ur_type.clone(),
+            received_frames: self.seen.len().max(self.message.is_some() as usize) as u32,
+            known_fragments: self
+                .params
+                .map(|params| self.fragments.len().min(params.seq_len))
+                .unwrap_or(self.message.is_some() as usize) as u32,
+            fragment_count: self
+                .params
+                .map(|params| params.seq_len)
+                .unwrap_or(self.message.is_some() as usize) as u32,
+            complete: self.message.is_some(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.message.is_some()
+    }
+
+    pub fn ur_type(&self) -> Option<&str> {
+        self.ur_type.as_deref()
+    }
+
+    /// The scanned payload, unwrapped from its CBOR byte string, once the scan is complete.
+    pub fn payload(&self) -> Option<Result<Vec<u8>, String>> {
+        let message = self.message.as_ref()?;
+        let mut reader = CborReader::new(message);
+        Some(
+            reader
+                .bytes()
+                .map(|bytes| bytes.to_vec())
+                .map_err(|_| "The scanned payload is not a byte string".to_string()),
+        )
+    }
+}
+
+/// The CBOR body of a multi-part frame: `[seq_num, seq_len, message_len, checksum, data]`.
+struct Part {
+    seq_num: u32,
+    seq_len: usize,
+    message_len: usize,
+    checksum: u32,
+    data: Vec<u8>,
+}
+
+impl Part {
+    fn decode(body: &[u8]) -> Result<Self, String> {
+        let malformed = |_: ()| "Malformed UR frame".to_string();
+        let mut reader = CborReader::new(body);
+        if reader.header(4).map_err(malformed)? != 5 {
+            return Err("Malformed UR frame".to_string());
+        }
+
+        let seq_num = reader.uint().map_err(malformed)?;
+        let seq_len = reader.uint().map_err(malformed)?;
+        let message_len = reader.uint().map_err(malformed)?;
+        if seq_len > MAX_SEQ_LEN || message_len > MAX_MESSAGE_LEN {
+            return Err("This animated code is too large".to_string());
+        }
+        let checksum = reader.uint().map_err(malformed)?;
+        let data = reader.bytes().map_err(malformed)?.to_vec();
+
+        Ok(Part {
+            seq_num: u32::try_from(seq_num).map_err(|_| "UR sequence number out of range")?,
+            seq_len: seq_len as usize,
+            message_len: message_len as usize,
+            checksum: u32::try_from(checksum).map_err(|_| "UR checksum out of range")?,
+            data,
+        })
+    }
+}
+
+/// Just enough CBOR for unsigned integers, byte strings and array headers.
+struct CborReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CborReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        CborReader { data }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ()> {
+        if self.data.len() < len {
+            return Err(());
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    /// The argument of the next item, which must be of `major` type.
+    fn header(&mut self, major: u8) -> Result<u64, ()> {
+        let initial = self.take(1)?[0];
+        if initial >> 5 != major {
+            return Err(());
+        }
+        let len = match initial & 0x1f {
+            value @ 0..=23 => return Ok(value as u64),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            _ => return Err(()),
+        };
+        Ok(self
+            .take(len)?
+            .iter()
+            .fold(0, |value, byte| (value << 8) | *byte as u64))
+    }
+
+    fn uint(&mut self) -> Result<u64, ()> {
+        self.header(0)
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], ()> {
+        let len = self.header(2)?;
+        self.take(usize::try_from(len).map_err(|_| ())?)
+    }
+}
+
+fn write_header(out: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    if value < 24 {
+        out.push(major | value as u8);
+    } else if value <= u8::MAX as u64 {
+        out.push(major | 24);
+        out.push(value as u8);
+    } else if value <= u16::MAX as u64 {
+        out.push(major | 25);
+        out.extend_from_slice(&(value as u16).to_be_bytes());
+    } else if value <= u32::MAX as u64 {
+        out.push(major | 26);
+        out.extend_from_slice(&(value as u32).to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+fn is_valid_type(ur_type: &str) -> bool {
+    !ur_type.is_empty()
+        && ur_type
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// The shortest fragment length that splits `message_len` bytes into fragments of at most
+/// `max_fragment_len`, so the last fragment needs as little padding as possible.
+fn nominal_fragment_len(message_len: usize, max_fragment_len: usize) -> usize {
+    let max_fragment_count = (message_len / MIN_FRAGMENT_LEN).max(1);
+    let mut fragment_len = message_len;
+    for fragment_count in 1..=max_fragment_count {
+        fragment_len = message_len.div_ceil(fragment_count);
+        if fragment_len <= max_fragment_len {
+            break;
+        }
+    }
+    fragment_len
+}
+
+/// The fragments mixed into part `seq_num`, as every UR implementation picks them.
+fn choose_fragments(seq_num: u32, seq_len: usize, checksum: u32) -> Vec<usize> {
+    if seq_num as usize <= seq_len {
+        return vec![seq_num as usize - 1];
+    }
+
+    let mut seed = [0; 8];
+    seed[..4].copy_from_slice(&seq_num.to_be_bytes());
+    seed[4..].copy_from_slice(&checksum.to_be_bytes());
+    let mut rng = Xoshiro256::from_seed(&seed);
+
+    let degree = choose_degree(seq_len, &mut rng);
+    let mut remaining: Vec<usize> = (0..seq_len).collect();
+    let mut shuffled = Vec::with_capacity(seq_len);
+    while !remaining.is_empty() {
+        let index = rng.next_int(0, remaining.len() as u64 - 1) as usize;
+        shuffled.push(remaining.remove(index));
+    }
+    shuffled.truncate(degree);
+    shuffled
+}
+
+/// Degree `d` is picked with a probability proportional to `1 / d`.
+fn choose_degree(seq_len: usize, rng: &mut Xoshiro256) -> usize {
+    let weights: Vec<f64> = (1..=seq_len).map(|degree| 1.0 / degree as f64).collect();
+    AliasSampler::new(&weights).next(rng) + 1
+}
+
+/// Walker's alias method, built exactly like the reference implementation so every decoder
+/// derives the same fragment sets.
+struct AliasSampler {
+    probs: Vec<f64>,
+    aliases: Vec<usize>,
+}
+
+impl AliasSampler {
+    fn new(weights: &[f64]) -> Self {
+        let n = weights.len();
+        let sum: f64 = weights.iter().sum();
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w * n as f64 / sum).collect();
+
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+        for j in (0..n).rev() {
+            if scaled[j] < 1.0 {
+                small.push(j);
+            } else {
+                large.push(j);
+            }
+        }
+
+        let mut probs = vec![0.0; n];
+        let mut aliases = vec![0; n];
+        while !small.is_empty() && !large.is_empty() {
+            let (Some(a), Some(g)) = (small.pop(), large.pop()) else {
+                break;
+            };
+            probs[a] = scaled[a];
+            aliases[a] = g;
+            scaled[g] += scaled[a] - 1.0;
+            if scaled[g] < 1.0 {
+                small.push(g);
+            } else {
+                large.push(g);
+            }
+        }
+        for j in large.into_iter().chain(small) {
+            probs[j] = 1.0;
+        }
+
+        AliasSampler { probs, aliases }
+    }
+
+    fn next(&self, rng: &mut Xoshiro256) -> usize {
+        let r1 = rng.next_double();
+        let r2 = rng.next_double();
+        let i = (self.probs.len() as f64 * r1) as usize;
+        if r2 < self.probs[i] {
+            i
+        } else {
+            self.aliases[i]
+        }
+    }
+}
+
+/// xoshiro256**, seeded with the SHA-256 of the seed bytes.
+struct Xoshiro256 {
+    s: [u64; 4],
+}
+
+impl Xoshiro256 {
+    fn from_seed(seed: &[u8]) -> Self {
+        let digest = sha256::Hash::hash(seed).to_byte_array();
+        let mut s = [0; 4];
+        for (i, word) in s.iter_mut().enumerate() {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&digest[i * 8..(i + 1) * 8]);
+            *word = u64::from_be_bytes(bytes);
+        }
+        Xoshiro256 { s }
+    }
+
+    fn next(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        result
+    }
+
+    fn next_double(&mut self) -> f64 {
+        self.next() as f64 / (u64::MAX as f64 + 1.0)
+    }
+
+    fn next_int(&mut self, low: u64, high: u64) -> u64 {
+        (self.next_double() * (high - low + 1) as f64) as u64 + low
+    }
+}
+
+fn xor_into(target: &mut [u8], other: &[u8]) {
+    for (a, b) in target.iter_mut().zip(other) {
+        *a ^= b;
+    }
+}
+
+/// CRC-32 (ISO-HDLC), as used by UR for message and bytewords checksums.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Minimal bytewords of `data` followed by its CRC-32.
+fn bytewords_encode(data: &[u8]) -> String {
+    let checksum = crc32(data).to_be_bytes();
+    data.iter()
+        .chain(checksum.iter())
+        .flat_map(|byte| {
+            let word = BYTEWORDS[*byte as usize].as_bytes();
+            [word[0] as char, word[3] as char]
+        })
+        .collect()
+}
+
+fn bytewords_decode(encoded: &str) -> Result<Vec<u8>, String> {
+    static LOOKUP: Lazy<HashMap<[u8; 2], u8>> = Lazy::new(|| {
+        BYTEWORDS
+            .iter()
+            .enumerate()
+            .map(|(byte, word)| ([word.as_bytes()[0], word.as_bytes()[3]], byte as u8))
+            .collect()
+    });
+
+    let encoded = encoded.as_bytes();
+    if encoded.len() % 2 != 0 {
+        return Err("Malformed bytewords".to_string());
+    }
+    let mut data = encoded
+        .chunks(2)
+        .map(|pair| {
+            LOOKUP
+                .get(&[pair[0], pair[1]])
+                .copied()
+                .ok_or("Malformed bytewords")
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    if data.len() < 4 {
+        return Err("Malformed bytewords".to_string());
+    }
+
+    let checksum = data.split_off(data.len() - 4);
+    if crc32(&data).to_be_bytes()[..] != checksum[..] {
+        return Err("Bytewords checksum mismatch, try scanning again".to_string());
+    }
+    Ok(data)
+}
+
+/// Reference vectors of the Uniform Resources spec, BCR-2020-005, and its reference
+/// implementation. Fountain-coded frames only decode across wallets if these match bit for bit.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The spec's test message: `len` bytes drawn from a generator seeded with `seed`.
+    fn make_message(len: usize, seed: &str) -> Vec<u8> {
+        let mut rng = Xoshiro256::from_seed(seed.as_bytes());
+        (0..len).map(|_| rng.next_int(0, 255) as u8).collect()
+    }
+
+    const MULTIPART_UR: &[&str] = &[
+        "ur:bytes/1-9/lpadascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtdkgslpgh",
+        "ur:bytes/2-9/lpaoascfadaxcywenbpljkhdcagwdpfnsboxgwlbaawzuefywkdplrsrjynbvygabwjldapfcsgmghhkhstlrdcxaefz",
+        "ur:bytes/3-9/lpaxascfadaxcywenbpljkhdcahelbknlkuejnbadmssfhfrdpsbiegecpasvssovlgeykssjykklronvsjksopdzmol",
+        "ur:bytes/4-9/lpaaascfadaxcywenbpljkhdcasotkhemthydawydtaxneurlkosgwcekonertkbrlwmplssjtammdplolsbrdzcrtas",
+        "ur:bytes/5-9/lpahascfadaxcywenbpljkhdcatbbdfmssrkzmcwnezelennjpfzbgmuktrhtejscktelgfpdlrkfyfwdajldejokbwf",
+        "ur:bytes/6-9/lpamascfadaxcywenbpljkhdcackjlhkhybssklbwefectpfnbbectrljectpavyrolkzczcpkmwidmwoxkilghdsowp",
+        "ur:bytes/7-9/lpatascfadaxcywenbpljkhdcavszmwnjkwtclrtvaynhpahrtoxmwvwatmedibkaegdosftvandiodagdhthtrlnnhy",
+        "ur:bytes/8-9/lpayascfadaxcywenbpljkhdcadmsponkkbbhgsoltjntegepmttmoonftnbuoiyrehfrtsabzsttorodklubbuyaetk",
+        "ur:bytes/9-9/lpasascfadaxcywenbpljkhdcajskecpmdckihdyhphfotjojtfmlnwmadspaxrkytbztpbauotbgtgtaeaevtgavtny",
+        "ur:bytes/10-9/lpbkascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtwdkiplzs",
+        "ur:bytes/11-9/lpbdascfadaxcywenbpljkhdcahelbknlkuejnbadmssfhfrdpsbiegecpasvssovlgeykssjykklronvsjkvetiiapk",
+        "ur:bytes/12-9/lpbnascfadaxcywenbpljkhdcarllaluzmdmgstospeyiefmwejlwtpedamktksrvlcygmzemovovllarodtmtbnptrs",
+        "ur:bytes/13-9/lpbtascfadaxcywenbpljkhdcamtkgtpknghchchyketwsvwgwfdhpgmgtylctotzopdrpayoschcmhplffziachrfgd",
+        "ur:bytes/14-9/lpbaascfadaxcywenbpljkhdcapazewnvonnvdnsbyleynwtnsjkjndeoldydkbkdslgjkbbkortbelomueekgvstegt",
+        "ur:bytes/15-9/lpbsascfadaxcywenbpljkhdcaynmhpddpzmversbdqdfyrehnqzlugmjzmnmtwmrouohtstgsbsahpawkditkckynwt",
+        "ur:bytes/16-9/lpbeascfadaxcywenbpljkhdcawygekobamwtlihsnpalnsghenskkiynthdzotsimtojetprsttmukirlrsbtamjtpd",
+        "ur:bytes/17-9/lpbyascfadaxcywenbpljkhdcamklgftaxykpewyrtqzhydntpnytyisincxmhtbceaykolduortotiaiaiafhiaoyce",
+        "ur:bytes/18-9/lpbgascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtntwkbkwy",
+        "ur:bytes/19-9/lpbwascfadaxcywenbpljkhdcadekicpaajootjzpsdrbalpeywllbdsnbinaerkurspbncxgslgftvtsrjtksplcpeo",
+        "ur:bytes/20-9/lpbbascfadaxcywenbpljkhdcayapmrleeleaxpasfrtrdkncffwjyjzgyetdmlewtkpktgllepfrltataztksmhkbot",
+    ];
+
+    #[test]
+    fn xoshiro256() {
+        let mut rng = Xoshiro256::from_seed(b"Wolf");
+        let numbers: Vec<u64> = (0..100).map(|_| rng.next() % 100).collect();
+        assert_eq!(
+            numbers,
+            [
+                42, 81, 85, 8, 82, 84, 76, 73, 70, 88, 2, 74, 40, 48, 77, 54, 88, 7, 5, 88, 37, 25,
+                82, 13, 69, 59, 30, 39, 11, 82, 19, 99, 45, 87, 30, 15, 32, 22, 89, 44, 92, 77, 29,
+                78, 4, 92, 44, 68, 92, 69, 1, 42, 89, 50, 37, 84, 63, 34, 32, 3, 17, 62, 40, 98,
+                82, 89, 24, 43, 85, 39, 15, 3, 99, 29, 20, 42, 27, 10, 85, 66, 50, 35, 69, 70, 70,
+                74, 30, 13, 72, 54, 11, 5, 70, 55, 91, 52, 10, 43, 43, 52
+            ]
+        );
+    }
+
+    #[test]
+    fn crc32_checksums() {
+        assert_eq!(crc32(b"Hello, world!"), 0xebe6c6e6);
+        assert_eq!(crc32(b"Wolf"), 0x598c84dc);
+    }
+
+    #[test]
+    fn minimal_bytewords() {
+        let data = [0, 1, 2, 128, 255];
+        assert_eq!(bytewords_encode(&data), "aeadaolazmjendeoti");
+        assert_eq!(bytewords_decode("aeadaolazmjendeoti").unwrap(), data);
+        assert!(bytewords_decode("aeadaolazmjendeotx").is_err());
+    }
+
+    #[test]
+    fn single_part_ur() {
+        let message = make_message(50, "Wolf");
+        let encoder = MultipartEncoder::new(&message, "bytes", 1000).unwrap();
+        assert!(encoder.is_single_part());
+        assert_eq!(
+            encoder.part(1).to_lowercase(),
+            "ur:bytes/hdeymejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtgwdpfnsboxgwlbaawzuefywkdplrsrjynbvygabwjldapfcsdwkbrkch"
+        );
+    }
+
+    #[test]
+    fn multipart_ur() {
+        let message = make_message(256, "Wolf");
+        let mut encoder = MultipartEncoder::new(&message, "bytes", 30).unwrap();
+        let parts: Vec<String> = (0..20)
+            .map(|_| encoder.next_part().to_lowercase())
+            .collect();
+        assert_eq!(parts, MULTIPART_UR);
+    }
+
+    #[test]
+    fn fragment_choice() {
+        let message = make_message(1024, "Wolf");
+        let checksum = crc32(&message);
+        let fragment_len = nominal_fragment_len(message.len(), 100);
+        let seq_len = message.len().div_ceil(fragment_len);
+        assert_eq!(seq_len, 11);
+
+        let expected: [&[usize]; 30] = [
+            &[0],
+            &[1],
+            &[2],
+            &[3],
+            &[4],
+            &[5],
+            &[6],
+            &[7],
+            &[8],
+            &[9],
+            &[10],
+            &[9],
+            &[2, 5, 6, 8, 9, 10],
+            &[8],
+            &[1, 5],
+            &[1],
+            &[0, 2, 4, 5, 8, 10],
+            &[5],
+            &[2],
+            &[2],
+            &[0, 1, 3, 4, 5, 7, 9, 10],
+            &[0, 1, 2, 3, 5, 6, 8, 9, 10],
+            &[0, 2, 4, 5, 7, 8, 9, 10],
+            &[3, 5],
+            &[4],
+            &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+            &[0, 1, 3, 4, 5, 6, 7, 9, 10],
+            &[6],
+            &[5, 6],
+            &[7],
+        ];
+        for (seq_num, expected) in (1..).zip(expected) {
+            let mut indexes = choose_fragments(seq_num, seq_len, checksum);
+            indexes.sort_unstable();
+            assert_eq!(indexes, expected, "part {}", seq_num);
+        }
+    }
+
+    #[test]
+    fn decodes_mixed_parts() {
+        let message = make_message(32767, "Wolf");
+        let mut encoder = MultipartEncoder::new(&message, "bytes", 1000).unwrap();
+        let seq_len = encoder.fragment_count();
+
+        // Skip every plain fragment so the message is only recoverable by peeling mixed parts.
+        let mut decoder = MultipartDecoder::default();
+        for _ in 0..seq_len {
+            encoder.next_part();
+        }
+        let mut received = 0;
+        while !decoder.is_complete() {
+            decoder.receive(&encoder.next_part()).unwrap();
+            received += 1;
+            assert!(received < seq_len * 3, "decoder never completed");
+        }
+        assert_eq!(decoder.ur_type(), Some("bytes"));
+        assert_eq!(decoder.payload().unwrap().unwrap(), message);
+    }
+
+    #[test]
+    fn refuses_oversized_sequences() {
+        // A forged frame claiming a million fragments, each a byte long.
+        let mut body = Vec::new();
+        write_header(&mut body, 4, 5);
+        for value in [1_000_001, 1_000_000, 1_000_000, 0] {
+            write_header(&mut body, 0, value);
+        }
+        write_header(&mut body, 2, 1);
+        body.push(0);
+        let frame = format!("ur:bytes/1000001-1000000/{}", bytewords_encode(&body));
+
+        let mut decoder = MultipartDecoder::default();
+        assert!(decoder.receive(&frame).is_err());
+    }
+}
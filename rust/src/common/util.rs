@@ -1,5 +1,26 @@
 use core::fmt::Display;
+use std::fs;
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::path::PathBuf;
 use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use bitcoin::Network;
+use tokio::net::lookup_host;
+use tracing::info;
+
+use crate::node::block_pipeline::CHECKPOINTS_FILE;
+
+/// Seconds since the UNIX epoch.
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
 
 /// Format a [`Duration`] to HH:MM:SS.
 pub(crate) fn format_duration(duration: Duration) -> String {
@@ -37,3 +58,117 @@ pub(crate) fn format_thousands<T: Display>(n: T) -> String {
     }
     format!("{}{}", out, decimal_part)
 }
+
+/// Parse a network name as sent by the app into a [`Network`].
+///
+/// Accepts the names used by [`Network`]'s `Display` implementation plus
+/// the common aliases (`mainnet`, `testnet3`).
+pub(crate) fn parse_network(network: &str) -> Result<Network, String> {
+    match network.trim().to_lowercase().as_str() {
+        "bitcoin" | "mainnet" | "main" => Ok(Network::Bitcoin),
+        "testnet" | "testnet3" => Ok(Network::Testnet),
+        "testnet4" => Ok(Network::Testnet4),
+        "signet" => Ok(Network::Signet),
+        "regtest" => Ok(Network::Regtest),
+        other => Err(format!("Unknown network: {}", other)),
+    }
+}
+
+/// The per-network data directory, so chain state for different networks never mixes.
+pub(crate) fn network_data_dir(base: &Path, network: Network) -> PathBuf {
+    base.join(network.to_string())
+}
+
+/// The only network older versions ran, with its chain state directly in the base directory.
+const LEGACY_NETWORK: Network = Network::Signet;
+
+/// What the node keeps in its data directory. The first one marks a chain state.
+const CHAIN_STATE_ENTRIES: &[&str] = &["chaindata", "cfilters", "peers.json", "anchors.json"];
+
+/// The part of [`CHAIN_STATE_ENTRIES`] that depends on the chain, the rest are known peers.
+const CHAIN_ENTRIES: &[&str] = &["chaindata", "cfilters"];
+
+/// Move a chain state left in `base` by an older version into the [`network_data_dir`] of
+/// [`LEGACY_NETWORK`], so upgrading doesn't start a fresh sync. Does nothing for other networks,
+/// or once the per-network directory has a chain state of its own.
+pub(crate) fn migrate_legacy_chain_state(base: &Path, network: Network) -> Result<(), String> {
+    let target = network_data_dir(base, network);
+    if network != LEGACY_NETWORK
+        || !base.join(CHAIN_STATE_ENTRIES[0]).exists()
+        || target.join(CHAIN_STATE_ENTRIES[0]).exists()
+    {
+        return Ok(());
+    }
+
+    fs::create_dir_all(&target).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    for entry in CHAIN_STATE_ENTRIES {
+        let source = base.join(entry);
+        if source.exists() {
+            fs::rename(&source, target.join(entry))
+                .map_err(|e| format!("Failed to move {} into {}: {}", entry, network, e))?;
+        }
+    }
+    info!(
+        "Moved the {} chain state into {}",
+        network,
+        target.to_string_lossy()
+    );
+    Ok(())
+}
+
+/// Delete the blocks, headers and filters the node keeps in `data_dir`, so the next start syncs
+/// from scratch, or from an imported accumulator. Known peers are kept.
+pub(crate) fn reset_chain_state(data_dir: &Path) -> Result<(), String> {
+    for entry in CHAIN_ENTRIES {
+        let path = data_dir.join(entry);
+        if path.exists() {
+            fs::remove_dir_all(&path).map_err(|e| format!("Failed to delete {}: {}", entry, e))?;
+        }
+    }
+    // The block handlers' checkpoints point into the chain that is gone.
+    let checkpoints = data_dir.join(CHECKPOINTS_FILE);
+    if checkpoints.exists() {
+        fs::remove_file(&checkpoints)
+            .map_err(|e| format!("Failed to delete {}: {}", CHECKPOINTS_FILE, e))?;
+    }
+    info!("Reset the chain state in {}", data_dir.to_string_lossy());
+    Ok(())
+}
+
+/// The default P2P port of a [`Network`], used when a peer is given without one.
+pub(crate) fn default_p2p_port(network: Network) -> u16 {
+    match network {
+        Network::Bitcoin => 8333,
+        Network::Testnet => 18333,
+        Network::Testnet4 => 48333,
+        Network::Signet => 38333,
+        Network::Regtest => 18444,
+    }
+}
+
+/// Resolve `host:port` (or a bare `host` or IP, using `default_port`) to a [`SocketAddr`].
+pub(crate) async fn resolve_peer(peer: &str, default_port: u16) -> Result<SocketAddr, String> {
+    let peer = peer.trim();
+    if let Ok(addr) = peer.parse::<SocketAddr>() {
+        return Ok(addr);
+    }
+    // Before the `host:port` check, a bare IPv6 address has colons too.
+    let bare = peer
+        .strip_prefix('[')
+        .and_then(|ip| ip.strip_suffix(']'))
+        .unwrap_or(peer);
+    if let Ok(ip) = bare.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(ip, default_port));
+    }
+
+    let resolved = if peer.contains(':') {
+        lookup_host(peer).await
+    } else {
+        lookup_host((peer, default_port)).await
+    };
+
+    resolved
+        .map_err(|e| e.to_string())?
+        .next()
+        .ok_or_else(|| format!("'{}' did not resolve to any address", peer))
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1274970010;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 626213667;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__check_wallet_exists_impl(
+fn wire__crate__api__add_watch_address_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__check_wallet_exists_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_wallet_exists",
+            debug_name: "add_watch_address",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,19 +67,19 @@ fn wire__crate__api__check_wallet_exists_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            let api_label = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::check_wallet_exists(api_data_dir))?;
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::add_watch_address(api_address, api_label)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__create_wallet_mnemonic_impl(
+fn wire__crate__api__add_watch_script_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -87,7 +87,7 @@ fn wire__crate__api__create_wallet_mnemonic_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_wallet_mnemonic",
+            debug_name: "add_watch_script",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -101,18 +101,19 @@ fn wire__crate__api__create_wallet_mnemonic_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_script_hex = <String>::sse_decode(&mut deserializer);
+            let api_label = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::create_wallet_mnemonic(api_data_dir)?;
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::add_watch_script(api_script_hex, api_label)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__get_node_stats_impl(
+fn wire__crate__api__ban_peer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -120,7 +121,7 @@ fn wire__crate__api__get_node_stats_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_node_stats",
+            debug_name: "ban_peer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -134,11 +135,14 @@ fn wire__crate__api__get_node_stats_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            let api_duration_secs = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(crate::api::get_node_stats().await)?;
+                        let output_ok =
+                            crate::api::ban_peer(api_address, api_duration_secs).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -147,7 +151,41 @@ fn wire__crate__api__get_node_stats_impl(
         },
     )
 }
-fn wire__crate__api__get_wallet_info_impl(
+fn wire__crate__api__cancel_qr_scan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_qr_scan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::cancel_qr_scan();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cancel_wallet_rescan_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -155,7 +193,7 @@ fn wire__crate__api__get_wallet_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_wallet_info",
+            debug_name: "cancel_wallet_rescan",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -173,7 +211,8 @@ fn wire__crate__api__get_wallet_info_impl(
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(crate::api::get_wallet_info().await)?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::cancel_wallet_rescan().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -182,7 +221,7 @@ fn wire__crate__api__get_wallet_info_impl(
         },
     )
 }
-fn wire__crate__api__get_wallet_mnemonic_impl(
+fn wire__crate__api__check_wallet_exists_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -190,7 +229,7 @@ fn wire__crate__api__get_wallet_mnemonic_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_wallet_mnemonic",
+            debug_name: "check_wallet_exists",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -209,14 +248,14 @@ fn wire__crate__api__get_wallet_mnemonic_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::get_wallet_mnemonic(api_data_dir))?;
+                        Result::<_, ()>::Ok(crate::api::check_wallet_exists(api_data_dir))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__get_wallet_transactions_impl(
+fn wire__crate__api__clear_banned_peers_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -224,7 +263,7 @@ fn wire__crate__api__get_wallet_transactions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_wallet_transactions",
+            debug_name: "clear_banned_peers",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -240,10 +279,9 @@ fn wire__crate__api__get_wallet_transactions_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::get_wallet_transactions().await)?;
+                        let output_ok = crate::api::clear_banned_peers().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -252,15 +290,15 @@ fn wire__crate__api__get_wallet_transactions_impl(
         },
     )
 }
-fn wire__crate__api__import_wallet_mnemonic_impl(
+fn wire__crate__api__connect_peer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "import_wallet_mnemonic",
+            debug_name: "connect_peer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -274,19 +312,21 @@ fn wire__crate__api__import_wallet_mnemonic_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
-            let api_mnemonic = <String>::sse_decode(&mut deserializer);
+            let api_address = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::import_wallet_mnemonic(api_data_dir, api_mnemonic)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::connect_peer(api_address).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__init_app_impl(
+fn wire__crate__api__create_wallet_mnemonic_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -294,7 +334,7 @@ fn wire__crate__api__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "create_wallet_mnemonic",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -308,19 +348,18 @@ fn wire__crate__api__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init_app();
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::create_wallet_mnemonic(api_data_dir)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__is_node_running_impl(
+fn wire__crate__api__disconnect_node_peer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -328,7 +367,7 @@ fn wire__crate__api__is_node_running_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_node_running",
+            debug_name: "disconnect_node_peer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -342,11 +381,12 @@ fn wire__crate__api__is_node_running_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_address = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(crate::api::is_node_running().await)?;
+                        let output_ok = crate::api::disconnect_node_peer(api_address).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -355,7 +395,48 @@ fn wire__crate__api__is_node_running_impl(
         },
     )
 }
-fn wire__crate__api__send_transaction_impl(
+fn wire__crate__api__encode_animated_qr_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encode_animated_qr",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_ur_type = <Option<String>>::sse_decode(&mut deserializer);
+            let api_max_fragment_len = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_frame_count = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::encode_animated_qr(
+                        api_data,
+                        api_ur_type,
+                        api_max_fragment_len,
+                        api_frame_count,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__export_accumulator_package_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -363,7 +444,7 @@ fn wire__crate__api__send_transaction_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_transaction",
+            debug_name: "export_accumulator_package",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -377,19 +458,11 @@ fn wire__crate__api__send_transaction_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_address = <String>::sse_decode(&mut deserializer);
-            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
-            let api_fee_rate = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
                     (move || async move {
-                        let output_ok = crate::api::send_transaction(
-                            api_address,
-                            api_amount_sats,
-                            api_fee_rate,
-                        )
-                        .await?;
+                        let output_ok = crate::api::export_accumulator_package().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -398,7 +471,39 @@ fn wire__crate__api__send_transaction_impl(
         },
     )
 }
-fn wire__crate__api__start_node_service_impl(
+fn wire__crate__api__fee_rate_bucket_bounds_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "fee_rate_bucket_bounds",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::fee_rate_bucket_bounds())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_accumulator_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -406,7 +511,7 @@ fn wire__crate__api__start_node_service_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_node_service",
+            debug_name: "get_accumulator_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -420,14 +525,12 @@ fn wire__crate__api__start_node_service_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
-            let api_network = <String>::sse_decode(&mut deserializer);
+            let api_history_len = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::start_node_service(api_data_dir, api_network).await?;
+                        let output_ok = crate::api::get_accumulator_info(api_history_len).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -436,7 +539,7 @@ fn wire__crate__api__start_node_service_impl(
         },
     )
 }
-fn wire__crate__api__stop_node_service_impl(
+fn wire__crate__api__get_block_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -444,7 +547,7 @@ fn wire__crate__api__stop_node_service_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_node_service",
+            debug_name: "get_block",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -458,11 +561,12 @@ fn wire__crate__api__stop_node_service_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
                     (move || async move {
-                        let output_ok = crate::api::stop_node_service().await?;
+                        let output_ok = crate::api::get_block(api_query).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -471,7 +575,73 @@ fn wire__crate__api__stop_node_service_impl(
         },
     )
 }
-fn wire__crate__api__sync_wallet_impl(
+fn wire__crate__api__get_block_consumers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_block_consumers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_block_consumers())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_block_fee_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_block_fee_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::get_block_fee_stats(api_height))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_block_header_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -479,7 +649,7 @@ fn wire__crate__api__sync_wallet_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_wallet",
+            debug_name: "get_block_header",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -493,11 +663,12 @@ fn wire__crate__api__sync_wallet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
                     (move || async move {
-                        let output_ok = crate::api::sync_wallet().await?;
+                        let output_ok = crate::api::get_block_header(api_query).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -506,430 +677,4646 @@ fn wire__crate__api__sync_wallet_impl(
         },
     )
 }
+fn wire__crate__api__get_block_pipeline_metrics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_block_pipeline_metrics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_block_pipeline_metrics())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_geoip_databases_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_geoip_databases",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::get_geoip_databases().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_node_start_time_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_node_start_time",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::get_node_start_time().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_node_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_node_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::get_node_stats().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_node_stats_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_node_stats_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::get_node_stats_history().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_peer_census_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_peer_census",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::get_peer_census().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_peer_diversity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_peer_diversity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::get_peer_diversity().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_peer_diversity_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_peer_diversity_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::get_peer_diversity_policy().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_recent_block_fee_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_recent_block_fee_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::get_recent_block_fee_stats(api_limit))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_recent_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_recent_blocks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::get_recent_blocks(api_offset, api_limit))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_recent_reorgs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_recent_reorgs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_recent_reorgs())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_sync_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_sync_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::get_sync_progress().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_transaction_detail_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_transaction_detail",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_txid = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::get_transaction_detail(api_txid)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_trusted_peers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_trusted_peers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::get_trusted_peers(api_data_dir, api_network)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_trusted_peers_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_trusted_peers_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::get_trusted_peers_status().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_wallet_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_wallet_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(crate::api::get_wallet_info().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_wallet_mnemonic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_wallet_mnemonic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::get_wallet_mnemonic(api_data_dir))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_wallet_rescan_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_wallet_rescan_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::get_wallet_rescan_progress().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_wallet_transactions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_wallet_transactions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::get_wallet_transactions().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_watch_matches_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_watch_matches",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::get_watch_matches(api_limit)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_watchlist_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_watchlist",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::get_watchlist()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__import_accumulator_package_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_accumulator_package",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_package = <String>::sse_decode(&mut deserializer);
+            let api_accept_trust = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::import_accumulator_package(api_package, api_accept_trust)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__import_geoip_database_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_geoip_database",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_path = <String>::sse_decode(&mut deserializer);
+            let api_geoip_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::import_geoip_database(api_source_path, api_geoip_dir)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__import_wallet_mnemonic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_wallet_mnemonic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_mnemonic = <String>::sse_decode(&mut deserializer);
+            let api_birthday = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::import_wallet_mnemonic(
+                        api_data_dir,
+                        api_mnemonic,
+                        api_birthday,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__inspect_accumulator_package_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "inspect_accumulator_package",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_package = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::inspect_accumulator_package(api_package).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__is_node_running_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_node_running",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(crate::api::is_node_running().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__list_banned_peers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_banned_peers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::list_banned_peers().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__load_geoip_database_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_geoip_database",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::load_geoip_database(api_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__load_geoip_databases_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_geoip_databases",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::load_geoip_databases(api_dir).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__receive_qr_frame_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "receive_qr_frame",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_frame = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::receive_qr_frame(api_frame)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__remove_imported_accumulator_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_imported_accumulator",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok =
+                        crate::api::remove_imported_accumulator(api_data_dir, api_network)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__remove_watch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_watch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_script_or_address = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::remove_watch(api_script_or_address)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__reorg_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reorg_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::ReorgInfo,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::reorg_stream(api_sink).await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reset_node_chain_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_node_chain_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::reset_node_chain_state(api_data_dir, api_network).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__send_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_fee_rate = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::send_transaction(
+                            api_address,
+                            api_amount_sats,
+                            api_fee_rate,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_block_consumer_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_block_consumer_enabled",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::set_block_consumer_enabled(api_name, api_enabled)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__set_peer_diversity_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_peer_diversity_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy = <crate::api::PeerDiversityPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::set_peer_diversity_policy(api_policy).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_trusted_peers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_trusted_peers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            let api_peers = <Vec<crate::api::TrustedPeerConfig>>::sse_decode(&mut deserializer);
+            let api_mode = <crate::api::TrustedPeerMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>((move || {
+                    let output_ok = crate::api::set_trusted_peers(
+                        api_data_dir,
+                        api_network,
+                        api_peers,
+                        api_mode,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__start_node_service_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_node_service",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::start_node_service(api_data_dir, api_network).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__start_qr_scan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_qr_scan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::start_qr_scan();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__start_wallet_rescan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_wallet_rescan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_from_height = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::start_wallet_rescan(api_from_height).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__stop_node_service_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_node_service",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::stop_node_service().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__sync_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::sync_wallet().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__unban_peer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unban_peer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::node::error::BonsaiNodeError>(
+                    (move || async move {
+                        let output_ok = crate::api::unban_peer(api_address).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__unload_geoip_databases_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unload_geoip_databases",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::unload_geoip_databases().await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__watch_activity_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_activity_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::WatchMatchInfo,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::watch_activity_stream(api_sink).await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for StreamSink<crate::api::ReorgInfo, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::WatchMatchInfo, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::AccumulatorDeltaInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_hash = <String>::sse_decode(deserializer);
+        let mut var_leavesAdded = <u32>::sse_decode(deserializer);
+        let mut var_leavesDeleted = <u32>::sse_decode(deserializer);
+        let mut var_leavesAfter = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::AccumulatorDeltaInfo {
+            height: var_height,
+            hash: var_hash,
+            leaves_added: var_leavesAdded,
+            leaves_deleted: var_leavesDeleted,
+            leaves_after: var_leavesAfter,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AccumulatorImportPreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_package = <crate::api::AccumulatorPackageInfo>::sse_decode(deserializer);
+        let mut var_inHeaderChain = <bool>::sse_decode(deserializer);
+        let mut var_problem = <Option<String>>::sse_decode(deserializer);
+        let mut var_trustWarning = <String>::sse_decode(deserializer);
+        return crate::api::AccumulatorImportPreview {
+            package: var_package,
+            in_header_chain: var_inHeaderChain,
+            problem: var_problem,
+            trust_warning: var_trustWarning,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AccumulatorInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_blockHash = <String>::sse_decode(deserializer);
+        let mut var_leaves = <u64>::sse_decode(deserializer);
+        let mut var_forestRows = <u8>::sse_decode(deserializer);
+        let mut var_roots = <Vec<crate::api::AccumulatorRootInfo>>::sse_decode(deserializer);
+        let mut var_rootsSizeBytes = <u32>::sse_decode(deserializer);
+        let mut var_serializedSizeBytes = <u32>::sse_decode(deserializer);
+        let mut var_commitment = <String>::sse_decode(deserializer);
+        let mut var_history = <Vec<crate::api::AccumulatorDeltaInfo>>::sse_decode(deserializer);
+        return crate::api::AccumulatorInfo {
+            height: var_height,
+            block_hash: var_blockHash,
+            leaves: var_leaves,
+            forest_rows: var_forestRows,
+            roots: var_roots,
+            roots_size_bytes: var_rootsSizeBytes,
+            serialized_size_bytes: var_serializedSizeBytes,
+            commitment: var_commitment,
+            history: var_history,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AccumulatorPackageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_package = <String>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_blockHash = <String>::sse_decode(deserializer);
+        let mut var_leaves = <u64>::sse_decode(deserializer);
+        let mut var_roots = <Vec<String>>::sse_decode(deserializer);
+        let mut var_network = <Option<String>>::sse_decode(deserializer);
+        return crate::api::AccumulatorPackageInfo {
+            package: var_package,
+            height: var_height,
+            block_hash: var_blockHash,
+            leaves: var_leaves,
+            roots: var_roots,
+            network: var_network,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AccumulatorRootInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hash = <String>::sse_decode(deserializer);
+        let mut var_row = <u8>::sse_decode(deserializer);
+        let mut var_position = <u64>::sse_decode(deserializer);
+        return crate::api::AccumulatorRootInfo {
+            hash: var_hash,
+            row: var_row,
+            position: var_position,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AnimatedQr {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_urType = <String>::sse_decode(deserializer);
+        let mut var_fragmentCount = <u32>::sse_decode(deserializer);
+        let mut var_frames = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::AnimatedQr {
+            ur_type: var_urType,
+            fragment_count: var_fragmentCount,
+            frames: var_frames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::BannedPeerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_bannedUntil = <u64>::sse_decode(deserializer);
+        return crate::api::BannedPeerInfo {
+            address: var_address,
+            created_at: var_createdAt,
+            banned_until: var_bannedUntil,
+        };
+    }
+}
+
+impl SseDecode for crate::api::BlockConsumerMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_delivered = <u64>::sse_decode(deserializer);
+        let mut var_processed = <u64>::sse_decode(deserializer);
+        let mut var_lag = <u64>::sse_decode(deserializer);
+        let mut var_lastHeight = <u32>::sse_decode(deserializer);
+        let mut var_stalls = <u64>::sse_decode(deserializer);
+        let mut var_avgProcessMicros = <u64>::sse_decode(deserializer);
+        let mut var_catchingUp = <bool>::sse_decode(deserializer);
+        return crate::api::BlockConsumerMetrics {
+            name: var_name,
+            delivered: var_delivered,
+            processed: var_processed,
+            lag: var_lag,
+            last_height: var_lastHeight,
+            stalls: var_stalls,
+            avg_process_micros: var_avgProcessMicros,
+            catching_up: var_catchingUp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::BlockFeeStatsInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_hash = <String>::sse_decode(deserializer);
+        let mut var_txCount = <u32>::sse_decode(deserializer);
+        let mut var_totalFeesSats = <u64>::sse_decode(deserializer);
+        let mut var_minFeeRate = <f64>::sse_decode(deserializer);
+        let mut var_medianFeeRate = <f64>::sse_decode(deserializer);
+        let mut var_maxFeeRate = <f64>::sse_decode(deserializer);
+        let mut var_feeRateHistogram = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_segwitPercent = <f64>::sse_decode(deserializer);
+        let mut var_taprootPercent = <f64>::sse_decode(deserializer);
+        let mut var_missingPrevouts = <u32>::sse_decode(deserializer);
+        return crate::api::BlockFeeStatsInfo {
+            height: var_height,
+            hash: var_hash,
+            tx_count: var_txCount,
+            total_fees_sats: var_totalFeesSats,
+            min_fee_rate: var_minFeeRate,
+            median_fee_rate: var_medianFeeRate,
+            max_fee_rate: var_maxFeeRate,
+            fee_rate_histogram: var_feeRateHistogram,
+            segwit_percent: var_segwitPercent,
+            taproot_percent: var_taprootPercent,
+            missing_prevouts: var_missingPrevouts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::BlockHeaderInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hash = <String>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_previousHash = <String>::sse_decode(deserializer);
+        let mut var_merkleRoot = <String>::sse_decode(deserializer);
+        let mut var_version = <i32>::sse_decode(deserializer);
+        let mut var_timestamp = <u32>::sse_decode(deserializer);
+        let mut var_bits = <u32>::sse_decode(deserializer);
+        let mut var_nonce = <u32>::sse_decode(deserializer);
+        let mut var_difficulty = <f64>::sse_decode(deserializer);
+        return crate::api::BlockHeaderInfo {
+            hash: var_hash,
+            height: var_height,
+            previous_hash: var_previousHash,
+            merkle_root: var_merkleRoot,
+            version: var_version,
+            timestamp: var_timestamp,
+            bits: var_bits,
+            nonce: var_nonce,
+            difficulty: var_difficulty,
+        };
+    }
+}
+
+impl SseDecode for crate::api::BlockInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_header = <crate::api::BlockHeaderInfo>::sse_decode(deserializer);
+        let mut var_size = <u32>::sse_decode(deserializer);
+        let mut var_weight = <u64>::sse_decode(deserializer);
+        let mut var_txCount = <u32>::sse_decode(deserializer);
+        let mut var_transactions = <Vec<crate::api::TransactionInfo>>::sse_decode(deserializer);
+        let mut var_subsidySats = <u64>::sse_decode(deserializer);
+        let mut var_feesSats = <u64>::sse_decode(deserializer);
+        let mut var_coinbaseValueSats = <u64>::sse_decode(deserializer);
+        return crate::api::BlockInfo {
+            header: var_header,
+            size: var_size,
+            weight: var_weight,
+            tx_count: var_txCount,
+            transactions: var_transactions,
+            subsidy_sats: var_subsidySats,
+            fees_sats: var_feesSats,
+            coinbase_value_sats: var_coinbaseValueSats,
+        };
+    }
+}
+
+impl SseDecode for crate::node::error::BonsaiNodeError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::node::error::BonsaiNodeErrorKind>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::node::error::BonsaiNodeError {
+            kind: var_kind,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::node::error::BonsaiNodeErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::node::error::BonsaiNodeErrorKind::Generic,
+            1 => crate::node::error::BonsaiNodeErrorKind::NodeBuildError,
+            2 => crate::node::error::BonsaiNodeErrorKind::NodeExecError,
+            3 => crate::node::error::BonsaiNodeErrorKind::NotRunning,
+            _ => unreachable!("Invalid variant for BonsaiNodeErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::GeoIpDatabaseStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_databaseType = <String>::sse_decode(deserializer);
+        let mut var_buildEpoch = <u64>::sse_decode(deserializer);
+        let mut var_searchTreeNodes = <u32>::sse_decode(deserializer);
+        let mut var_ipVersion = <u16>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        return crate::api::GeoIpDatabaseStatus {
+            kind: var_kind,
+            path: var_path,
+            database_type: var_databaseType,
+            build_epoch: var_buildEpoch,
+            search_tree_nodes: var_searchTreeNodes,
+            ip_version: var_ipVersion,
+            description: var_description,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::IndexedTransactionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blockHash = <String>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_position = <u32>::sse_decode(deserializer);
+        let mut var_rawTxHex = <String>::sse_decode(deserializer);
+        let mut var_transaction = <crate::api::TransactionInfo>::sse_decode(deserializer);
+        return crate::api::IndexedTransactionInfo {
+            block_hash: var_blockHash,
+            height: var_height,
+            position: var_position,
+            raw_tx_hex: var_rawTxHex,
+            transaction: var_transaction,
+        };
+    }
+}
+
+impl SseDecode for crate::api::InscriptionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_contentType = <Option<String>>::sse_decode(deserializer);
+        let mut var_contentLength = <u32>::sse_decode(deserializer);
+        let mut var_text = <Option<String>>::sse_decode(deserializer);
+        return crate::api::InscriptionInfo {
+            content_type: var_contentType,
+            content_length: var_contentLength,
+            text: var_text,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::AccumulatorDeltaInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AccumulatorDeltaInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::AccumulatorRootInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AccumulatorRootInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::BannedPeerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::BannedPeerInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::BlockConsumerMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::BlockConsumerMetrics>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::BlockFeeStatsInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::BlockFeeStatsInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::GeoIpDatabaseStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::GeoIpDatabaseStatus>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NodeStatsSample> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NodeStatsSample>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PeerCensusBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PeerCensusBucket>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PeerDetailedInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PeerDetailedInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PeerGroupShare> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PeerGroupShare>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::RecentBlockInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::RecentBlockInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ReorgInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ReorgInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::StatFieldStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::StatFieldStatus>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TransactionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TransactionInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TrustedPeerConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TrustedPeerConfig>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TrustedPeerStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TrustedPeerStatus>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TxInputInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TxInputInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TxOutputInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TxOutputInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WalletTransactionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WalletTransactionInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WatchEntryInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WatchEntryInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WatchMatchInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WatchMatchInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::NodeStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inIbd = <bool>::sse_decode(deserializer);
+        let mut var_headers = <u32>::sse_decode(deserializer);
+        let mut var_blocks = <u32>::sse_decode(deserializer);
+        let mut var_userAgent = <String>::sse_decode(deserializer);
+        let mut var_uptimeSecs = <u64>::sse_decode(deserializer);
+        let mut var_peersCount = <usize>::sse_decode(deserializer);
+        let mut var_peers = <Vec<crate::api::PeerDetailedInfo>>::sse_decode(deserializer);
+        let mut var_fieldStatus = <Vec<crate::api::StatFieldStatus>>::sse_decode(deserializer);
+        let mut var_sampledAt = <u64>::sse_decode(deserializer);
+        return crate::api::NodeStats {
+            in_ibd: var_inIbd,
+            headers: var_headers,
+            blocks: var_blocks,
+            user_agent: var_userAgent,
+            uptime_secs: var_uptimeSecs,
+            peers_count: var_peersCount,
+            peers: var_peers,
+            field_status: var_fieldStatus,
+            sampled_at: var_sampledAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NodeStatsSample {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timestamp = <u64>::sse_decode(deserializer);
+        let mut var_blocks = <u32>::sse_decode(deserializer);
+        let mut var_headers = <u32>::sse_decode(deserializer);
+        let mut var_peers = <u32>::sse_decode(deserializer);
+        let mut var_blocksPerSec = <f64>::sse_decode(deserializer);
+        return crate::api::NodeStatsSample {
+            timestamp: var_timestamp,
+            blocks: var_blocks,
+            headers: var_headers,
+            peers: var_peers,
+            blocks_per_sec: var_blocksPerSec,
+        };
+    }
+}
+
+impl SseDecode for crate::api::OpReturnInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dataHex = <String>::sse_decode(deserializer);
+        let mut var_text = <Option<String>>::sse_decode(deserializer);
+        let mut var_protocol = <Option<String>>::sse_decode(deserializer);
+        return crate::api::OpReturnInfo {
+            data_hex: var_dataHex,
+            text: var_text,
+            protocol: var_protocol,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::BlockFeeStatsInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::BlockFeeStatsInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::IndexedTransactionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::IndexedTransactionInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::InscriptionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::InscriptionInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::OpReturnInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::OpReturnInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::WalletInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::WalletInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::WalletRescanProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::WalletRescanProgress>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::PeerCensusBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_peers = <u32>::sse_decode(deserializer);
+        return crate::api::PeerCensusBucket {
+            key: var_key,
+            label: var_label,
+            peers: var_peers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PeerCensusInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalPeers = <u32>::sse_decode(deserializer);
+        let mut var_byCountry = <Vec<crate::api::PeerCensusBucket>>::sse_decode(deserializer);
+        let mut var_byAsn = <Vec<crate::api::PeerCensusBucket>>::sse_decode(deserializer);
+        let mut var_byImplementation =
+            <Vec<crate::api::PeerCensusBucket>>::sse_decode(deserializer);
+        let mut var_byVersion = <Vec<crate::api::PeerCensusBucket>>::sse_decode(deserializer);
+        let mut var_utreexoPeers = <u32>::sse_decode(deserializer);
+        let mut var_compactFilterPeers = <u32>::sse_decode(deserializer);
+        return crate::api::PeerCensusInfo {
+            total_peers: var_totalPeers,
+            by_country: var_byCountry,
+            by_asn: var_byAsn,
+            by_implementation: var_byImplementation,
+            by_version: var_byVersion,
+            utreexo_peers: var_utreexoPeers,
+            compact_filter_peers: var_compactFilterPeers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PeerDetailedInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_userAgent = <String>::sse_decode(deserializer);
+        let mut var_implementation = <String>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_isInbound = <bool>::sse_decode(deserializer);
+        let mut var_services = <u64>::sse_decode(deserializer);
+        let mut var_serviceNames = <Vec<String>>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_connectionKind = <String>::sse_decode(deserializer);
+        let mut var_transport = <String>::sse_decode(deserializer);
+        let mut var_firstSeen = <u64>::sse_decode(deserializer);
+        let mut var_pingMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        let mut var_addressType = <String>::sse_decode(deserializer);
+        let mut var_asn = <Option<u32>>::sse_decode(deserializer);
+        let mut var_asnOrganization = <Option<String>>::sse_decode(deserializer);
+        let mut var_city = <Option<String>>::sse_decode(deserializer);
+        let mut var_country = <Option<String>>::sse_decode(deserializer);
+        let mut var_countryCode = <Option<String>>::sse_decode(deserializer);
+        return crate::api::PeerDetailedInfo {
+            address: var_address,
+            user_agent: var_userAgent,
+            implementation: var_implementation,
+            height: var_height,
+            is_inbound: var_isInbound,
+            services: var_services,
+            service_names: var_serviceNames,
+            status: var_status,
+            connection_kind: var_connectionKind,
+            transport: var_transport,
+            first_seen: var_firstSeen,
+            ping_ms: var_pingMs,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+            address_type: var_addressType,
+            asn: var_asn,
+            asn_organization: var_asnOrganization,
+            city: var_city,
+            country: var_country,
+            country_code: var_countryCode,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PeerDiversityAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::PeerDiversityAction::Warn,
+            1 => crate::api::PeerDiversityAction::Rotate,
+            _ => unreachable!("Invalid variant for PeerDiversityAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::PeerDiversityInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_outboundPeers = <u32>::sse_decode(deserializer);
+        let mut var_asnGroups = <Vec<crate::api::PeerGroupShare>>::sse_decode(deserializer);
+        let mut var_countryGroups = <Vec<crate::api::PeerGroupShare>>::sse_decode(deserializer);
+        let mut var_concentratedPeers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_rotatedPeers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_warnings = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::PeerDiversityInfo {
+            score: var_score,
+            outbound_peers: var_outboundPeers,
+            asn_groups: var_asnGroups,
+            country_groups: var_countryGroups,
+            concentrated_peers: var_concentratedPeers,
+            rotated_peers: var_rotatedPeers,
+            warnings: var_warnings,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PeerDiversityPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxAsnShare = <f64>::sse_decode(deserializer);
+        let mut var_maxCountryShare = <f64>::sse_decode(deserializer);
+        let mut var_minPeers = <u32>::sse_decode(deserializer);
+        let mut var_action = <crate::api::PeerDiversityAction>::sse_decode(deserializer);
+        return crate::api::PeerDiversityPolicy {
+            max_asn_share: var_maxAsnShare,
+            max_country_share: var_maxCountryShare,
+            min_peers: var_minPeers,
+            action: var_action,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PeerGroupShare {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_peers = <u32>::sse_decode(deserializer);
+        let mut var_share = <f64>::sse_decode(deserializer);
+        let mut var_overLimit = <bool>::sse_decode(deserializer);
+        return crate::api::PeerGroupShare {
+            key: var_key,
+            label: var_label,
+            peers: var_peers,
+            share: var_share,
+            over_limit: var_overLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::QrScanProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_urType = <Option<String>>::sse_decode(deserializer);
+        let mut var_progress = <f64>::sse_decode(deserializer);
+        let mut var_receivedFrames = <u32>::sse_decode(deserializer);
+        let mut var_knownFragments = <u32>::sse_decode(deserializer);
+        let mut var_fragmentCount = <u32>::sse_decode(deserializer);
+        let mut var_complete = <bool>::sse_decode(deserializer);
+        let mut var_data = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::QrScanProgress {
+            ur_type: var_urType,
+            progress: var_progress,
+            received_frames: var_receivedFrames,
+            known_fragments: var_knownFragments,
+            fragment_count: var_fragmentCount,
+            complete: var_complete,
+            data: var_data,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RecentBlockInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_hash = <String>::sse_decode(deserializer);
+        let mut var_time = <u32>::sse_decode(deserializer);
+        let mut var_txCount = <u32>::sse_decode(deserializer);
+        let mut var_size = <u32>::sse_decode(deserializer);
+        let mut var_weight = <u64>::sse_decode(deserializer);
+        let mut var_totalFeesSats = <u64>::sse_decode(deserializer);
+        let mut var_minerTag = <Option<String>>::sse_decode(deserializer);
+        return crate::api::RecentBlockInfo {
+            height: var_height,
+            hash: var_hash,
+            time: var_time,
+            tx_count: var_txCount,
+            size: var_size,
+            weight: var_weight,
+            total_fees_sats: var_totalFeesSats,
+            miner_tag: var_minerTag,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RecentBlocksPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blocks = <Vec<crate::api::RecentBlockInfo>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::RecentBlocksPage {
+            blocks: var_blocks,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ReorgInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_forkHeight = <u32>::sse_decode(deserializer);
+        let mut var_forkHash = <String>::sse_decode(deserializer);
+        let mut var_depth = <u32>::sse_decode(deserializer);
+        let mut var_disconnectedHashes = <Vec<String>>::sse_decode(deserializer);
+        let mut var_detectedAt = <u64>::sse_decode(deserializer);
+        return crate::api::ReorgInfo {
+            fork_height: var_forkHeight,
+            fork_hash: var_forkHash,
+            depth: var_depth,
+            disconnected_hashes: var_disconnectedHashes,
+            detected_at: var_detectedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SendTransactionResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txid = <String>::sse_decode(deserializer);
+        let mut var_rawTxHex = <String>::sse_decode(deserializer);
+        return crate::api::SendTransactionResult {
+            txid: var_txid,
+            raw_tx_hex: var_rawTxHex,
+        };
+    }
+}
+
+impl SseDecode for crate::api::StatFieldStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_available = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::StatFieldStatus {
+            field: var_field,
+            available: var_available,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SyncPhaseKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::SyncPhaseKind::Connecting,
+            1 => crate::api::SyncPhaseKind::Headers,
+            2 => crate::api::SyncPhaseKind::Blocks,
+            3 => crate::api::SyncPhaseKind::Backfill,
+            4 => crate::api::SyncPhaseKind::Filters,
+            5 => crate::api::SyncPhaseKind::Synced,
+            _ => unreachable!("Invalid variant for SyncPhaseKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::SyncProgressInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phase = <crate::api::SyncPhaseKind>::sse_decode(deserializer);
+        let mut var_percent = <f64>::sse_decode(deserializer);
+        let mut var_heightPercent = <f64>::sse_decode(deserializer);
+        let mut var_blocks = <u32>::sse_decode(deserializer);
+        let mut var_headers = <u32>::sse_decode(deserializer);
+        let mut var_filterHeight = <Option<u32>>::sse_decode(deserializer);
+        let mut var_backfillHeight = <Option<u32>>::sse_decode(deserializer);
+        let mut var_targetHeight = <u32>::sse_decode(deserializer);
+        let mut var_blocksPerSec = <f64>::sse_decode(deserializer);
+        let mut var_etaSecs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::SyncProgressInfo {
+            phase: var_phase,
+            percent: var_percent,
+            height_percent: var_heightPercent,
+            blocks: var_blocks,
+            headers: var_headers,
+            filter_height: var_filterHeight,
+            backfill_height: var_backfillHeight,
+            target_height: var_targetHeight,
+            blocks_per_sec: var_blocksPerSec,
+            eta_secs: var_etaSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TransactionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txid = <String>::sse_decode(deserializer);
+        let mut var_isCoinbase = <bool>::sse_decode(deserializer);
+        let mut var_size = <u32>::sse_decode(deserializer);
+        let mut var_vsize = <u32>::sse_decode(deserializer);
+        let mut var_weight = <u64>::sse_decode(deserializer);
+        let mut var_inputs = <Vec<crate::api::TxInputInfo>>::sse_decode(deserializer);
+        let mut var_outputs = <Vec<crate::api::TxOutputInfo>>::sse_decode(deserializer);
+        let mut var_outputTotalSats = <u64>::sse_decode(deserializer);
+        return crate::api::TransactionInfo {
+            txid: var_txid,
+            is_coinbase: var_isCoinbase,
+            size: var_size,
+            vsize: var_vsize,
+            weight: var_weight,
+            inputs: var_inputs,
+            outputs: var_outputs,
+            output_total_sats: var_outputTotalSats,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrustedPeerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_priority = <u32>::sse_decode(deserializer);
+        return crate::api::TrustedPeerConfig {
+            address: var_address,
+            priority: var_priority,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrustedPeerMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::TrustedPeerMode::Prefer,
+            1 => crate::api::TrustedPeerMode::Only,
+            _ => unreachable!("Invalid variant for TrustedPeerMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrustedPeerStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_priority = <u32>::sse_decode(deserializer);
+        let mut var_resolvedAddress = <Option<String>>::sse_decode(deserializer);
+        let mut var_isConnected = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::TrustedPeerStatus {
+            address: var_address,
+            priority: var_priority,
+            resolved_address: var_resolvedAddress,
+            is_connected: var_isConnected,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TxInputInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_previousOutput = <Option<String>>::sse_decode(deserializer);
+        let mut var_sequence = <u32>::sse_decode(deserializer);
+        let mut var_scriptSigSize = <u32>::sse_decode(deserializer);
+        let mut var_witnessItems = <u32>::sse_decode(deserializer);
+        let mut var_inscription = <Option<crate::api::InscriptionInfo>>::sse_decode(deserializer);
+        return crate::api::TxInputInfo {
+            previous_output: var_previousOutput,
+            sequence: var_sequence,
+            script_sig_size: var_scriptSigSize,
+            witness_items: var_witnessItems,
+            inscription: var_inscription,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TxOutputInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_valueSats = <u64>::sse_decode(deserializer);
+        let mut var_scriptType = <String>::sse_decode(deserializer);
+        let mut var_multisigRequired = <Option<u8>>::sse_decode(deserializer);
+        let mut var_multisigTotal = <Option<u8>>::sse_decode(deserializer);
+        let mut var_address = <Option<String>>::sse_decode(deserializer);
+        let mut var_scriptHex = <String>::sse_decode(deserializer);
+        let mut var_opReturn = <Option<crate::api::OpReturnInfo>>::sse_decode(deserializer);
+        return crate::api::TxOutputInfo {
+            value_sats: var_valueSats,
+            script_type: var_scriptType,
+            multisig_required: var_multisigRequired,
+            multisig_total: var_multisigTotal,
+            address: var_address,
+            script_hex: var_scriptHex,
+            op_return: var_opReturn,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::WalletInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_balanceSats = <u64>::sse_decode(deserializer);
+        let mut var_address = <String>::sse_decode(deserializer);
+        return crate::api::WalletInfo {
+            balance_sats: var_balanceSats,
+            address: var_address,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletRescanProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startHeight = <u32>::sse_decode(deserializer);
+        let mut var_currentHeight = <u32>::sse_decode(deserializer);
+        let mut var_tipHeight = <u32>::sse_decode(deserializer);
+        let mut var_progress = <f64>::sse_decode(deserializer);
+        let mut var_matchedBlocks = <u32>::sse_decode(deserializer);
+        let mut var_foundTransactions = <u32>::sse_decode(deserializer);
+        let mut var_scriptCount = <u32>::sse_decode(deserializer);
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_waitingForFilters = <bool>::sse_decode(deserializer);
+        let mut var_filterHeight = <Option<u32>>::sse_decode(deserializer);
+        let mut var_cancelled = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WalletRescanProgress {
+            start_height: var_startHeight,
+            current_height: var_currentHeight,
+            tip_height: var_tipHeight,
+            progress: var_progress,
+            matched_blocks: var_matchedBlocks,
+            found_transactions: var_foundTransactions,
+            script_count: var_scriptCount,
+            running: var_running,
+            waiting_for_filters: var_waitingForFilters,
+            filter_height: var_filterHeight,
+            cancelled: var_cancelled,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletTransactionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txid = <String>::sse_decode(deserializer);
+        let mut var_sent = <u64>::sse_decode(deserializer);
+        let mut var_received = <u64>::sse_decode(deserializer);
+        let mut var_fee = <Option<u64>>::sse_decode(deserializer);
+        let mut var_isConfirmed = <bool>::sse_decode(deserializer);
+        let mut var_confirmationHeight = <Option<u32>>::sse_decode(deserializer);
+        let mut var_timestamp = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::WalletTransactionInfo {
+            txid: var_txid,
+            sent: var_sent,
+            received: var_received,
+            fee: var_fee,
+            is_confirmed: var_isConfirmed,
+            confirmation_height: var_confirmationHeight,
+            timestamp: var_timestamp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WatchEntryInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scriptHex = <String>::sse_decode(deserializer);
+        let mut var_address = <Option<String>>::sse_decode(deserializer);
+        let mut var_scriptType = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_addedAt = <u64>::sse_decode(deserializer);
+        return crate::api::WatchEntryInfo {
+            script_hex: var_scriptHex,
+            address: var_address,
+            script_type: var_scriptType,
+            label: var_label,
+            added_at: var_addedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WatchMatchInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scriptHex = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_txid = <String>::sse_decode(deserializer);
+        let mut var_blockHash = <String>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_incoming = <bool>::sse_decode(deserializer);
+        let mut var_amountSats = <u64>::sse_decode(deserializer);
+        let mut var_memo = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WatchMatchInfo {
+            script_hex: var_scriptHex,
+            label: var_label,
+            txid: var_txid,
+            block_hash: var_blockHash,
+            height: var_height,
+            incoming: var_incoming,
+            amount_sats: var_amountSats,
+            memo: var_memo,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__add_watch_address_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__add_watch_script_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__ban_peer_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__cancel_qr_scan_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__cancel_wallet_rescan_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__check_wallet_exists_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__clear_banned_peers_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__connect_peer_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__create_wallet_mnemonic_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__disconnect_node_peer_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__encode_animated_qr_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__export_accumulator_package_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__fee_rate_bucket_bounds_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__get_accumulator_info_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__get_block_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__get_block_consumers_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__get_block_fee_stats_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__get_block_header_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__get_block_pipeline_metrics_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__get_geoip_databases_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__get_node_start_time_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__get_node_stats_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__get_node_stats_history_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__get_peer_census_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__get_peer_diversity_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__get_peer_diversity_policy_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__get_recent_block_fee_stats_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__get_recent_blocks_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__get_recent_reorgs_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__get_sync_progress_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__get_transaction_detail_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__get_trusted_peers_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__get_trusted_peers_status_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__get_wallet_info_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__get_wallet_mnemonic_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__get_wallet_rescan_progress_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__get_wallet_transactions_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__get_watch_matches_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__get_watchlist_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__import_accumulator_package_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__import_geoip_database_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__import_wallet_mnemonic_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__inspect_accumulator_package_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__is_node_running_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__list_banned_peers_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__load_geoip_database_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__load_geoip_databases_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__receive_qr_frame_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__remove_imported_accumulator_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__remove_watch_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__reorg_stream_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__reset_node_chain_state_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__send_transaction_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__set_block_consumer_enabled_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__set_peer_diversity_policy_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__set_trusted_peers_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__start_node_service_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__start_qr_scan_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__start_wallet_rescan_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__stop_node_service_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__sync_wallet_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__unban_peer_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__unload_geoip_databases_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__watch_activity_stream_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AccumulatorDeltaInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.height.into_into_dart().into_dart(),
+            self.hash.into_into_dart().into_dart(),
+            self.leaves_added.into_into_dart().into_dart(),
+            self.leaves_deleted.into_into_dart().into_dart(),
+            self.leaves_after.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AccumulatorDeltaInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AccumulatorDeltaInfo>
+    for crate::api::AccumulatorDeltaInfo
+{
+    fn into_into_dart(self) -> crate::api::AccumulatorDeltaInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AccumulatorImportPreview {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.package.into_into_dart().into_dart(),
+            self.in_header_chain.into_into_dart().into_dart(),
+            self.problem.into_into_dart().into_dart(),
+            self.trust_warning.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AccumulatorImportPreview
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AccumulatorImportPreview>
+    for crate::api::AccumulatorImportPreview
+{
+    fn into_into_dart(self) -> crate::api::AccumulatorImportPreview {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AccumulatorInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.height.into_into_dart().into_dart(),
+            self.block_hash.into_into_dart().into_dart(),
+            self.leaves.into_into_dart().into_dart(),
+            self.forest_rows.into_into_dart().into_dart(),
+            self.roots.into_into_dart().into_dart(),
+            self.roots_size_bytes.into_into_dart().into_dart(),
+            self.serialized_size_bytes.into_into_dart().into_dart(),
+            self.commitment.into_into_dart().into_dart(),
+            self.history.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AccumulatorInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AccumulatorInfo>
+    for crate::api::AccumulatorInfo
+{
+    fn into_into_dart(self) -> crate::api::AccumulatorInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AccumulatorPackageInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.package.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.block_hash.into_into_dart().into_dart(),
+            self.leaves.into_into_dart().into_dart(),
+            self.roots.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AccumulatorPackageInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AccumulatorPackageInfo>
+    for crate::api::AccumulatorPackageInfo
+{
+    fn into_into_dart(self) -> crate::api::AccumulatorPackageInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AccumulatorRootInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hash.into_into_dart().into_dart(),
+            self.row.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AccumulatorRootInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AccumulatorRootInfo>
+    for crate::api::AccumulatorRootInfo
+{
+    fn into_into_dart(self) -> crate::api::AccumulatorRootInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AnimatedQr {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ur_type.into_into_dart().into_dart(),
+            self.fragment_count.into_into_dart().into_dart(),
+            self.frames.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AnimatedQr {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AnimatedQr> for crate::api::AnimatedQr {
+    fn into_into_dart(self) -> crate::api::AnimatedQr {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::BannedPeerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.banned_until.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::BannedPeerInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::BannedPeerInfo> for crate::api::BannedPeerInfo {
+    fn into_into_dart(self) -> crate::api::BannedPeerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::BlockConsumerMetrics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.delivered.into_into_dart().into_dart(),
+            self.processed.into_into_dart().into_dart(),
+            self.lag.into_into_dart().into_dart(),
+            self.last_height.into_into_dart().into_dart(),
+            self.stalls.into_into_dart().into_dart(),
+            self.avg_process_micros.into_into_dart().into_dart(),
+            self.catching_up.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::BlockConsumerMetrics
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::BlockConsumerMetrics>
+    for crate::api::BlockConsumerMetrics
+{
+    fn into_into_dart(self) -> crate::api::BlockConsumerMetrics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::BlockFeeStatsInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.height.into_into_dart().into_dart(),
+            self.hash.into_into_dart().into_dart(),
+            self.tx_count.into_into_dart().into_dart(),
+            self.total_fees_sats.into_into_dart().into_dart(),
+            self.min_fee_rate.into_into_dart().into_dart(),
+            self.median_fee_rate.into_into_dart().into_dart(),
+            self.max_fee_rate.into_into_dart().into_dart(),
+            self.fee_rate_histogram.into_into_dart().into_dart(),
+            self.segwit_percent.into_into_dart().into_dart(),
+            self.taproot_percent.into_into_dart().into_dart(),
+            self.missing_prevouts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::BlockFeeStatsInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::BlockFeeStatsInfo>
+    for crate::api::BlockFeeStatsInfo
+{
+    fn into_into_dart(self) -> crate::api::BlockFeeStatsInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::BlockHeaderInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hash.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.previous_hash.into_into_dart().into_dart(),
+            self.merkle_root.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.bits.into_into_dart().into_dart(),
+            self.nonce.into_into_dart().into_dart(),
+            self.difficulty.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::BlockHeaderInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::BlockHeaderInfo>
+    for crate::api::BlockHeaderInfo
+{
+    fn into_into_dart(self) -> crate::api::BlockHeaderInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::BlockInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.header.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+            self.tx_count.into_into_dart().into_dart(),
+            self.transactions.into_into_dart().into_dart(),
+            self.subsidy_sats.into_into_dart().into_dart(),
+            self.fees_sats.into_into_dart().into_dart(),
+            self.coinbase_value_sats.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::BlockInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::BlockInfo> for crate::api::BlockInfo {
+    fn into_into_dart(self) -> crate::api::BlockInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::node::error::BonsaiNodeError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::node::error::BonsaiNodeError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::node::error::BonsaiNodeError>
+    for crate::node::error::BonsaiNodeError
+{
+    fn into_into_dart(self) -> crate::node::error::BonsaiNodeError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::node::error::BonsaiNodeErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Generic => 0.into_dart(),
+            Self::NodeBuildError => 1.into_dart(),
+            Self::NodeExecError => 2.into_dart(),
+            Self::NotRunning => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::node::error::BonsaiNodeErrorKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::node::error::BonsaiNodeErrorKind>
+    for crate::node::error::BonsaiNodeErrorKind
+{
+    fn into_into_dart(self) -> crate::node::error::BonsaiNodeErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::GeoIpDatabaseStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.database_type.into_into_dart().into_dart(),
+            self.build_epoch.into_into_dart().into_dart(),
+            self.search_tree_nodes.into_into_dart().into_dart(),
+            self.ip_version.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::GeoIpDatabaseStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::GeoIpDatabaseStatus>
+    for crate::api::GeoIpDatabaseStatus
+{
+    fn into_into_dart(self) -> crate::api::GeoIpDatabaseStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::IndexedTransactionInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.block_hash.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+            self.raw_tx_hex.into_into_dart().into_dart(),
+            self.transaction.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::IndexedTransactionInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::IndexedTransactionInfo>
+    for crate::api::IndexedTransactionInfo
+{
+    fn into_into_dart(self) -> crate::api::IndexedTransactionInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::InscriptionInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content_type.into_into_dart().into_dart(),
+            self.content_length.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::InscriptionInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::InscriptionInfo>
+    for crate::api::InscriptionInfo
+{
+    fn into_into_dart(self) -> crate::api::InscriptionInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NodeStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.in_ibd.into_into_dart().into_dart(),
+            self.headers.into_into_dart().into_dart(),
+            self.blocks.into_into_dart().into_dart(),
+            self.user_agent.into_into_dart().into_dart(),
+            self.uptime_secs.into_into_dart().into_dart(),
+            self.peers_count.into_into_dart().into_dart(),
+            self.peers.into_into_dart().into_dart(),
+            self.field_status.into_into_dart().into_dart(),
+            self.sampled_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NodeStats {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NodeStats> for crate::api::NodeStats {
+    fn into_into_dart(self) -> crate::api::NodeStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NodeStatsSample {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.timestamp.into_into_dart().into_dart(),
+            self.blocks.into_into_dart().into_dart(),
+            self.headers.into_into_dart().into_dart(),
+            self.peers.into_into_dart().into_dart(),
+            self.blocks_per_sec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NodeStatsSample {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NodeStatsSample>
+    for crate::api::NodeStatsSample
+{
+    fn into_into_dart(self) -> crate::api::NodeStatsSample {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::OpReturnInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data_hex.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.protocol.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::OpReturnInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::OpReturnInfo> for crate::api::OpReturnInfo {
+    fn into_into_dart(self) -> crate::api::OpReturnInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PeerCensusBucket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.peers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PeerCensusBucket {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PeerCensusBucket>
+    for crate::api::PeerCensusBucket
+{
+    fn into_into_dart(self) -> crate::api::PeerCensusBucket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PeerCensusInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_peers.into_into_dart().into_dart(),
+            self.by_country.into_into_dart().into_dart(),
+            self.by_asn.into_into_dart().into_dart(),
+            self.by_implementation.into_into_dart().into_dart(),
+            self.by_version.into_into_dart().into_dart(),
+            self.utreexo_peers.into_into_dart().into_dart(),
+            self.compact_filter_peers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PeerCensusInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PeerCensusInfo> for crate::api::PeerCensusInfo {
+    fn into_into_dart(self) -> crate::api::PeerCensusInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PeerDetailedInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.user_agent.into_into_dart().into_dart(),
+            self.implementation.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.is_inbound.into_into_dart().into_dart(),
+            self.services.into_into_dart().into_dart(),
+            self.service_names.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.connection_kind.into_into_dart().into_dart(),
+            self.transport.into_into_dart().into_dart(),
+            self.first_seen.into_into_dart().into_dart(),
+            self.ping_ms.into_into_dart().into_dart(),
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
+            self.address_type.into_into_dart().into_dart(),
+            self.asn.into_into_dart().into_dart(),
+            self.asn_organization.into_into_dart().into_dart(),
+            self.city.into_into_dart().into_dart(),
+            self.country.into_into_dart().into_dart(),
+            self.country_code.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PeerDetailedInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PeerDetailedInfo>
+    for crate::api::PeerDetailedInfo
+{
+    fn into_into_dart(self) -> crate::api::PeerDetailedInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PeerDiversityAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Warn => 0.into_dart(),
+            Self::Rotate => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PeerDiversityAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PeerDiversityAction>
+    for crate::api::PeerDiversityAction
+{
+    fn into_into_dart(self) -> crate::api::PeerDiversityAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PeerDiversityInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.score.into_into_dart().into_dart(),
+            self.outbound_peers.into_into_dart().into_dart(),
+            self.asn_groups.into_into_dart().into_dart(),
+            self.country_groups.into_into_dart().into_dart(),
+            self.concentrated_peers.into_into_dart().into_dart(),
+            self.rotated_peers.into_into_dart().into_dart(),
+            self.warnings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PeerDiversityInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PeerDiversityInfo>
+    for crate::api::PeerDiversityInfo
+{
+    fn into_into_dart(self) -> crate::api::PeerDiversityInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PeerDiversityPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_asn_share.into_into_dart().into_dart(),
+            self.max_country_share.into_into_dart().into_dart(),
+            self.min_peers.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PeerDiversityPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PeerDiversityPolicy>
+    for crate::api::PeerDiversityPolicy
+{
+    fn into_into_dart(self) -> crate::api::PeerDiversityPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PeerGroupShare {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.peers.into_into_dart().into_dart(),
+            self.share.into_into_dart().into_dart(),
+            self.over_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PeerGroupShare {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PeerGroupShare> for crate::api::PeerGroupShare {
+    fn into_into_dart(self) -> crate::api::PeerGroupShare {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::QrScanProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ur_type.into_into_dart().into_dart(),
+            self.progress.into_into_dart().into_dart(),
+            self.received_frames.into_into_dart().into_dart(),
+            self.known_fragments.into_into_dart().into_dart(),
+            self.fragment_count.into_into_dart().into_dart(),
+            self.complete.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::QrScanProgress {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::QrScanProgress> for crate::api::QrScanProgress {
+    fn into_into_dart(self) -> crate::api::QrScanProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RecentBlockInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.height.into_into_dart().into_dart(),
+            self.hash.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.tx_count.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+            self.total_fees_sats.into_into_dart().into_dart(),
+            self.miner_tag.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RecentBlockInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RecentBlockInfo>
+    for crate::api::RecentBlockInfo
+{
+    fn into_into_dart(self) -> crate::api::RecentBlockInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RecentBlocksPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.blocks.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RecentBlocksPage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RecentBlocksPage>
+    for crate::api::RecentBlocksPage
+{
+    fn into_into_dart(self) -> crate::api::RecentBlocksPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ReorgInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fork_height.into_into_dart().into_dart(),
+            self.fork_hash.into_into_dart().into_dart(),
+            self.depth.into_into_dart().into_dart(),
+            self.disconnected_hashes.into_into_dart().into_dart(),
+            self.detected_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ReorgInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ReorgInfo> for crate::api::ReorgInfo {
+    fn into_into_dart(self) -> crate::api::ReorgInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SendTransactionResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.txid.into_into_dart().into_dart(),
+            self.raw_tx_hex.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::SendTransactionResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SendTransactionResult>
+    for crate::api::SendTransactionResult
+{
+    fn into_into_dart(self) -> crate::api::SendTransactionResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::StatFieldStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StatFieldStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::StatFieldStatus>
+    for crate::api::StatFieldStatus
+{
+    fn into_into_dart(self) -> crate::api::StatFieldStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SyncPhaseKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Connecting => 0.into_dart(),
+            Self::Headers => 1.into_dart(),
+            Self::Blocks => 2.into_dart(),
+            Self::Backfill => 3.into_dart(),
+            Self::Filters => 4.into_dart(),
+            Self::Synced => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SyncPhaseKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SyncPhaseKind> for crate::api::SyncPhaseKind {
+    fn into_into_dart(self) -> crate::api::SyncPhaseKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SyncProgressInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase.into_into_dart().into_dart(),
+            self.percent.into_into_dart().into_dart(),
+            self.height_percent.into_into_dart().into_dart(),
+            self.blocks.into_into_dart().into_dart(),
+            self.headers.into_into_dart().into_dart(),
+            self.filter_height.into_into_dart().into_dart(),
+            self.backfill_height.into_into_dart().into_dart(),
+            self.target_height.into_into_dart().into_dart(),
+            self.blocks_per_sec.into_into_dart().into_dart(),
+            self.eta_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SyncProgressInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SyncProgressInfo>
+    for crate::api::SyncProgressInfo
+{
+    fn into_into_dart(self) -> crate::api::SyncProgressInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TransactionInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.txid.into_into_dart().into_dart(),
+            self.is_coinbase.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.vsize.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+            self.inputs.into_into_dart().into_dart(),
+            self.outputs.into_into_dart().into_dart(),
+            self.output_total_sats.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TransactionInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TransactionInfo>
+    for crate::api::TransactionInfo
+{
+    fn into_into_dart(self) -> crate::api::TransactionInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrustedPeerConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TrustedPeerConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrustedPeerConfig>
+    for crate::api::TrustedPeerConfig
+{
+    fn into_into_dart(self) -> crate::api::TrustedPeerConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrustedPeerMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Prefer => 0.into_dart(),
+            Self::Only => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TrustedPeerMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrustedPeerMode>
+    for crate::api::TrustedPeerMode
+{
+    fn into_into_dart(self) -> crate::api::TrustedPeerMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrustedPeerStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.resolved_address.into_into_dart().into_dart(),
+            self.is_connected.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TrustedPeerStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrustedPeerStatus>
+    for crate::api::TrustedPeerStatus
+{
+    fn into_into_dart(self) -> crate::api::TrustedPeerStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TxInputInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.previous_output.into_into_dart().into_dart(),
+            self.sequence.into_into_dart().into_dart(),
+            self.script_sig_size.into_into_dart().into_dart(),
+            self.witness_items.into_into_dart().into_dart(),
+            self.inscription.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TxInputInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TxInputInfo> for crate::api::TxInputInfo {
+    fn into_into_dart(self) -> crate::api::TxInputInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TxOutputInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.value_sats.into_into_dart().into_dart(),
+            self.script_type.into_into_dart().into_dart(),
+            self.multisig_required.into_into_dart().into_dart(),
+            self.multisig_total.into_into_dart().into_dart(),
+            self.address.into_into_dart().into_dart(),
+            self.script_hex.into_into_dart().into_dart(),
+            self.op_return.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TxOutputInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TxOutputInfo> for crate::api::TxOutputInfo {
+    fn into_into_dart(self) -> crate::api::TxOutputInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.balance_sats.into_into_dart().into_dart(),
+            self.address.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletInfo> for crate::api::WalletInfo {
+    fn into_into_dart(self) -> crate::api::WalletInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletRescanProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_height.into_into_dart().into_dart(),
+            self.current_height.into_into_dart().into_dart(),
+            self.tip_height.into_into_dart().into_dart(),
+            self.progress.into_into_dart().into_dart(),
+            self.matched_blocks.into_into_dart().into_dart(),
+            self.found_transactions.into_into_dart().into_dart(),
+            self.script_count.into_into_dart().into_dart(),
+            self.running.into_into_dart().into_dart(),
+            self.waiting_for_filters.into_into_dart().into_dart(),
+            self.filter_height.into_into_dart().into_dart(),
+            self.cancelled.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletRescanProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletRescanProgress>
+    for crate::api::WalletRescanProgress
+{
+    fn into_into_dart(self) -> crate::api::WalletRescanProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletTransactionInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.txid.into_into_dart().into_dart(),
+            self.sent.into_into_dart().into_dart(),
+            self.received.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.is_confirmed.into_into_dart().into_dart(),
+            self.confirmation_height.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletTransactionInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletTransactionInfo>
+    for crate::api::WalletTransactionInfo
+{
+    fn into_into_dart(self) -> crate::api::WalletTransactionInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WatchEntryInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.script_hex.into_into_dart().into_dart(),
+            self.address.into_into_dart().into_dart(),
+            self.script_type.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.added_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WatchEntryInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WatchEntryInfo> for crate::api::WatchEntryInfo {
+    fn into_into_dart(self) -> crate::api::WatchEntryInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WatchMatchInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.script_hex.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.txid.into_into_dart().into_dart(),
+            self.block_hash.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.incoming.into_into_dart().into_dart(),
+            self.amount_sats.into_into_dart().into_dart(),
+            self.memo.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WatchMatchInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WatchMatchInfo> for crate::api::WatchMatchInfo {
+    fn into_into_dart(self) -> crate::api::WatchMatchInfo {
+        self
+    }
+}
 
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
-}
-
-impl SseDecode for f32 {
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::PeerDetailedInfo> {
+impl SseEncode for StreamSink<crate::api::ReorgInfo, flutter_rust_bridge::for_generated::SseCodec> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::PeerDetailedInfo>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode
+    for StreamSink<crate::api::WatchMatchInfo, flutter_rust_bridge::for_generated::SseCodec>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for Vec<crate::api::WalletTransactionInfo> {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::WalletTransactionInfo>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for crate::api::NodeStats {
+impl SseEncode for crate::api::AccumulatorDeltaInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_inIbd = <bool>::sse_decode(deserializer);
-        let mut var_headers = <u32>::sse_decode(deserializer);
-        let mut var_blocks = <u32>::sse_decode(deserializer);
-        let mut var_userAgent = <String>::sse_decode(deserializer);
-        let mut var_uptimeSecs = <u64>::sse_decode(deserializer);
-        let mut var_peersCount = <usize>::sse_decode(deserializer);
-        let mut var_peers = <Vec<crate::api::PeerDetailedInfo>>::sse_decode(deserializer);
-        return crate::api::NodeStats {
-            in_ibd: var_inIbd,
-            headers: var_headers,
-            blocks: var_blocks,
-            user_agent: var_userAgent,
-            uptime_secs: var_uptimeSecs,
-            peers_count: var_peersCount,
-            peers: var_peers,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.height, serializer);
+        <String>::sse_encode(self.hash, serializer);
+        <u32>::sse_encode(self.leaves_added, serializer);
+        <u32>::sse_encode(self.leaves_deleted, serializer);
+        <Option<u64>>::sse_encode(self.leaves_after, serializer);
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for crate::api::AccumulatorImportPreview {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::AccumulatorPackageInfo>::sse_encode(self.package, serializer);
+        <bool>::sse_encode(self.in_header_chain, serializer);
+        <Option<String>>::sse_encode(self.problem, serializer);
+        <String>::sse_encode(self.trust_warning, serializer);
     }
 }
 
-impl SseDecode for Option<crate::api::NodeStats> {
+impl SseEncode for crate::api::AccumulatorInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::NodeStats>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.height, serializer);
+        <String>::sse_encode(self.block_hash, serializer);
+        <u64>::sse_encode(self.leaves, serializer);
+        <u8>::sse_encode(self.forest_rows, serializer);
+        <Vec<crate::api::AccumulatorRootInfo>>::sse_encode(self.roots, serializer);
+        <u32>::sse_encode(self.roots_size_bytes, serializer);
+        <u32>::sse_encode(self.serialized_size_bytes, serializer);
+        <String>::sse_encode(self.commitment, serializer);
+        <Vec<crate::api::AccumulatorDeltaInfo>>::sse_encode(self.history, serializer);
     }
 }
 
-impl SseDecode for Option<u32> {
+impl SseEncode for crate::api::AccumulatorPackageInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.package, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <String>::sse_encode(self.block_hash, serializer);
+        <u64>::sse_encode(self.leaves, serializer);
+        <Vec<String>>::sse_encode(self.roots, serializer);
+        <Option<String>>::sse_encode(self.network, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AccumulatorRootInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.hash, serializer);
+        <u8>::sse_encode(self.row, serializer);
+        <u64>::sse_encode(self.position, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AnimatedQr {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.ur_type, serializer);
+        <u32>::sse_encode(self.fragment_count, serializer);
+        <Vec<String>>::sse_encode(self.frames, serializer);
+    }
+}
+
+impl SseEncode for crate::api::BannedPeerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
+        <u64>::sse_encode(self.banned_until, serializer);
+    }
+}
+
+impl SseEncode for crate::api::BlockConsumerMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.delivered, serializer);
+        <u64>::sse_encode(self.processed, serializer);
+        <u64>::sse_encode(self.lag, serializer);
+        <u32>::sse_encode(self.last_height, serializer);
+        <u64>::sse_encode(self.stalls, serializer);
+        <u64>::sse_encode(self.avg_process_micros, serializer);
+        <bool>::sse_encode(self.catching_up, serializer);
+    }
+}
+
+impl SseEncode for crate::api::BlockFeeStatsInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.height, serializer);
+        <String>::sse_encode(self.hash, serializer);
+        <u32>::sse_encode(self.tx_count, serializer);
+        <u64>::sse_encode(self.total_fees_sats, serializer);
+        <f64>::sse_encode(self.min_fee_rate, serializer);
+        <f64>::sse_encode(self.median_fee_rate, serializer);
+        <f64>::sse_encode(self.max_fee_rate, serializer);
+        <Vec<u32>>::sse_encode(self.fee_rate_histogram, serializer);
+        <f64>::sse_encode(self.segwit_percent, serializer);
+        <f64>::sse_encode(self.taproot_percent, serializer);
+        <u32>::sse_encode(self.missing_prevouts, serializer);
+    }
+}
+
+impl SseEncode for crate::api::BlockHeaderInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.hash, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <String>::sse_encode(self.previous_hash, serializer);
+        <String>::sse_encode(self.merkle_root, serializer);
+        <i32>::sse_encode(self.version, serializer);
+        <u32>::sse_encode(self.timestamp, serializer);
+        <u32>::sse_encode(self.bits, serializer);
+        <u32>::sse_encode(self.nonce, serializer);
+        <f64>::sse_encode(self.difficulty, serializer);
+    }
+}
+
+impl SseEncode for crate::api::BlockInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::BlockHeaderInfo>::sse_encode(self.header, serializer);
+        <u32>::sse_encode(self.size, serializer);
+        <u64>::sse_encode(self.weight, serializer);
+        <u32>::sse_encode(self.tx_count, serializer);
+        <Vec<crate::api::TransactionInfo>>::sse_encode(self.transactions, serializer);
+        <u64>::sse_encode(self.subsidy_sats, serializer);
+        <u64>::sse_encode(self.fees_sats, serializer);
+        <u64>::sse_encode(self.coinbase_value_sats, serializer);
     }
 }
 
-impl SseDecode for Option<u64> {
+impl SseEncode for crate::node::error::BonsaiNodeError {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::node::error::BonsaiNodeErrorKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
-impl SseDecode for Option<crate::api::WalletInfo> {
+impl SseEncode for crate::node::error::BonsaiNodeErrorKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::WalletInfo>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::node::error::BonsaiNodeErrorKind::Generic => 0,
+                crate::node::error::BonsaiNodeErrorKind::NodeBuildError => 1,
+                crate::node::error::BonsaiNodeErrorKind::NodeExecError => 2,
+                crate::node::error::BonsaiNodeErrorKind::NotRunning => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::api::PeerDetailedInfo {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_address = <String>::sse_decode(deserializer);
-        let mut var_userAgent = <String>::sse_decode(deserializer);
-        let mut var_height = <u32>::sse_decode(deserializer);
-        let mut var_isInbound = <bool>::sse_decode(deserializer);
-        return crate::api::PeerDetailedInfo {
-            address: var_address,
-            user_agent: var_userAgent,
-            height: var_height,
-            is_inbound: var_isInbound,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for crate::api::SendTransactionResult {
+impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txid = <String>::sse_decode(deserializer);
-        let mut var_rawTxHex = <String>::sse_decode(deserializer);
-        return crate::api::SendTransactionResult {
-            txid: var_txid,
-            raw_tx_hex: var_rawTxHex,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for crate::api::GeoIpDatabaseStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.database_type, serializer);
+        <u64>::sse_encode(self.build_epoch, serializer);
+        <u32>::sse_encode(self.search_tree_nodes, serializer);
+        <u16>::sse_encode(self.ip_version, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::IndexedTransactionInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.block_hash, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u32>::sse_encode(self.position, serializer);
+        <String>::sse_encode(self.raw_tx_hex, serializer);
+        <crate::api::TransactionInfo>::sse_encode(self.transaction, serializer);
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::api::InscriptionInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.content_type, serializer);
+        <u32>::sse_encode(self.content_length, serializer);
+        <Option<String>>::sse_encode(self.text, serializer);
     }
 }
 
-impl SseDecode for crate::api::WalletInfo {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_balanceSats = <u64>::sse_decode(deserializer);
-        let mut var_address = <String>::sse_decode(deserializer);
-        return crate::api::WalletInfo {
-            balance_sats: var_balanceSats,
-            address: var_address,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::WalletTransactionInfo {
+impl SseEncode for Vec<crate::api::AccumulatorDeltaInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txid = <String>::sse_decode(deserializer);
-        let mut var_sent = <u64>::sse_decode(deserializer);
-        let mut var_received = <u64>::sse_decode(deserializer);
-        let mut var_fee = <Option<u64>>::sse_decode(deserializer);
-        let mut var_isConfirmed = <bool>::sse_decode(deserializer);
-        let mut var_confirmationHeight = <Option<u32>>::sse_decode(deserializer);
-        let mut var_timestamp = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::WalletTransactionInfo {
-            txid: var_txid,
-            sent: var_sent,
-            received: var_received,
-            fee: var_fee,
-            is_confirmed: var_isConfirmed,
-            confirmation_height: var_confirmationHeight,
-            timestamp: var_timestamp,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::AccumulatorDeltaInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::api::AccumulatorRootInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::AccumulatorRootInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__check_wallet_exists_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__create_wallet_mnemonic_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__get_node_stats_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__get_wallet_info_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__get_wallet_mnemonic_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__get_wallet_transactions_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__import_wallet_mnemonic_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__is_node_running_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__send_transaction_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__start_node_service_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__stop_node_service_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__sync_wallet_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::api::BannedPeerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::BannedPeerInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::api::BlockConsumerMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::BlockConsumerMetrics>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::NodeStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.in_ibd.into_into_dart().into_dart(),
-            self.headers.into_into_dart().into_dart(),
-            self.blocks.into_into_dart().into_dart(),
-            self.user_agent.into_into_dart().into_dart(),
-            self.uptime_secs.into_into_dart().into_dart(),
-            self.peers_count.into_into_dart().into_dart(),
-            self.peers.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Vec<crate::api::BlockFeeStatsInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::BlockFeeStatsInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NodeStats {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::NodeStats> for crate::api::NodeStats {
-    fn into_into_dart(self) -> crate::api::NodeStats {
-        self
+
+impl SseEncode for Vec<crate::api::GeoIpDatabaseStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::GeoIpDatabaseStatus>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::PeerDetailedInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.address.into_into_dart().into_dart(),
-            self.user_agent.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.is_inbound.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::NodeStatsSample> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::NodeStatsSample>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PeerDetailedInfo {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::PeerDetailedInfo>
-    for crate::api::PeerDetailedInfo
-{
-    fn into_into_dart(self) -> crate::api::PeerDetailedInfo {
-        self
+
+impl SseEncode for Vec<crate::api::PeerCensusBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PeerCensusBucket>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::SendTransactionResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.txid.into_into_dart().into_dart(),
-            self.raw_tx_hex.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::PeerDetailedInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PeerDetailedInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::SendTransactionResult
-{
+
+impl SseEncode for Vec<crate::api::PeerGroupShare> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PeerGroupShare>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::SendTransactionResult>
-    for crate::api::SendTransactionResult
-{
-    fn into_into_dart(self) -> crate::api::SendTransactionResult {
-        self
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.balance_sats.into_into_dart().into_dart(),
-            self.address.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletInfo {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletInfo> for crate::api::WalletInfo {
-    fn into_into_dart(self) -> crate::api::WalletInfo {
-        self
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletTransactionInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.txid.into_into_dart().into_dart(),
-            self.sent.into_into_dart().into_dart(),
-            self.received.into_into_dart().into_dart(),
-            self.fee.into_into_dart().into_dart(),
-            self.is_confirmed.into_into_dart().into_dart(),
-            self.confirmation_height.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::RecentBlockInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::RecentBlockInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletTransactionInfo
-{
+
+impl SseEncode for Vec<crate::api::ReorgInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ReorgInfo>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletTransactionInfo>
-    for crate::api::WalletTransactionInfo
-{
-    fn into_into_dart(self) -> crate::api::WalletTransactionInfo {
-        self
+
+impl SseEncode for Vec<crate::api::StatFieldStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::StatFieldStatus>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<crate::api::TransactionInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TransactionInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Vec<crate::api::TrustedPeerConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TrustedPeerConfig>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for Vec<crate::api::TrustedPeerStatus> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TrustedPeerStatus>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<crate::api::PeerDetailedInfo> {
+impl SseEncode for Vec<crate::api::TxInputInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::PeerDetailedInfo>::sse_encode(item, serializer);
+            <crate::api::TxInputInfo>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Vec<crate::api::TxOutputInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <u8>::sse_encode(item, serializer);
+            <crate::api::TxOutputInfo>::sse_encode(item, serializer);
         }
     }
 }
@@ -944,6 +5331,26 @@ impl SseEncode for Vec<crate::api::WalletTransactionInfo> {
     }
 }
 
+impl SseEncode for Vec<crate::api::WatchEntryInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::WatchEntryInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::WatchMatchInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::WatchMatchInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for crate::api::NodeStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -954,6 +5361,28 @@ impl SseEncode for crate::api::NodeStats {
         <u64>::sse_encode(self.uptime_secs, serializer);
         <usize>::sse_encode(self.peers_count, serializer);
         <Vec<crate::api::PeerDetailedInfo>>::sse_encode(self.peers, serializer);
+        <Vec<crate::api::StatFieldStatus>>::sse_encode(self.field_status, serializer);
+        <u64>::sse_encode(self.sampled_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::NodeStatsSample {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.timestamp, serializer);
+        <u32>::sse_encode(self.blocks, serializer);
+        <u32>::sse_encode(self.headers, serializer);
+        <u32>::sse_encode(self.peers, serializer);
+        <f64>::sse_encode(self.blocks_per_sec, serializer);
+    }
+}
+
+impl SseEncode for crate::api::OpReturnInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.data_hex, serializer);
+        <Option<String>>::sse_encode(self.text, serializer);
+        <Option<String>>::sse_encode(self.protocol, serializer);
     }
 }
 
@@ -967,12 +5396,42 @@ impl SseEncode for Option<String> {
     }
 }
 
-impl SseEncode for Option<crate::api::NodeStats> {
+impl SseEncode for Option<crate::api::BlockFeeStatsInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::BlockFeeStatsInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::IndexedTransactionInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::NodeStats>::sse_encode(value, serializer);
+            <crate::api::IndexedTransactionInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::InscriptionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::InscriptionInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::OpReturnInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::OpReturnInfo>::sse_encode(value, serializer);
         }
     }
 }
@@ -997,6 +5456,16 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u8>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::WalletInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1007,13 +5476,167 @@ impl SseEncode for Option<crate::api::WalletInfo> {
     }
 }
 
+impl SseEncode for Option<crate::api::WalletRescanProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::WalletRescanProgress>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::PeerCensusBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <u32>::sse_encode(self.peers, serializer);
+    }
+}
+
+impl SseEncode for crate::api::PeerCensusInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.total_peers, serializer);
+        <Vec<crate::api::PeerCensusBucket>>::sse_encode(self.by_country, serializer);
+        <Vec<crate::api::PeerCensusBucket>>::sse_encode(self.by_asn, serializer);
+        <Vec<crate::api::PeerCensusBucket>>::sse_encode(self.by_implementation, serializer);
+        <Vec<crate::api::PeerCensusBucket>>::sse_encode(self.by_version, serializer);
+        <u32>::sse_encode(self.utreexo_peers, serializer);
+        <u32>::sse_encode(self.compact_filter_peers, serializer);
+    }
+}
+
 impl SseEncode for crate::api::PeerDetailedInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.address, serializer);
         <String>::sse_encode(self.user_agent, serializer);
+        <String>::sse_encode(self.implementation, serializer);
         <u32>::sse_encode(self.height, serializer);
         <bool>::sse_encode(self.is_inbound, serializer);
+        <u64>::sse_encode(self.services, serializer);
+        <Vec<String>>::sse_encode(self.service_names, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.connection_kind, serializer);
+        <String>::sse_encode(self.transport, serializer);
+        <u64>::sse_encode(self.first_seen, serializer);
+        <Option<u64>>::sse_encode(self.ping_ms, serializer);
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+        <String>::sse_encode(self.address_type, serializer);
+        <Option<u32>>::sse_encode(self.asn, serializer);
+        <Option<String>>::sse_encode(self.asn_organization, serializer);
+        <Option<String>>::sse_encode(self.city, serializer);
+        <Option<String>>::sse_encode(self.country, serializer);
+        <Option<String>>::sse_encode(self.country_code, serializer);
+    }
+}
+
+impl SseEncode for crate::api::PeerDiversityAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::PeerDiversityAction::Warn => 0,
+                crate::api::PeerDiversityAction::Rotate => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::PeerDiversityInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.score, serializer);
+        <u32>::sse_encode(self.outbound_peers, serializer);
+        <Vec<crate::api::PeerGroupShare>>::sse_encode(self.asn_groups, serializer);
+        <Vec<crate::api::PeerGroupShare>>::sse_encode(self.country_groups, serializer);
+        <Vec<String>>::sse_encode(self.concentrated_peers, serializer);
+        <Vec<String>>::sse_encode(self.rotated_peers, serializer);
+        <Vec<String>>::sse_encode(self.warnings, serializer);
+    }
+}
+
+impl SseEncode for crate::api::PeerDiversityPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.max_asn_share, serializer);
+        <f64>::sse_encode(self.max_country_share, serializer);
+        <u32>::sse_encode(self.min_peers, serializer);
+        <crate::api::PeerDiversityAction>::sse_encode(self.action, serializer);
+    }
+}
+
+impl SseEncode for crate::api::PeerGroupShare {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <u32>::sse_encode(self.peers, serializer);
+        <f64>::sse_encode(self.share, serializer);
+        <bool>::sse_encode(self.over_limit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::QrScanProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.ur_type, serializer);
+        <f64>::sse_encode(self.progress, serializer);
+        <u32>::sse_encode(self.received_frames, serializer);
+        <u32>::sse_encode(self.known_fragments, serializer);
+        <u32>::sse_encode(self.fragment_count, serializer);
+        <bool>::sse_encode(self.complete, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.data, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RecentBlockInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.height, serializer);
+        <String>::sse_encode(self.hash, serializer);
+        <u32>::sse_encode(self.time, serializer);
+        <u32>::sse_encode(self.tx_count, serializer);
+        <u32>::sse_encode(self.size, serializer);
+        <u64>::sse_encode(self.weight, serializer);
+        <u64>::sse_encode(self.total_fees_sats, serializer);
+        <Option<String>>::sse_encode(self.miner_tag, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RecentBlocksPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::RecentBlockInfo>>::sse_encode(self.blocks, serializer);
+        <u32>::sse_encode(self.total, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ReorgInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.fork_height, serializer);
+        <String>::sse_encode(self.fork_hash, serializer);
+        <u32>::sse_encode(self.depth, serializer);
+        <Vec<String>>::sse_encode(self.disconnected_hashes, serializer);
+        <u64>::sse_encode(self.detected_at, serializer);
     }
 }
 
@@ -1025,6 +5648,131 @@ impl SseEncode for crate::api::SendTransactionResult {
     }
 }
 
+impl SseEncode for crate::api::StatFieldStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.field, serializer);
+        <bool>::sse_encode(self.available, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::SyncPhaseKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::SyncPhaseKind::Connecting => 0,
+                crate::api::SyncPhaseKind::Headers => 1,
+                crate::api::SyncPhaseKind::Blocks => 2,
+                crate::api::SyncPhaseKind::Backfill => 3,
+                crate::api::SyncPhaseKind::Filters => 4,
+                crate::api::SyncPhaseKind::Synced => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::SyncProgressInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::SyncPhaseKind>::sse_encode(self.phase, serializer);
+        <f64>::sse_encode(self.percent, serializer);
+        <f64>::sse_encode(self.height_percent, serializer);
+        <u32>::sse_encode(self.blocks, serializer);
+        <u32>::sse_encode(self.headers, serializer);
+        <Option<u32>>::sse_encode(self.filter_height, serializer);
+        <Option<u32>>::sse_encode(self.backfill_height, serializer);
+        <u32>::sse_encode(self.target_height, serializer);
+        <f64>::sse_encode(self.blocks_per_sec, serializer);
+        <Option<u64>>::sse_encode(self.eta_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TransactionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.txid, serializer);
+        <bool>::sse_encode(self.is_coinbase, serializer);
+        <u32>::sse_encode(self.size, serializer);
+        <u32>::sse_encode(self.vsize, serializer);
+        <u64>::sse_encode(self.weight, serializer);
+        <Vec<crate::api::TxInputInfo>>::sse_encode(self.inputs, serializer);
+        <Vec<crate::api::TxOutputInfo>>::sse_encode(self.outputs, serializer);
+        <u64>::sse_encode(self.output_total_sats, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TrustedPeerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <u32>::sse_encode(self.priority, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TrustedPeerMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::TrustedPeerMode::Prefer => 0,
+                crate::api::TrustedPeerMode::Only => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::TrustedPeerStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <u32>::sse_encode(self.priority, serializer);
+        <Option<String>>::sse_encode(self.resolved_address, serializer);
+        <bool>::sse_encode(self.is_connected, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TxInputInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.previous_output, serializer);
+        <u32>::sse_encode(self.sequence, serializer);
+        <u32>::sse_encode(self.script_sig_size, serializer);
+        <u32>::sse_encode(self.witness_items, serializer);
+        <Option<crate::api::InscriptionInfo>>::sse_encode(self.inscription, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TxOutputInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.value_sats, serializer);
+        <String>::sse_encode(self.script_type, serializer);
+        <Option<u8>>::sse_encode(self.multisig_required, serializer);
+        <Option<u8>>::sse_encode(self.multisig_total, serializer);
+        <Option<String>>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.script_hex, serializer);
+        <Option<crate::api::OpReturnInfo>>::sse_encode(self.op_return, serializer);
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1069,6 +5817,24 @@ impl SseEncode for crate::api::WalletInfo {
     }
 }
 
+impl SseEncode for crate::api::WalletRescanProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start_height, serializer);
+        <u32>::sse_encode(self.current_height, serializer);
+        <u32>::sse_encode(self.tip_height, serializer);
+        <f64>::sse_encode(self.progress, serializer);
+        <u32>::sse_encode(self.matched_blocks, serializer);
+        <u32>::sse_encode(self.found_transactions, serializer);
+        <u32>::sse_encode(self.script_count, serializer);
+        <bool>::sse_encode(self.running, serializer);
+        <bool>::sse_encode(self.waiting_for_filters, serializer);
+        <Option<u32>>::sse_encode(self.filter_height, serializer);
+        <bool>::sse_encode(self.cancelled, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletTransactionInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1082,10 +5848,28 @@ impl SseEncode for crate::api::WalletTransactionInfo {
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::WatchEntryInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.script_hex, serializer);
+        <Option<String>>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.script_type, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <u64>::sse_encode(self.added_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WatchMatchInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.script_hex, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.txid, serializer);
+        <String>::sse_encode(self.block_hash, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <bool>::sse_encode(self.incoming, serializer);
+        <u64>::sse_encode(self.amount_sats, serializer);
+        <Option<String>>::sse_encode(self.memo, serializer);
     }
 }
 
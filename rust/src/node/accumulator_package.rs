@@ -0,0 +1,244 @@
+use std::fs;
+use std::io::Cursor;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use bdk_floresta::AssumeUtreexoValue;
+use bdk_floresta::Node;
+use bdk_floresta::rustreexo::accumulator::node_hash::BitcoinNodeHash;
+use bdk_floresta::rustreexo::accumulator::stump::Stump;
+use bitcoin::BlockHash;
+use bitcoin::Network;
+use bitcoin::base58;
+use bitcoin::hashes::Hash;
+use bitcoin::p2p::Magic;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::RwLock;
+use tracing::error;
+use tracing::info;
+use tracing::warn;
+
+use crate::node::error::BonsaiNodeError;
+
+/// Marks a string as an accumulator package, ahead of the base58check payload.
+pub const PACKAGE_PREFIX: &str = "utreexo:";
+
+const PACKAGE_VERSION: u8 = 1;
+
+/// An imported accumulator, used as the assume-utreexo starting point on the next start.
+pub(crate) const IMPORTED_ACCUMULATOR_FILE: &str = "assume_utreexo.toml";
+
+/// Shown before an import is accepted.
+pub const TRUST_WARNING: &str = "An imported accumulator is trusted, not verified. Blocks below \
+    its height are never validated by this device, so whoever made the package decides which \
+    coins exist. Only import packages you exported yourself, from a device you trust.";
+
+/// An assume-utreexo starting point: the accumulator after the block at `height`, as saved
+/// in [`IMPORTED_ACCUMULATOR_FILE`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AssumeUtreexoPoint {
+    pub block_hash: String,
+    pub height: u32,
+    pub leaves: u64,
+    pub roots: Vec<String>,
+}
+
+impl AssumeUtreexoPoint {
+    pub fn to_value(&self) -> Result<AssumeUtreexoValue, String> {
+        let block_hash = BlockHash::from_str(&self.block_hash)
+            .map_err(|e| format!("Invalid assume-utreexo block hash: {}", e))?;
+        let roots = self
+            .roots
+            .iter()
+            .map(|root| {
+                BitcoinNodeHash::from_str(root)
+                    .map_err(|e| format!("Invalid assume-utreexo root '{}': {:?}", root, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(AssumeUtreexoValue {
+            block_hash,
+            height: self.height,
+            leaves: self.leaves,
+            roots,
+        })
+    }
+}
+
+/// The utreexo accumulator after a block, to bootstrap another node from.
+///
+/// Encoded as [`PACKAGE_PREFIX`] and the base58check of: version, network magic, height,
+/// block hash and the serialized [`Stump`]. The checksum only catches transcription errors,
+/// nothing is signed.
+#[derive(Clone, Debug)]
+pub struct AccumulatorPackage {
+    pub magic: Magic,
+    pub height: u32,
+    pub block_hash: BlockHash,
+    pub stump: Stump,
+}
+
+impl AccumulatorPackage {
+    pub fn encode(&self) -> Result<String, String> {
+        let mut payload = vec![PACKAGE_VERSION];
+        payload.extend_from_slice(&self.magic.to_bytes());
+        payload.extend_from_slice(&self.height.to_le_bytes());
+        payload.extend_from_slice(self.block_hash.as_byte_array());
+        self.stump
+            .serialize(&mut payload)
+            .map_err(|e| format!("Failed to serialize the accumulator: {}", e))?;
+
+        Ok(format!(
+            "{}{}",
+            PACKAGE_PREFIX,
+            base58::encode_check(&payload)
+        ))
+    }
+
+    pub fn decode(package: &str) -> Result<Self, String> {
+        let encoded = package
+            .trim()
+            .strip_prefix(PACKAGE_PREFIX)
+            .ok_or("Not an accumulator package")?;
+        let payload = base58::decode_check(encoded)
+            .map_err(|e| format!("Corrupt accumulator package: {}", e))?;
+
+        let mut reader = Cursor::new(payload);
+        let mut version = [0; 1];
+        let mut magic = [0; 4];
+        let mut height = [0; 4];
+        let mut block_hash = [0; 32];
+        for field in [
+            &mut version[..],
+            &mut magic[..],
+            &mut height[..],
+            &mut block_hash[..],
+        ] {
+            reader
+                .read_exact(field)
+                .map_err(|_| "Truncated accumulator package")?;
+        }
+        if version[0] != PACKAGE_VERSION {
+            return Err(format!(
+                "Unsupported accumulator package version {}",
+                version[0]
+            ));
+        }
+        let stump = Stump::deserialize(&mut reader)
+            .map_err(|e| format!("Invalid accumulator in package: {}", e))?;
+
+        Ok(AccumulatorPackage {
+            magic: Magic::from_bytes(magic),
+            height: u32::from_le_bytes(height),
+            block_hash: BlockHash::from_byte_array(block_hash),
+            stump,
+        })
+    }
+
+    /// The network the package was exported on. Custom signets share the default signet's.
+    pub fn network(&self) -> Option<Network> {
+        Network::from_magic(self.magic)
+    }
+
+    pub fn to_assume_utreexo(&self) -> AssumeUtreexoPoint {
+        AssumeUtreexoPoint {
+            block_hash: self.block_hash.to_string(),
+            height: self.height,
+            leaves: self.stump.leaves,
+            roots: self
+                .stump
+                .roots
+                .iter()
+                .map(|root| root.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Package the accumulator of the running node at its validation tip.
+pub(crate) async fn export_accumulator(
+    node_handle: Arc<RwLock<Node>>,
+) -> Result<AccumulatorPackage, BonsaiNodeError> {
+    let node = node_handle.read().await;
+    let magic = node.get_config().await?.network.magic();
+    let height = node.get_validation_height()?;
+
+    Ok(AccumulatorPackage {
+        magic,
+        height,
+        block_hash: node.get_block_hash(height)?,
+        stump: node.get_accumulator()?,
+    })
+}
+
+/// Check that `package` is for the network `node` runs on, and that its block is in our
+/// header chain at the height it claims.
+pub(crate) async fn verify_package(
+    node_handle: Arc<RwLock<Node>>,
+    package: &AccumulatorPackage,
+) -> Result<(), BonsaiNodeError> {
+    let node = node_handle.read().await;
+    let magic = node.get_config().await?.network.magic();
+    if package.magic != magic {
+        return Err(BonsaiNodeError::generic(format!(
+            "The package is for another network (magic {})",
+            package.magic
+        )));
+    }
+
+    match node.get_block_height(&package.block_hash)? {
+        Some(height) if height == package.height => Ok(()),
+        Some(height) => Err(BonsaiNodeError::generic(format!(
+            "Block {} is at height {} in our chain, not {}",
+            package.block_hash, height, package.height
+        ))),
+        None if node.get_height()? < package.height => Err(BonsaiNodeError::generic(format!(
+            "Our headers don't reach height {} yet, try again once they are synced",
+            package.height
+        ))),
+        None => Err(BonsaiNodeError::generic(format!(
+            "Block {} is not in our header chain",
+            package.block_hash
+        ))),
+    }
+}
+
+/// Keep `package` in `data_dir`, to start from on the next start of a fresh chain state.
+pub fn save_imported(data_dir: &Path, package: &AccumulatorPackage) -> Result<(), String> {
+    let contents =
+        toml::to_string_pretty(&package.to_assume_utreexo()).map_err(|e| e.to_string())?;
+    fs::write(data_dir.join(IMPORTED_ACCUMULATOR_FILE), contents)
+        .map_err(|e| format!("Failed to save the imported accumulator: {}", e))?;
+
+    warn!(
+        "Imported a trusted accumulator at height {} ({})",
+        package.height, package.block_hash
+    );
+    Ok(())
+}
+
+/// The accumulator imported into `data_dir`, if there is one.
+pub fn load_imported(data_dir: &Path) -> Option<AssumeUtreexoPoint> {
+    let contents = fs::read_to_string(data_dir.join(IMPORTED_ACCUMULATOR_FILE)).ok()?;
+    match toml::from_str::<AssumeUtreexoPoint>(&contents) {
+        Ok(imported) => {
+            info!(
+                "Using the imported accumulator at height {}",
+                imported.height
+            );
+            Some(imported)
+        }
+        Err(e) => {
+            error!("Failed to parse {}: {}", IMPORTED_ACCUMULATOR_FILE, e);
+            None
+        }
+    }
+}
+
+/// Forget the imported accumulator. Returns whether there was one.
+pub fn remove_imported(data_dir: &Path) -> bool {
+    fs::remove_file(data_dir.join(IMPORTED_ACCUMULATOR_FILE)).is_ok()
+}
@@ -0,0 +1,203 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use bdk_floresta::rustreexo::accumulator::stump::Stump;
+use bitcoin::Block;
+use bitcoin::BlockHash;
+use bitcoin::OutPoint;
+use bitcoin::hashes::Hash;
+use bitcoin::hashes::sha256;
+use once_cell::sync::Lazy;
+
+use crate::node::block_history::BlockEntry;
+use crate::node::block_history::push_block;
+use crate::node::block_pipeline::BlockDelivery;
+use crate::node::block_pipeline::BlockHandler;
+
+/// How many blocks of accumulator changes are kept, about a day's worth.
+pub const ACCUMULATOR_HISTORY_LEN: usize = 144;
+
+/// Scripts larger than this can't be spent, and never enter the accumulator.
+const MAX_SCRIPT_SIZE: usize = 10_000;
+
+/// Accumulator changes of the most recent blocks, oldest first.
+pub(crate) static ACCUMULATOR_HISTORY: Lazy<Mutex<VecDeque<AccumulatorDelta>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(ACCUMULATOR_HISTORY_LEN)));
+
+/// The root of one perfect tree of the forest.
+#[derive(Clone, Debug)]
+pub struct AccumulatorRoot {
+    pub hash: String,
+    /// Height of the tree, so it commits to `2^row` leaves.
+    pub row: u8,
+    /// Position of the root in the forest, numbering leaves first, then each row above.
+    pub position: u64,
+}
+
+/// The structure of a utreexo accumulator.
+#[derive(Clone, Debug)]
+pub struct AccumulatorShape {
+    /// Every leaf ever added, spent or not. Deletions don't lower it.
+    pub leaves: u64,
+    /// Rows of the smallest perfect tree that could hold every leaf.
+    pub forest_rows: u8,
+    /// Tallest tree first, one per bit set in `leaves`.
+    pub roots: Vec<AccumulatorRoot>,
+    /// The roots alone, 32 bytes each.
+    pub roots_size: usize,
+    /// The accumulator as serialized in an accumulator package.
+    pub serialized_size: usize,
+    /// SHA-256 of that serialization. Two nodes at the same block should have the same one.
+    pub commitment: sha256::Hash,
+}
+
+impl AccumulatorShape {
+    pub fn new(stump: &Stump) -> Result<Self, String> {
+        let mut serialized = Vec::new();
+        stump
+            .serialize(&mut serialized)
+            .map_err(|e| format!("Failed to serialize the accumulator: {}", e))?;
+
+        let forest_rows = tree_rows(stump.leaves);
+        // Stump keeps the roots tallest first, the order of the set bits of `leaves` from the top.
+        let rows = (0..64u8).rev().filter(|row| (stump.leaves >> row) & 1 == 1);
+        let roots = stump
+            .roots
+            .iter()
+            .zip(rows)
+            .map(|(root, row)| AccumulatorRoot {
+                hash: root.to_string(),
+                row,
+                position: root_position(stump.leaves, row, forest_rows),
+            })
+            .collect();
+
+        Ok(AccumulatorShape {
+            leaves: stump.leaves,
+            forest_rows,
+            roots,
+            roots_size: 32 * stump.roots.len(),
+            serialized_size: serialized.len(),
+            commitment: sha256::Hash::hash(&serialized),
+        })
+    }
+}
+
+/// Rows needed to hold `leaves` leaves in one perfect tree.
+fn tree_rows(leaves: u64) -> u8 {
+    match leaves {
+        0 => 0,
+        leaves => (64 - (leaves - 1).leading_zeros()) as u8,
+    }
+}
+
+/// Position of the root of the tree at `row`, in a forest of `forest_rows` rows.
+fn root_position(leaves: u64, row: u8, forest_rows: u8) -> u64 {
+    let mask = 2u64
+        .checked_shl(forest_rows as u32)
+        .unwrap_or(0)
+        .wrapping_sub(1);
+    let before = leaves & mask.checked_shl(row as u32 + 1).unwrap_or(0);
+    let shifted = (before >> row)
+        | mask
+            .checked_shl((forest_rows + 1 - row) as u32)
+            .unwrap_or(0);
+    shifted & mask
+}
+
+/// How one block changed the accumulator.
+#[derive(Clone, Debug)]
+pub struct AccumulatorDelta {
+    pub height: u32,
+    pub hash: BlockHash,
+    pub prev_hash: BlockHash,
+    /// New spendable outputs, not counting those spent in the same block.
+    pub leaves_added: u32,
+    /// Outputs of earlier blocks spent by this one.
+    pub leaves_deleted: u32,
+}
+
+impl AccumulatorDelta {
+    pub fn new(block: &Block, height: u32) -> Self {
+        let spent: HashSet<OutPoint> = block
+            .txdata
+            .iter()
+            .filter(|tx| !tx.is_coinbase())
+            .flat_map(|tx| tx.input.iter().map(|input| input.previous_output))
+            .collect();
+
+        let mut leaves_added = 0;
+        let mut spent_in_block = 0;
+        for tx in &block.txdata {
+            let txid = tx.compute_txid();
+            for (vout, output) in tx.output.iter().enumerate() {
+                let script = &output.script_pubkey;
+                if script.is_op_return() || script.len() > MAX_SCRIPT_SIZE {
+                    continue;
+                }
+                if spent.contains(&OutPoint::new(txid, vout as u32)) {
+                    spent_in_block += 1;
+                } else {
+                    leaves_added += 1;
+                }
+            }
+        }
+
+        AccumulatorDelta {
+            height,
+            hash: block.block_hash(),
+            prev_hash: block.header.prev_blockhash,
+            leaves_added,
+            leaves_deleted: spent.len() as u32 - spent_in_block,
+        }
+    }
+}
+
+impl BlockEntry for AccumulatorDelta {
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn hash(&self) -> BlockHash {
+        self.hash
+    }
+
+    fn prev_hash(&self) -> BlockHash {
+        self.prev_hash
+    }
+}
+
+/// Keep `delta`, see [`push_block`] for how reorgs are handled.
+fn record(delta: AccumulatorDelta) {
+    if let Ok(mut history) = ACCUMULATOR_HISTORY.lock() {
+        push_block(&mut history, delta, ACCUMULATOR_HISTORY_LEN);
+    }
+}
+
+/// The accumulator changes of the most recent blocks, newest first.
+pub fn accumulator_history(limit: usize) -> Vec<AccumulatorDelta> {
+    ACCUMULATOR_HISTORY
+        .lock()
+        .map(|history| history.iter().rev().take(limit).cloned().collect())
+        .unwrap_or_default()
+}
+
+pub fn clear_accumulator_history() {
+    if let Ok(mut history) = ACCUMULATOR_HISTORY.lock() {
+        history.clear();
+    }
+}
+
+/// Records an [`AccumulatorDelta`] for every new block.
+pub(crate) struct AccumulatorHistoryHandler;
+
+impl BlockHandler for AccumulatorHistoryHandler {
+    fn name(&self) -> &'static str {
+        "accumulator_history"
+    }
+
+    fn handle(&mut self, delivery: &BlockDelivery) {
+        record(AccumulatorDelta::new(&delivery.block, delivery.height));
+    }
+}
@@ -0,0 +1,110 @@
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::RwLock;
+use tracing::error;
+use tracing::info;
+
+use crate::common::util::unix_now;
+
+pub(crate) const BAN_LIST_FILE: &str = "banlist.toml";
+
+/// The ban list of the running node, loaded from its data directory on start.
+pub(crate) static BAN_LIST: Lazy<Arc<RwLock<Option<BanList>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(None)));
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BanEntry {
+    pub address: IpAddr,
+    /// UNIX timestamp of when the ban was created.
+    pub created_at: u64,
+    /// UNIX timestamp of when the ban expires.
+    pub banned_until: u64,
+}
+
+/// Peers banned by the user, persisted as `banlist.toml` in the node's data directory.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BanList {
+    #[serde(default)]
+    bans: Vec<BanEntry>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl BanList {
+    /// Load the ban list from `data_dir`, or start an empty one if there is none.
+    pub fn load(data_dir: &Path) -> Self {
+        let path = data_dir.join(BAN_LIST_FILE);
+
+        let mut ban_list = match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str::<BanList>(&contents).unwrap_or_else(|e| {
+                error!("Failed to parse {}: {}", path.to_string_lossy(), e);
+                BanList::default()
+            }),
+            Err(_) => BanList::default(),
+        };
+        ban_list.path = path;
+        ban_list.prune_expired();
+
+        info!("Loaded {} banned peer(s)", ban_list.bans.len());
+        ban_list
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let contents = toml::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(&self.path, contents).map_err(|e| {
+            format!(
+                "Failed to write ban list to {}: {}",
+                self.path.to_string_lossy(),
+                e
+            )
+        })
+    }
+
+    /// Ban `address` for `duration_secs`, replacing any existing ban on it.
+    pub fn ban(&mut self, address: IpAddr, duration_secs: u64) {
+        let now = unix_now();
+        self.bans.retain(|ban| ban.address != address);
+        self.bans.push(BanEntry {
+            address,
+            created_at: now,
+            banned_until: now.saturating_add(duration_secs),
+        });
+    }
+
+    /// Lift the ban on `address`. Returns whether it was banned.
+    pub fn unban(&mut self, address: IpAddr) -> bool {
+        let len = self.bans.len();
+        self.bans.retain(|ban| ban.address != address);
+        self.bans.len() != len
+    }
+
+    pub fn clear(&mut self) {
+        self.bans.clear();
+    }
+
+    pub fn is_banned(&self, address: IpAddr) -> bool {
+        let now = unix_now();
+        self.bans
+            .iter()
+            .any(|ban| ban.address == address && ban.banned_until > now)
+    }
+
+    /// Drop expired bans. Returns whether anything was removed.
+    pub fn prune_expired(&mut self) -> bool {
+        let now = unix_now();
+        let len = self.bans.len();
+        self.bans.retain(|ban| ban.banned_until > now);
+        self.bans.len() != len
+    }
+
+    pub fn entries(&self) -> &[BanEntry] {
+        &self.bans
+    }
+}
@@ -0,0 +1,246 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use bdk_floresta::Node;
+use bitcoin::Address;
+use bitcoin::Amount;
+use bitcoin::Block;
+use bitcoin::BlockHash;
+use bitcoin::Network;
+use bitcoin::Transaction;
+use bitcoin::block::Header;
+use bitcoin::constants::SUBSIDY_HALVING_INTERVAL;
+use tokio::sync::RwLock;
+
+use crate::node::error::BonsaiNodeError;
+use crate::node::script_analyzer::InscriptionEnvelope;
+use crate::node::script_analyzer::OpReturnPayload;
+use crate::node::script_analyzer::ScriptKind;
+use crate::node::script_analyzer::decode_op_return;
+use crate::node::script_analyzer::find_inscription;
+
+const INITIAL_SUBSIDY: u64 = 50 * 100_000_000; // 50 BTC in satoshis.
+
+/// Blocks between halvings on `network`, as in Bitcoin Core's chain params.
+fn subsidy_halving_interval(network: Network) -> u32 {
+    match network {
+        Network::Regtest => 150,
+        _ => SUBSIDY_HALVING_INTERVAL,
+    }
+}
+
+/// Get the block subsidy in satoshis based on blockheight.
+pub(crate) fn get_block_subsidy(height: u32, network: Network) -> u64 {
+    let halvings = height / subsidy_halving_interval(network);
+
+    if halvings >= 64 {
+        return 0;
+    }
+
+    INITIAL_SUBSIDY >> halvings
+}
+
+/// A block, either by height on the best chain or by hash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockQuery {
+    Height(u32),
+    Hash(BlockHash),
+}
+
+impl FromStr for BlockQuery {
+    type Err = String;
+
+    /// A 64 character hex string is a hash, anything else must be a height.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.len() == 64 {
+            return BlockHash::from_str(s)
+                .map(BlockQuery::Hash)
+                .map_err(|e| format!("Invalid block hash: {}", e));
+        }
+
+        s.replace(',', "")
+            .parse::<u32>()
+            .map(BlockQuery::Height)
+            .map_err(|_| format!("'{}' is neither a block height nor a block hash", s))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HeaderSummary {
+    pub hash: BlockHash,
+    pub height: u32,
+    pub header: Header,
+    pub difficulty: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct DecodedInput {
+    /// `None` for the coinbase input.
+    pub previous_output: Option<String>,
+    pub sequence: u32,
+    pub script_sig_size: usize,
+    pub witness_items: usize,
+    pub inscription: Option<InscriptionEnvelope>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DecodedOutput {
+    pub value: Amount,
+    pub script_kind: ScriptKind,
+    pub address: Option<String>,
+    pub script_hex: String,
+    pub op_return: Option<OpReturnPayload>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DecodedTransaction {
+    pub txid: String,
+    pub is_coinbase: bool,
+    pub size: usize,
+    pub vsize: usize,
+    pub weight: u64,
+    pub inputs: Vec<DecodedInput>,
+    pub outputs: Vec<DecodedOutput>,
+    pub output_total: Amount,
+}
+
+/// What the coinbase claims, split into the new coins and the fees collected.
+#[derive(Clone, Copy, Debug)]
+pub struct SubsidySplit {
+    pub subsidy: Amount,
+    /// Coinbase output value above the subsidy.
+    pub fees: Amount,
+    pub coinbase_value: Amount,
+}
+
+#[derive(Clone, Debug)]
+pub struct BlockDetails {
+    pub summary: HeaderSummary,
+    pub size: usize,
+    pub weight: u64,
+    pub transactions: Vec<DecodedTransaction>,
+    pub subsidy: SubsidySplit,
+}
+
+pub fn decode_transaction(tx: &Transaction, network: Network) -> DecodedTransaction {
+    let is_coinbase = tx.is_coinbase();
+    let inputs = tx
+        .input
+        .iter()
+        .map(|input| DecodedInput {
+            previous_output: (!is_coinbase).then(|| input.previous_output.to_string()),
+            sequence: input.sequence.to_consensus_u32(),
+            script_sig_size: input.script_sig.len(),
+            witness_items: input.witness.len(),
+            inscription: find_inscription(input),
+        })
+        .collect();
+    let outputs = tx
+        .output
+        .iter()
+        .map(|output| DecodedOutput {
+            value: output.value,
+            script_kind: ScriptKind::classify(&output.script_pubkey),
+            address: Address::from_script(&output.script_pubkey, network)
+                .ok()
+                .map(|address| address.to_string()),
+            script_hex: output.script_pubkey.to_hex_string(),
+            op_return: decode_op_return(&output.script_pubkey),
+        })
+        .collect();
+
+    DecodedTransaction {
+        txid: tx.compute_txid().to_string(),
+        is_coinbase,
+        size: tx.total_size(),
+        vsize: tx.vsize(),
+        weight: tx.weight().to_wu(),
+        inputs,
+        outputs,
+        output_total: tx.output.iter().map(|output| output.value).sum(),
+    }
+}
+
+pub fn subsidy_split(block: &Block, height: u32, network: Network) -> SubsidySplit {
+    let subsidy = Amount::from_sat(get_block_subsidy(height, network));
+    let coinbase_value = block
+        .txdata
+        .first()
+        .map(|coinbase| coinbase.output.iter().map(|output| output.value).sum())
+        .unwrap_or(Amount::ZERO);
+
+    SubsidySplit {
+        subsidy,
+        fees: coinbase_value.checked_sub(subsidy).unwrap_or(Amount::ZERO),
+        coinbase_value,
+    }
+}
+
+/// The hash and height of the block `query` points at.
+fn locate(node: &Node, query: BlockQuery) -> Result<(BlockHash, u32), BonsaiNodeError> {
+    match query {
+        BlockQuery::Height(height) => {
+            let hash = node.get_block_hash(height)?;
+            Ok((hash, height))
+        }
+        BlockQuery::Hash(hash) => match node.get_block_height(&hash)? {
+            Some(height) => Ok((hash, height)),
+            None => Err(BonsaiNodeError::generic(format!(
+                "Block {} is not in our chain",
+                hash
+            ))),
+        },
+    }
+}
+
+pub(crate) async fn fetch_header(
+    node_handle: Arc<RwLock<Node>>,
+    query: BlockQuery,
+) -> Result<HeaderSummary, BonsaiNodeError> {
+    let node = node_handle.read().await;
+    let (hash, height) = locate(&node, query)?;
+    let header = node.get_block_header(&hash)?;
+
+    Ok(HeaderSummary {
+        hash,
+        height,
+        difficulty: header.difficulty_float(),
+        header,
+    })
+}
+
+/// Fetch a block from our peers and decode it.
+pub(crate) async fn fetch_block(
+    node_handle: Arc<RwLock<Node>>,
+    query: BlockQuery,
+) -> Result<BlockDetails, BonsaiNodeError> {
+    let (hash, height, network) = {
+        let node = node_handle.read().await;
+        let (hash, height) = locate(&node, query)?;
+        (hash, height, node.get_config().await?.network)
+    };
+    let block = node_handle
+        .read()
+        .await
+        .get_block(&hash)
+        .await?
+        .ok_or_else(|| BonsaiNodeError::generic(format!("Block {} is not available", hash)))?;
+
+    Ok(BlockDetails {
+        summary: HeaderSummary {
+            hash,
+            height,
+            difficulty: block.header.difficulty_float(),
+            header: block.header,
+        },
+        size: block.total_size(),
+        weight: block.weight().to_wu(),
+        transactions: block
+            .txdata
+            .iter()
+            .map(|tx| decode_transaction(tx, network))
+            .collect(),
+        subsidy: subsidy_split(&block, height, network),
+    })
+}
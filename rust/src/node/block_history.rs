@@ -0,0 +1,33 @@
+use std::collections::VecDeque;
+
+use bitcoin::BlockHash;
+
+/// One block's entry in a buffer of the most recent blocks on our best chain.
+pub(crate) trait BlockEntry {
+    fn height(&self) -> u32;
+    fn hash(&self) -> BlockHash;
+    fn prev_hash(&self) -> BlockHash;
+}
+
+/// Keep `entry` in `buffer`, dropping the oldest entry past `capacity`.
+///
+/// On a reorg the node sends the new branch from the fork point up, so everything at or above
+/// the new block's height is stale. If the block still doesn't connect to what is left, the
+/// buffer can't be trusted and starts over.
+pub(crate) fn push_block<T: BlockEntry>(buffer: &mut VecDeque<T>, entry: T, capacity: usize) {
+    while buffer
+        .back()
+        .is_some_and(|last| last.height() >= entry.height())
+    {
+        buffer.pop_back();
+    }
+    if let Some(last) = buffer.back() {
+        if last.height() + 1 != entry.height() || last.hash() != entry.prev_hash() {
+            buffer.clear();
+        }
+    }
+    if buffer.len() == capacity {
+        buffer.pop_front();
+    }
+    buffer.push_back(entry);
+}
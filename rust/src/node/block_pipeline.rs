@@ -0,0 +1,600 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::sync::Weak;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::sync::mpsc::SyncSender;
+use std::sync::mpsc::TrySendError;
+use std::thread;
+use std::time::Instant;
+
+use bdk_floresta::BlockConsumer;
+use bdk_floresta::Node;
+use bdk_floresta::UtxoData;
+use bitcoin::Block;
+use bitcoin::BlockHash;
+use bitcoin::Network;
+use bitcoin::OutPoint;
+use once_cell::sync::Lazy;
+use tokio::runtime::Handle;
+use tokio::runtime::RuntimeFlavor;
+use tokio::task::block_in_place;
+use tokio::task::spawn_blocking;
+use tracing::error;
+use tracing::info;
+use tracing::warn;
+
+use crate::node::error::BonsaiNodeError;
+
+/// How many blocks may wait for each handler before the node is held back.
+pub const BLOCK_QUEUE_LEN: usize = 16;
+
+pub(crate) const CHECKPOINTS_FILE: &str = "block_consumers.toml";
+
+/// The pipeline of the running node, where block handlers are added and removed.
+pub(crate) static BLOCK_PIPELINE: Lazy<RwLock<Option<Arc<BlockPipeline>>>> =
+    Lazy::new(|| RwLock::new(None));
+
+/// A block as handed to each [`BlockHandler`]. Cloning it is cheap, the block is shared.
+#[derive(Clone)]
+pub struct BlockDelivery {
+    pub block: Arc<Block>,
+    pub height: u32,
+    pub network: Network,
+    /// The outputs the block spends, if the handler asked for them.
+    /// Always `None` for blocks replayed during catch-up.
+    pub spent_utxos: Option<Arc<HashMap<OutPoint, UtxoData>>>,
+}
+
+/// Where a handler wants its first block from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StartFrom {
+    /// Only blocks that arrive from now on.
+    #[default]
+    Tip,
+    /// Replay the chain from this height, then follow the tip.
+    Height(u32),
+    /// Resume after the last block this handler processed in a previous run, or follow the tip.
+    Checkpoint,
+}
+
+/// Something that wants every new block, in order.
+///
+/// Each handler gets its own worker thread, so a slow handler only holds back the node
+/// once its queue is full, and never another handler. A handler may see a height again
+/// after a reorg, and must handle that.
+pub trait BlockHandler: Send + 'static {
+    /// Unique within the pipeline, and the key of the handler's checkpoint.
+    fn name(&self) -> &'static str;
+
+    fn wants_spent_utxos(&self) -> bool {
+        false
+    }
+
+    fn start_from(&self) -> StartFrom {
+        StartFrom::Tip
+    }
+
+    fn handle(&mut self, delivery: &BlockDelivery);
+}
+
+/// Counters of one handler, updated by the pipeline and its worker.
+#[derive(Debug, Default)]
+pub struct HandlerMetrics {
+    pub delivered: AtomicU64,
+    pub processed: AtomicU64,
+    pub last_height: AtomicU32,
+    /// How often the node had to wait because this handler's queue was full.
+    pub stalls: AtomicU64,
+    pub busy_micros: AtomicU64,
+}
+
+#[derive(Clone, Debug)]
+pub struct HandlerMetricsSnapshot {
+    pub name: &'static str,
+    pub delivered: u64,
+    pub processed: u64,
+    /// Blocks delivered but not processed yet.
+    pub lag: u64,
+    pub last_height: u32,
+    pub stalls: u64,
+    pub avg_process_micros: u64,
+    pub catching_up: bool,
+}
+
+#[derive(Debug, Default)]
+struct WorkerState {
+    /// The height to replay from, once the pipeline is attached to a node.
+    pending_catch_up: Option<u32>,
+    /// Live blocks are left to the catch-up task until it reaches the tip.
+    catching_up: bool,
+    last_sent: Option<(u32, BlockHash)>,
+    /// Set when the handler is removed, to stop its catch-up task.
+    removed: bool,
+}
+
+struct Worker {
+    name: &'static str,
+    wants_spent_utxos: bool,
+    tx: SyncSender<BlockDelivery>,
+    metrics: Arc<HandlerMetrics>,
+    state: Mutex<WorkerState>,
+}
+
+/// Block on a full queue without starving the async runtime we may be called from.
+fn send_blocking(tx: &SyncSender<BlockDelivery>, delivery: BlockDelivery) -> bool {
+    let send = || tx.send(delivery).is_ok();
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => block_in_place(send),
+        _ => send(),
+    }
+}
+
+impl Worker {
+    /// Queue `delivery`, unless it is the block sent last. `state` is this worker's, locked.
+    fn deliver(&self, state: &mut WorkerState, delivery: BlockDelivery) -> bool {
+        let key = (delivery.height, delivery.block.block_hash());
+        if state.last_sent == Some(key) {
+            return true;
+        }
+
+        // Counted before it is sent, so the worker never gets ahead of it.
+        self.metrics.delivered.fetch_add(1, Ordering::Relaxed);
+        let sent = match self.tx.try_send(delivery) {
+            Ok(()) => true,
+            Err(TrySendError::Full(delivery)) => {
+                self.metrics.stalls.fetch_add(1, Ordering::Relaxed);
+                send_blocking(&self.tx, delivery)
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        };
+        self.sent(state, key, sent)
+    }
+
+    /// Queue a replayed `delivery`, unless the handler was removed or already has it.
+    ///
+    /// Unlike [`Worker::deliver`], a full queue is waited on from a blocking thread with the
+    /// state unlocked, so neither the runtime nor live delivery is held up meanwhile.
+    async fn deliver_replayed(&self, delivery: BlockDelivery) -> bool {
+        let key = (delivery.height, delivery.block.block_hash());
+        match self.state.lock() {
+            Ok(state) if state.removed => return false,
+            Ok(state) if state.last_sent == Some(key) => return true,
+            Ok(_) => {}
+            Err(_) => return false,
+        }
+
+        self.metrics.delivered.fetch_add(1, Ordering::Relaxed);
+        let sent = match self.tx.try_send(delivery) {
+            Ok(()) => true,
+            Err(TrySendError::Full(delivery)) => {
+                self.metrics.stalls.fetch_add(1, Ordering::Relaxed);
+                let tx = self.tx.clone();
+                spawn_blocking(move || tx.send(delivery).is_ok())
+                    .await
+                    .unwrap_or(false)
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        };
+        match self.state.lock() {
+            Ok(mut state) => self.sent(&mut state, key, sent),
+            Err(_) => false,
+        }
+    }
+
+    /// Record the outcome of sending the block `key`. `state` is this worker's, locked.
+    fn sent(&self, state: &mut WorkerState, key: (u32, BlockHash), sent: bool) -> bool {
+        if sent {
+            state.last_sent = Some(key);
+        } else {
+            self.metrics.delivered.fetch_sub(1, Ordering::Relaxed);
+            error!(
+                "Block handler {} is gone, dropping block {}",
+                self.name, key.0
+            );
+        }
+        sent
+    }
+
+    fn finish_catch_up(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.catching_up = false;
+        }
+    }
+}
+
+/// A registry of [`BlockHandler`]s, fanning each block out through bounded queues.
+///
+/// Handlers can be added and removed while the node runs.
+pub struct BlockPipeline {
+    workers: RwLock<Vec<Arc<Worker>>>,
+    node: RwLock<Option<Weak<tokio::sync::RwLock<Node>>>>,
+    /// The handlers' last processed heights from previous runs, see [`StartFrom::Checkpoint`].
+    checkpoints: Mutex<HashMap<String, u32>>,
+    checkpoints_path: Option<PathBuf>,
+    network: Network,
+}
+
+impl BlockPipeline {
+    /// An empty pipeline for `network`, keeping handler checkpoints in `data_dir` if given.
+    pub fn new(data_dir: Option<&Path>, network: Network) -> Self {
+        let checkpoints_path = data_dir.map(|dir| dir.join(CHECKPOINTS_FILE));
+        let checkpoints = checkpoints_path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| {
+                toml::from_str(&contents)
+                    .map_err(|e| error!("Failed to parse {}: {}", CHECKPOINTS_FILE, e))
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        BlockPipeline {
+            workers: RwLock::new(Vec::new()),
+            node: RwLock::new(None),
+            checkpoints: Mutex::new(checkpoints),
+            checkpoints_path,
+            network,
+        }
+    }
+
+    /// Add a handler. If it starts below the tip, it catches up from the node before going live.
+    pub fn register(&self, mut handler: Box<dyn BlockHandler>) -> Result<(), String> {
+        let name = handler.name();
+        let wants_spent_utxos = handler.wants_spent_utxos();
+        let start_height = match handler.start_from() {
+            StartFrom::Tip => None,
+            StartFrom::Height(height) => Some(height),
+            StartFrom::Checkpoint => self
+                .checkpoints
+                .lock()
+                .ok()
+                .and_then(|checkpoints| checkpoints.get(name).map(|height| height + 1)),
+        };
+
+        let mut workers = self.workers.write().map_err(|e| e.to_string())?;
+        if workers.iter().any(|worker| worker.name == name) {
+            return Err(format!("Block handler {} is already registered", name));
+        }
+
+        let metrics = Arc::new(HandlerMetrics::default());
+        let (tx, rx) = mpsc::sync_channel::<BlockDelivery>(BLOCK_QUEUE_LEN);
+        let worker_metrics = metrics.clone();
+        thread::Builder::new()
+            .name(format!("blocks-{}", name))
+            .spawn(move || {
+                // Ends once the handler is removed, or the pipeline dropped.
+                for delivery in rx {
+                    let started = Instant::now();
+                    handler.handle(&delivery);
+                    worker_metrics
+                        .busy_micros
+                        .fetch_add(started.elapsed().as_micros() as u64, Ordering::Relaxed);
+                    worker_metrics.processed.fetch_add(1, Ordering::Relaxed);
+                    worker_metrics
+                        .last_height
+                        .store(delivery.height, Ordering::Relaxed);
+                }
+            })
+            .map_err(|e| format!("Failed to start block handler {}: {}", name, e))?;
+
+        let worker = Arc::new(Worker {
+            name,
+            wants_spent_utxos,
+            tx,
+            metrics,
+            state: Mutex::new(WorkerState {
+                pending_catch_up: start_height,
+                catching_up: start_height.is_some(),
+                ..Default::default()
+            }),
+        });
+        workers.push(worker.clone());
+        drop(workers);
+
+        info!("Registered block handler {}", name);
+        self.spawn_catch_up(&worker);
+        Ok(())
+    }
+
+    /// Remove a handler, saving its checkpoint. Returns whether it was registered.
+    pub fn unregister(&self, name: &str) -> bool {
+        let removed = match self.workers.write() {
+            Ok(mut workers) => workers
+                .iter()
+                .position(|worker| worker.name == name)
+                .map(|index| workers.remove(index)),
+            Err(_) => None,
+        };
+        let Some(worker) = removed else {
+            return false;
+        };
+
+        if let Ok(mut state) = worker.state.lock() {
+            state.removed = true;
+        }
+        self.checkpoint(&worker);
+        self.save_checkpoints();
+        info!("Removed block handler {}", name);
+        true
+    }
+
+    pub fn handler_names(&self) -> Vec<&'static str> {
+        self.workers
+            .read()
+            .map(|workers| workers.iter().map(|worker| worker.name).collect())
+            .unwrap_or_default()
+    }
+
+    /// Let the pipeline fetch past blocks from `node`, and start any pending catch-up.
+    pub fn attach(&self, node: &Arc<tokio::sync::RwLock<Node>>) {
+        if let Ok(mut attached) = self.node.write() {
+            *attached = Some(Arc::downgrade(node));
+        }
+
+        let workers = self
+            .workers
+            .read()
+            .map(|workers| workers.clone())
+            .unwrap_or_default();
+        for worker in &workers {
+            self.spawn_catch_up(worker);
+        }
+    }
+
+    fn spawn_catch_up(&self, worker: &Arc<Worker>) {
+        // Not attached yet, `attach` will get back to it.
+        let Some(node) = self.node.read().ok().and_then(|node| node.clone()) else {
+            return;
+        };
+        let Some(from) = worker
+            .state
+            .lock()
+            .ok()
+            .and_then(|mut state| state.pending_catch_up.take())
+        else {
+            return;
+        };
+
+        match Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(catch_up(worker.clone(), node, self.network, from));
+            }
+            Err(_) => {
+                error!("No runtime to catch up block handler {} on", worker.name);
+                worker.finish_catch_up();
+            }
+        }
+    }
+
+    /// Remember the last block `worker` processed, if any.
+    fn checkpoint(&self, worker: &Worker) {
+        if worker.metrics.processed.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        let height = worker.metrics.last_height.load(Ordering::Relaxed);
+        if let Ok(mut checkpoints) = self.checkpoints.lock() {
+            checkpoints.insert(worker.name.to_string(), height);
+        }
+    }
+
+    /// Write each handler's last processed height to the checkpoints file.
+    pub fn save_checkpoints(&self) {
+        let Some(path) = &self.checkpoints_path else {
+            return;
+        };
+
+        if let Ok(workers) = self.workers.read() {
+            for worker in workers.iter() {
+                self.checkpoint(worker);
+            }
+        }
+        let Ok(checkpoints) = self.checkpoints.lock() else {
+            return;
+        };
+
+        let result = toml::to_string_pretty(&*checkpoints)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| fs::write(path, contents).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            error!("Failed to save block handler checkpoints: {}", e);
+        }
+    }
+
+    pub fn metrics(&self) -> Vec<HandlerMetricsSnapshot> {
+        let workers = match self.workers.read() {
+            Ok(workers) => workers.clone(),
+            Err(_) => return Vec::new(),
+        };
+
+        workers
+            .iter()
+            .map(|worker| {
+                let delivered = worker.metrics.delivered.load(Ordering::Relaxed);
+                let processed = worker.metrics.processed.load(Ordering::Relaxed);
+                let busy_micros = worker.metrics.busy_micros.load(Ordering::Relaxed);
+                HandlerMetricsSnapshot {
+                    name: worker.name,
+                    delivered,
+                    processed,
+                    lag: delivered.saturating_sub(processed),
+                    last_height: worker.metrics.last_height.load(Ordering::Relaxed),
+                    stalls: worker.metrics.stalls.load(Ordering::Relaxed),
+                    avg_process_micros: busy_micros.checked_div(processed).unwrap_or(0),
+                    catching_up: worker
+                        .state
+                        .lock()
+                        .map(|state| state.catching_up)
+                        .unwrap_or(false),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Replay blocks from `from` up to the tip into `worker`, then hand it over to live delivery.
+async fn catch_up(
+    worker: Arc<Worker>,
+    node: Weak<tokio::sync::RwLock<Node>>,
+    network: Network,
+    from: u32,
+) {
+    info!(
+        "Block handler {} catching up from height {}",
+        worker.name, from
+    );
+    let mut height = from;
+
+    loop {
+        let Some(node_handle) = node.upgrade() else {
+            return;
+        };
+        let node = node_handle.read().await;
+
+        let tip = match node.get_validation_height() {
+            Ok(tip) => tip,
+            Err(e) => {
+                error!("Block handler {} can't catch up: {}", worker.name, e);
+                worker.finish_catch_up();
+                return;
+            }
+        };
+
+        if height > tip {
+            // The node may have moved on meanwhile. Check again with the state locked, so
+            // no live block can slip in between: it waits for the lock and finds us live.
+            let Ok(mut state) = worker.state.lock() else {
+                return;
+            };
+            if node.get_validation_height().is_ok_and(|tip| tip >= height) {
+                continue;
+            }
+            state.catching_up = false;
+            info!("Block handler {} caught up at height {}", worker.name, tip);
+            return;
+        }
+
+        let block = match node.get_block_hash(height) {
+            Ok(hash) => node.get_block(&hash).await.ok().flatten(),
+            Err(_) => None,
+        };
+        // Let go of the node before waiting on the handler, which may take a while.
+        drop(node);
+        drop(node_handle);
+        let Some(block) = block else {
+            warn!(
+                "Block {} is not available, block handler {} skips to the tip",
+                height, worker.name
+            );
+            worker.finish_catch_up();
+            return;
+        };
+
+        let delivery = BlockDelivery {
+            block: Arc::new(block),
+            height,
+            network,
+            spent_utxos: None,
+        };
+        if !worker.deliver_replayed(delivery).await {
+            return;
+        }
+        height += 1;
+    }
+}
+
+/// Add a handler to the running node's pipeline.
+pub fn register_handler(handler: Box<dyn BlockHandler>) -> Result<(), BonsaiNodeError> {
+    let pipeline = BLOCK_PIPELINE
+        .read()
+        .map_err(|e| e.to_string())?
+        .clone()
+        .ok_or_else(BonsaiNodeError::not_running)?;
+    Ok(pipeline.register(handler)?)
+}
+
+/// Remove a handler from the running node's pipeline. Returns whether it was registered.
+pub fn unregister_handler(name: &str) -> bool {
+    let pipeline = BLOCK_PIPELINE
+        .read()
+        .ok()
+        .and_then(|pipeline| pipeline.clone());
+    pipeline.is_some_and(|pipeline| pipeline.unregister(name))
+}
+
+/// The names of the running node's block handlers.
+pub fn registered_handlers() -> Vec<&'static str> {
+    BLOCK_PIPELINE
+        .read()
+        .ok()
+        .and_then(|pipeline| pipeline.as_ref().map(|pipeline| pipeline.handler_names()))
+        .unwrap_or_default()
+}
+
+/// Metrics of the running node's block handlers.
+pub fn pipeline_metrics() -> Vec<HandlerMetricsSnapshot> {
+    BLOCK_PIPELINE
+        .read()
+        .ok()
+        .and_then(|pipeline| pipeline.as_ref().map(|pipeline| pipeline.metrics()))
+        .unwrap_or_default()
+}
+
+/// Save the handlers' checkpoints and let go of the pipeline.
+/// Its workers stop once the node drops it too.
+pub fn clear_block_pipeline() {
+    if let Ok(mut pipeline) = BLOCK_PIPELINE.write() {
+        if let Some(pipeline) = pipeline.take() {
+            pipeline.save_checkpoints();
+        }
+    }
+}
+
+impl BlockConsumer for BlockPipeline {
+    fn on_block(
+        &self,
+        block: &Block,
+        height: u32,
+        spent_utxos: Option<&HashMap<OutPoint, UtxoData>>,
+    ) {
+        let workers = match self.workers.read() {
+            Ok(workers) => workers.clone(),
+            Err(_) => return,
+        };
+
+        // One copy of the block, shared by every handler.
+        let block = Arc::new(block.clone());
+        let spent_utxos = spent_utxos
+            .filter(|_| workers.iter().any(|worker| worker.wants_spent_utxos))
+            .map(|spent_utxos| Arc::new(spent_utxos.clone()));
+
+        for worker in &workers {
+            let Ok(mut state) = worker.state.lock() else {
+                continue;
+            };
+            if state.catching_up {
+                continue;
+            }
+
+            let delivery = BlockDelivery {
+                block: block.clone(),
+                height,
+                network: self.network,
+                spent_utxos: spent_utxos.clone().filter(|_| worker.wants_spent_utxos),
+            };
+            worker.deliver(&mut state, delivery);
+        }
+    }
+
+    /// Asked once, when the pipeline subscribes, so handlers added later can still get them.
+    fn wants_spent_utxos(&self) -> bool {
+        true
+    }
+}
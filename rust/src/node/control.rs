@@ -1,47 +1,67 @@
 use core::fmt::Display;
-use std::collections::HashMap;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
-use bdk_floresta::BlockConsumer;
 use bdk_floresta::Node;
 use bdk_floresta::UtreexoNodeConfig;
-use bdk_floresta::UtxoData;
 use bdk_floresta::builder::Builder;
-use bitcoin::Block;
 use bitcoin::Network;
-use bitcoin::OutPoint;
 // use iced::Element;
 // use iced::Subscription;
 // use iced::Task;
 // use iced::clipboard;
 use futures::SinkExt;
 // use iced::widget::qr_code;
-use once_cell::sync::Lazy;
 use tokio::runtime::Handle;
-use tokio::sync::Mutex;
 use tokio::sync::RwLock;
-use tokio::sync::mpsc;
 use tracing::error;
 use tracing::info;
+use tracing::warn;
 
 // use crate::Tab;
+use crate::common::util::default_p2p_port;
 use crate::common::util::format_thousands;
+use crate::node::accumulator_stats::AccumulatorHistoryHandler;
+use crate::node::ban_list::BAN_LIST;
+use crate::node::block_pipeline::BLOCK_PIPELINE;
+use crate::node::block_pipeline::BlockHandler;
+use crate::node::block_pipeline::BlockPipeline;
 use crate::node::error::BonsaiNodeError;
+use crate::node::fee_stats::FeeStatsHandler;
+use crate::node::fixed_peers::FIXED_PEERS;
+use crate::node::geoip::GEOIP_READER;
 use crate::node::geoip::GeoIpReader;
 use crate::node::log_capture::LogCapture;
 use crate::node::message::NodeMessage;
+use crate::node::peer_diversity::DIVERSITY_POLICY;
+use crate::node::peer_diversity::DiversityReport;
+use crate::node::peer_diversity::evaluate;
+use crate::node::recent_blocks::RecentBlocksHandler;
+use crate::node::reorg::ReorgDetector;
 use crate::node::stats_fetcher::NodeStatistics;
 use crate::node::stats_fetcher::fetch_stats;
+use crate::node::stats_fetcher::process_peer_infos;
+use crate::node::watchlist::WatchlistHandler;
+use crate::wallet::manager::WalletBlockHandler;
+use crate::wallet::tx_index::TxIndexHandler;
 
 pub const DATA_DIR: &str = "./data/";
 pub const NETWORK: Network = Network::Signet;
 pub const FETCH_STATISTICS_TIME: u64 = 1;
 
-static BLOCK_RECEIVER: Lazy<Arc<Mutex<Option<mpsc::UnboundedReceiver<Block>>>>> =
-    Lazy::new(|| Arc::new(Mutex::new(None)));
+/// The block handlers every node starts with, see [`builtin_block_handler`].
+pub const DEFAULT_BLOCK_HANDLERS: &[&str] = &[
+    "reorg_detector",
+    "fee_stats",
+    "recent_blocks",
+    "wallet",
+    "tx_index",
+    "watchlist",
+    "accumulator_history",
+];
 
 #[derive(Clone, Debug, Default)]
 pub enum NodeStatus {
@@ -66,26 +86,6 @@ impl Display for NodeStatus {
     }
 }
 
-pub(crate) struct BlockForwarder {
-    tx: mpsc::UnboundedSender<Block>,
-}
-
-impl BlockConsumer for BlockForwarder {
-    fn on_block(
-        &self,
-        block: &Block,
-        _height: u32,
-        _spent_utxos: Option<&HashMap<OutPoint, UtxoData>>,
-    ) {
-        let _ = self.tx.send(block.clone());
-    }
-
-    #[allow(unused)]
-    fn wants_spent_utxos(&self) -> bool {
-        false
-    }
-}
-
 /*
 #[derive(Default)]
 pub(crate) struct EmbeddedNode {
@@ -136,28 +136,62 @@ impl EmbeddedNode {
 }
 */
 
+/// A fresh instance of the built-in block handler called `name`.
+pub(crate) fn builtin_block_handler(name: &str) -> Option<Box<dyn BlockHandler>> {
+    match name {
+        "reorg_detector" => Some(Box::new(ReorgDetector::default())),
+        "fee_stats" => Some(Box::new(FeeStatsHandler)),
+        "recent_blocks" => Some(Box::new(RecentBlocksHandler)),
+        "wallet" => Some(Box::new(WalletBlockHandler)),
+        "tx_index" => Some(Box::new(TxIndexHandler)),
+        "watchlist" => Some(Box::new(WatchlistHandler)),
+        "accumulator_history" => Some(Box::new(AccumulatorHistoryHandler)),
+        _ => None,
+    }
+}
+
 pub(crate) async fn start_node(
     node_config: UtreexoNodeConfig,
+    seed_peers: Vec<SocketAddr>,
 ) -> Result<Arc<RwLock<Node>>, String> {
     let rt_handle = Handle::current();
 
     rt_handle
         .spawn(async {
+            let data_dir = PathBuf::from(&node_config.datadir);
+            let network = node_config.network;
             let node = Builder::new()
                 .from_config(node_config)
                 .build()
                 .await
                 .map_err(|e| e.to_string())?;
 
-            let (block_tx, block_rx) = mpsc::unbounded_channel();
-            let forwarder = Arc::new(BlockForwarder { tx: block_tx });
+            let pipeline = Arc::new(BlockPipeline::new(Some(&data_dir), network));
+            for name in DEFAULT_BLOCK_HANDLERS {
+                if let Some(handler) = builtin_block_handler(name) {
+                    if let Err(e) = pipeline.register(handler) {
+                        error!("{}", e);
+                    }
+                }
+            }
+            node.block_subscriber(pipeline.clone());
+
+            for peer in seed_peers {
+                match node.connect_peer(&peer).await {
+                    Ok(_) => info!("Connecting to seed peer {}", peer),
+                    Err(e) => error!("Failed to connect to seed peer {}: {}", peer, e),
+                }
+            }
 
-            node.block_subscriber(forwarder);
+            let node = Arc::new(RwLock::new(node));
+            pipeline.attach(&node);
 
-            // Store receiver globally
-            *BLOCK_RECEIVER.lock().await = Some(block_rx);
+            // Keep it around so handlers can be added and removed later.
+            if let Ok(mut block_pipeline) = BLOCK_PIPELINE.write() {
+                *block_pipeline = Some(pipeline);
+            }
 
-            Ok(Arc::new(RwLock::new(node)))
+            Ok(node)
         })
         .await
         .map_err(|e| e.to_string())?
@@ -175,3 +209,102 @@ pub(crate) async fn stop_node(handle: Arc<RwLock<Node>>) -> Result<(), String> {
         }
     }
 }
+
+/// Connect to `peer`, refusing it if it is banned.
+pub(crate) async fn add_peer(node_handle: Arc<RwLock<Node>>, peer: SocketAddr) -> NodeMessage {
+    if let Some(ban_list) = BAN_LIST.read().await.as_ref() {
+        if ban_list.is_banned(peer.ip()) {
+            return NodeMessage::Error(BonsaiNodeError::generic(format!(
+                "Peer {} is banned",
+                peer
+            )));
+        }
+    }
+
+    let node = node_handle.read().await;
+    match node.connect_peer(&peer).await {
+        Ok(_) => {
+            info!("Connected to peer {}", peer);
+            NodeMessage::PeerConnected(peer.to_string())
+        }
+        Err(e) => NodeMessage::Error(BonsaiNodeError::from(e)),
+    }
+}
+
+pub(crate) async fn disconnect_peer(
+    node_handle: Arc<RwLock<Node>>,
+    peer: SocketAddr,
+) -> NodeMessage {
+    let node = node_handle.read().await;
+    match node.disconnect_peer(&peer).await {
+        Ok(_) => {
+            info!("Disconnected from peer {}", peer);
+            NodeMessage::PeerDisconnected(peer)
+        }
+        Err(e) => NodeMessage::Error(BonsaiNodeError::from(e)),
+    }
+}
+
+/// Disconnect every connected peer whose address is on the ban list.
+pub(crate) async fn enforce_bans(node_handle: Arc<RwLock<Node>>) -> Vec<SocketAddr> {
+    let mut ban_list = BAN_LIST.write().await;
+    let Some(ban_list) = ban_list.as_mut() else {
+        return Vec::new();
+    };
+    if ban_list.prune_expired() {
+        if let Err(e) = ban_list.save() {
+            error!("{}", e);
+        }
+    }
+
+    let node = node_handle.read().await;
+    let peers = node.get_peer_info().await.unwrap_or_default();
+
+    let mut disconnected = Vec::new();
+    for peer in peers {
+        if ban_list.is_banned(peer.address.ip()) {
+            match node.disconnect_peer(&peer.address).await {
+                Ok(_) => {
+                    info!("Disconnected banned peer {}", peer.address);
+                    disconnected.push(peer.address);
+                }
+                Err(e) => error!("Failed to disconnect banned peer {}: {}", peer.address, e),
+            }
+        }
+    }
+
+    disconnected
+}
+
+/// Check the outbound peers against the [`DIVERSITY_POLICY`], rotating out
+/// peers from over-represented groups if the policy asks for it.
+pub(crate) async fn check_peer_diversity(node_handle: Arc<RwLock<Node>>) -> DiversityReport {
+    let policy = DIVERSITY_POLICY.read().await.clone();
+    let geoip_reader = GEOIP_READER.read().await.clone();
+
+    let node = node_handle.read().await;
+    let peer_infos = node.get_peer_info().await.unwrap_or_default();
+
+    let protected = match FIXED_PEERS.read().await.as_ref() {
+        Some(fixed_peers) => match node.get_config().await {
+            Ok(config) => fixed_peers.resolve(default_p2p_port(config.network)).await,
+            Err(_) => Vec::new(),
+        },
+        None => Vec::new(),
+    };
+
+    let peers = process_peer_infos(peer_infos);
+    let report = evaluate(&policy, &peers, geoip_reader.as_ref(), &protected);
+
+    for warning in &report.warnings {
+        warn!("Peer diversity: {}", warning);
+    }
+    for peer in &report.to_rotate {
+        match node.disconnect_peer(peer).await {
+            Ok(_) => info!("Rotated out peer {} to improve peer diversity", peer),
+            Err(e) => error!("Failed to rotate out peer {}: {}", peer, e),
+        }
+    }
+
+    report
+}
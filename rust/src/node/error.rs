@@ -2,20 +2,56 @@ use bdk_floresta::error::BuilderError;
 use bdk_floresta::error::NodeError;
 use thiserror::Error;
 
+/// What went wrong, for the app to tell errors apart without parsing the message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BonsaiNodeErrorKind {
+    Generic,
+    NodeBuildError,
+    NodeExecError,
+    NotRunning,
+}
+
+// A struct rather than an enum with payloads: the bridge generates freezed unions for those,
+// which would need build_runner output committed next to the bindings.
+/// Errors from the embedded node.
 #[derive(Clone, Debug, Error)]
-pub(crate) enum BonsaiNodeError {
-    #[error("Generic Error: {0}")]
-    Generic(String),
+#[error("{message}")]
+pub struct BonsaiNodeError {
+    pub kind: BonsaiNodeErrorKind,
+    pub message: String,
+}
 
-    #[error(transparent)]
-    NodeBuildError(#[from] BuilderError),
+impl BonsaiNodeError {
+    pub fn new(kind: BonsaiNodeErrorKind, message: impl Into<String>) -> Self {
+        BonsaiNodeError {
+            kind,
+            message: message.into(),
+        }
+    }
 
-    #[error(transparent)]
-    NodeExecError(#[from] NodeError),
+    pub fn generic(message: impl Into<String>) -> Self {
+        Self::new(BonsaiNodeErrorKind::Generic, message)
+    }
+
+    pub fn not_running() -> Self {
+        Self::new(BonsaiNodeErrorKind::NotRunning, "Node is not running")
+    }
 }
 
 impl From<String> for BonsaiNodeError {
     fn from(s: String) -> Self {
-        BonsaiNodeError::Generic(s)
+        BonsaiNodeError::generic(s)
+    }
+}
+
+impl From<BuilderError> for BonsaiNodeError {
+    fn from(e: BuilderError) -> Self {
+        BonsaiNodeError::new(BonsaiNodeErrorKind::NodeBuildError, e.to_string())
+    }
+}
+
+impl From<NodeError> for BonsaiNodeError {
+    fn from(e: NodeError) -> Self {
+        BonsaiNodeError::new(BonsaiNodeErrorKind::NodeExecError, e.to_string())
     }
 }
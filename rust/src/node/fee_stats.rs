@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use bdk_floresta::UtxoData;
+use bitcoin::Amount;
+use bitcoin::Block;
+use bitcoin::BlockHash;
+use bitcoin::OutPoint;
+use once_cell::sync::Lazy;
+use tracing::warn;
+
+use crate::node::block_history::BlockEntry;
+use crate::node::block_history::push_block;
+use crate::node::block_pipeline::BlockDelivery;
+use crate::node::block_pipeline::BlockHandler;
+
+/// How many blocks of fee statistics are kept, about a day's worth.
+pub const FEE_STATS_BLOCKS: usize = 144;
+
+/// Upper bounds, in sat/vB, of the fee-rate histogram buckets. The last bucket is open-ended.
+pub const FEE_RATE_BUCKETS: &[f64] = &[
+    1.0, 2.0, 3.0, 5.0, 8.0, 10.0, 15.0, 20.0, 30.0, 50.0, 75.0, 100.0, 150.0, 200.0, 300.0, 500.0,
+    1000.0,
+];
+
+/// Fee statistics of the most recent blocks, oldest first.
+pub(crate) static BLOCK_FEE_STATS: Lazy<Mutex<VecDeque<BlockFeeStats>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(FEE_STATS_BLOCKS)));
+
+#[derive(Clone, Debug)]
+pub struct BlockFeeStats {
+    pub height: u32,
+    pub hash: BlockHash,
+    pub prev_hash: BlockHash,
+    /// Transactions other than the coinbase.
+    pub tx_count: usize,
+    pub total_fees: Amount,
+    /// Fee rates in sat/vB.
+    pub min_fee_rate: f64,
+    pub median_fee_rate: f64,
+    pub max_fee_rate: f64,
+    /// Transaction count per [`FEE_RATE_BUCKETS`] bucket, plus one for everything above.
+    pub fee_rate_histogram: Vec<u32>,
+    /// Share (0.0 - 1.0) of transactions spending at least one SegWit input.
+    pub segwit_share: f64,
+    /// Share (0.0 - 1.0) of transactions spending at least one Taproot output.
+    pub taproot_share: f64,
+    /// Transactions whose fee could not be computed because a spent output was missing.
+    pub missing_prevouts: usize,
+}
+
+fn bucket_index(fee_rate: f64) -> usize {
+    FEE_RATE_BUCKETS
+        .iter()
+        .position(|bound| fee_rate <= *bound)
+        .unwrap_or(FEE_RATE_BUCKETS.len())
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        len if len % 2 == 0 => (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0,
+        len => sorted[len / 2],
+    }
+}
+
+/// Compute the fee statistics of `block`, given the outputs it spends.
+pub fn compute_fee_stats(
+    block: &Block,
+    height: u32,
+    spent_utxos: &HashMap<OutPoint, UtxoData>,
+) -> BlockFeeStats {
+    let mut total_fees = Amount::ZERO;
+    let mut fee_rates = Vec::new();
+    let mut fee_rate_histogram = vec![0; FEE_RATE_BUCKETS.len() + 1];
+    let mut segwit_txs = 0;
+    let mut taproot_txs = 0;
+    let mut missing_prevouts = 0;
+
+    let transactions: Vec<_> = block.txdata.iter().filter(|tx| !tx.is_coinbase()).collect();
+    for tx in &transactions {
+        if tx.input.iter().any(|input| !input.witness.is_empty()) {
+            segwit_txs += 1;
+        }
+
+        let prevouts: Option<Vec<&UtxoData>> = tx
+            .input
+            .iter()
+            .map(|input| spent_utxos.get(&input.previous_output))
+            .collect();
+        let Some(prevouts) = prevouts else {
+            missing_prevouts += 1;
+            continue;
+        };
+
+        if prevouts
+            .iter()
+            .any(|utxo| utxo.txout.script_pubkey.is_p2tr())
+        {
+            taproot_txs += 1;
+        }
+
+        let input_value: Amount = prevouts.iter().map(|utxo| utxo.txout.value).sum();
+        let output_value: Amount = tx.output.iter().map(|output| output.value).sum();
+        let fee = input_value
+            .checked_sub(output_value)
+            .unwrap_or(Amount::ZERO);
+        let fee_rate = fee.to_sat() as f64 / tx.vsize() as f64;
+
+        total_fees += fee;
+        fee_rate_histogram[bucket_index(fee_rate)] += 1;
+        fee_rates.push(fee_rate);
+    }
+
+    fee_rates.sort_by(|a, b| a.total_cmp(b));
+    let share = |count: usize| {
+        if transactions.is_empty() {
+            0.0
+        } else {
+            count as f64 / transactions.len() as f64
+        }
+    };
+
+    BlockFeeStats {
+        height,
+        hash: block.block_hash(),
+        prev_hash: block.header.prev_blockhash,
+        tx_count: transactions.len(),
+        total_fees,
+        min_fee_rate: fee_rates.first().copied().unwrap_or(0.0),
+        median_fee_rate: median(&fee_rates),
+        max_fee_rate: fee_rates.last().copied().unwrap_or(0.0),
+        fee_rate_histogram,
+        segwit_share: share(segwit_txs),
+        taproot_share: share(taproot_txs),
+        missing_prevouts,
+    }
+}
+
+impl BlockEntry for BlockFeeStats {
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn hash(&self) -> BlockHash {
+        self.hash
+    }
+
+    fn prev_hash(&self) -> BlockHash {
+        self.prev_hash
+    }
+}
+
+/// Keep `stats`, see [`push_block`] for how reorgs are handled.
+fn record(stats: BlockFeeStats) {
+    if let Ok(mut recent) = BLOCK_FEE_STATS.lock() {
+        push_block(&mut recent, stats, FEE_STATS_BLOCKS);
+    }
+}
+
+/// The fee statistics of the most recent blocks, newest first.
+pub fn recent_fee_stats(limit: usize) -> Vec<BlockFeeStats> {
+    BLOCK_FEE_STATS
+        .lock()
+        .map(|recent| recent.iter().rev().take(limit).cloned().collect())
+        .unwrap_or_default()
+}
+
+pub fn fee_stats_at(height: u32) -> Option<BlockFeeStats> {
+    BLOCK_FEE_STATS
+        .lock()
+        .ok()?
+        .iter()
+        .find(|stats| stats.height == height)
+        .cloned()
+}
+
+pub fn clear_fee_stats() {
+    if let Ok(mut recent) = BLOCK_FEE_STATS.lock() {
+        recent.clear();
+    }
+}
+
+/// Computes [`BlockFeeStats`] for every new block.
+pub(crate) struct FeeStatsHandler;
+
+impl BlockHandler for FeeStatsHandler {
+    fn name(&self) -> &'static str {
+        "fee_stats"
+    }
+
+    fn wants_spent_utxos(&self) -> bool {
+        true
+    }
+
+    fn handle(&mut self, delivery: &BlockDelivery) {
+        match &delivery.spent_utxos {
+            Some(spent_utxos) => record(compute_fee_stats(
+                &delivery.block,
+                delivery.height,
+                spent_utxos,
+            )),
+            None => warn!(
+                "Block {} arrived without its spent outputs",
+                delivery.height
+            ),
+        }
+    }
+}
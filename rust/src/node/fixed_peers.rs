@@ -0,0 +1,275 @@
+use std::fs;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use bdk_floresta::Node;
+use bdk_floresta::PeerInfo;
+use bdk_floresta::UtreexoNodeConfig;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::error;
+use tracing::info;
+use tracing::warn;
+
+use crate::common::util::default_p2p_port;
+use crate::common::util::resolve_peer;
+
+pub(crate) const FIXED_PEERS_FILE: &str = "fixed_peers.toml";
+
+/// How often the [`FixedPeerKeeper`] checks on the trusted peers.
+const KEEPER_INTERVAL_SECS: u64 = 30;
+
+/// The trusted peers the running node was started with.
+pub(crate) static FIXED_PEERS: Lazy<Arc<RwLock<Option<FixedPeers>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(None)));
+
+/// The keeper of the running node's trusted peers, if it has any.
+pub(crate) static FIXED_PEER_KEEPER: Lazy<Arc<RwLock<Option<FixedPeerKeeper>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(None)));
+
+/// How the node treats its trusted peers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FixedPeerMode {
+    /// Connect to the trusted peers first, then fill the remaining slots as usual.
+    #[default]
+    Prefer,
+    /// Connect to the trusted peers and nothing else.
+    Only,
+}
+
+/// A trusted peer the node connects to on start.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixedPeer {
+    /// `host:port`, or a bare `host` on the network's default port.
+    pub address: String,
+    /// Fallback order, lowest first.
+    #[serde(default)]
+    pub priority: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixedPeers {
+    #[serde(default)]
+    pub mode: FixedPeerMode,
+    #[serde(default)]
+    pub peers: Vec<FixedPeer>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FixedPeerState {
+    Connected(SocketAddr),
+    Disconnected(SocketAddr),
+    Unresolved(String),
+}
+
+impl FixedPeers {
+    /// Load the trusted peers from `data_dir`, or an empty list if there are none.
+    pub fn load(data_dir: &Path) -> Self {
+        let path = data_dir.join(FIXED_PEERS_FILE);
+        match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).unwrap_or_else(|e| {
+                error!("Failed to parse {}: {}", path.to_string_lossy(), e);
+                FixedPeers::default()
+            }),
+            Err(_) => FixedPeers::default(),
+        }
+    }
+
+    pub fn save(&self, data_dir: &Path) -> Result<(), String> {
+        let path = data_dir.join(FIXED_PEERS_FILE);
+        fs::create_dir_all(data_dir).map_err(|e| e.to_string())?;
+        let contents = toml::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(&path, contents).map_err(|e| {
+            format!(
+                "Failed to write fixed peers to {}: {}",
+                path.to_string_lossy(),
+                e
+            )
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// The trusted peers in fallback order.
+    pub fn ordered(&self) -> Vec<&FixedPeer> {
+        let mut peers: Vec<&FixedPeer> = self.peers.iter().collect();
+        peers.sort_by_key(|peer| peer.priority);
+        peers
+    }
+
+    /// Check that every peer looks like `host` or `host:port`, without resolving it.
+    pub fn validate(&self) -> Result<(), String> {
+        for peer in &self.peers {
+            let address = peer.address.trim();
+            let host = match address.rsplit_once(':') {
+                // A bare IPv6 address has colons but no port.
+                Some((host, port)) if !host.contains(':') || host.starts_with('[') => {
+                    port.parse::<u16>()
+                        .map_err(|_| format!("Invalid port in fixed peer '{}'", address))?;
+                    host
+                }
+                _ => address,
+            };
+            if host.is_empty() {
+                return Err(format!("Invalid fixed peer '{}'", address));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolve the trusted peers in fallback order, skipping the ones that fail.
+    pub async fn resolve(&self, default_port: u16) -> Vec<SocketAddr> {
+        let mut resolved = Vec::new();
+        for peer in self.ordered() {
+            match resolve_peer(&peer.address, default_port).await {
+                Ok(addr) => resolved.push(addr),
+                Err(e) => warn!("Skipping fixed peer '{}': {}", peer.address, e),
+            }
+        }
+        resolved
+    }
+
+    /// Pin the first trusted peer in [`FixedPeerMode::Only`], which stops the node from making
+    /// any other outbound connection, and turn off DNS seeding.
+    pub fn pin(&self, config: &mut UtreexoNodeConfig) {
+        if self.mode == FixedPeerMode::Only {
+            if let Some(first) = self.ordered().first() {
+                config.disable_dns_seeds = true;
+                config.fixed_peer = Some(first.address.clone());
+            }
+        }
+    }
+
+    /// Apply the trusted peers to `config`, returning the peers to connect to once the node is up.
+    ///
+    /// Like [`FixedPeers::pin`], but pins the first peer that resolves. If none of them does, the
+    /// first one is still pinned so the node never falls back to strangers. Falling back down the
+    /// list once the node runs is left to the [`FixedPeerKeeper`].
+    pub async fn apply(&self, config: &mut UtreexoNodeConfig) -> Vec<SocketAddr> {
+        self.pin(config);
+        let mut peers = self.resolve(default_p2p_port(config.network)).await;
+        if self.mode == FixedPeerMode::Only && !peers.is_empty() {
+            config.fixed_peer = Some(peers.remove(0).to_string());
+        }
+
+        peers
+    }
+
+    /// The connection state of each trusted peer, in fallback order.
+    pub async fn status(
+        &self,
+        default_port: u16,
+        connected: &[PeerInfo],
+    ) -> Vec<(FixedPeer, FixedPeerState)> {
+        let mut status = Vec::new();
+        for peer in self.ordered() {
+            let state = match resolve_peer(&peer.address, default_port).await {
+                Ok(addr) if connected.iter().any(|info| info.address == addr) => {
+                    FixedPeerState::Connected(addr)
+                }
+                Ok(addr) => FixedPeerState::Disconnected(addr),
+                Err(e) => FixedPeerState::Unresolved(e),
+            };
+            status.push((peer.clone(), state));
+        }
+        status
+    }
+}
+
+/// Keeps the node connected to its trusted peers for as long as it runs.
+///
+/// In [`FixedPeerMode::Prefer`] every trusted peer that dropped is reconnected. In
+/// [`FixedPeerMode::Only`] one trusted peer is enough: when none is connected, the next one in
+/// fallback order is tried, wrapping around at the end of the list.
+///
+/// Like the [`StatsSampler`](crate::node::stats_sampler::StatsSampler), it only holds a
+/// [`Weak`] reference to the node.
+pub struct FixedPeerKeeper {
+    task: JoinHandle<()>,
+}
+
+impl FixedPeerKeeper {
+    pub fn spawn(node: &Arc<RwLock<Node>>, fixed_peers: FixedPeers, default_port: u16) -> Self {
+        let task = tokio::spawn(keep_loop(Arc::downgrade(node), fixed_peers, default_port));
+        FixedPeerKeeper { task }
+    }
+
+    /// Stop, and wait until the task has let go of the node.
+    pub async fn stop(self) {
+        self.task.abort();
+        let _ = self.task.await;
+    }
+}
+
+async fn keep_loop(node: Weak<RwLock<Node>>, fixed_peers: FixedPeers, default_port: u16) {
+    let mut interval = tokio::time::interval(Duration::from_secs(KEEPER_INTERVAL_SECS));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let peers: Vec<FixedPeer> = fixed_peers.ordered().into_iter().cloned().collect();
+    // The next peer to try in `Only` mode.
+    let mut next = 0;
+
+    loop {
+        interval.tick().await;
+
+        // Resolve without holding on to the node, lookups can take a while.
+        let mut resolved = Vec::with_capacity(peers.len());
+        for peer in &peers {
+            match resolve_peer(&peer.address, default_port).await {
+                Ok(addr) => resolved.push(Some(addr)),
+                Err(e) => {
+                    warn!("Failed to resolve fixed peer '{}': {}", peer.address, e);
+                    resolved.push(None);
+                }
+            }
+        }
+
+        let Some(node) = node.upgrade() else {
+            info!("Node is gone, stopping the fixed peer keeper");
+            return;
+        };
+        let node = node.read().await;
+        let connected = node.get_peer_info().await.unwrap_or_default();
+        let is_connected = |addr: &SocketAddr| connected.iter().any(|info| info.address == *addr);
+
+        let to_connect: Vec<SocketAddr> = match fixed_peers.mode {
+            FixedPeerMode::Prefer => resolved
+                .iter()
+                .flatten()
+                .filter(|addr| !is_connected(*addr))
+                .copied()
+                .collect(),
+            FixedPeerMode::Only if resolved.iter().flatten().any(is_connected) => Vec::new(),
+            FixedPeerMode::Only => {
+                let len = resolved.len();
+                let found = (0..len)
+                    .map(|offset| (next + offset) % len)
+                    .find_map(|index| resolved[index].map(|addr| (index, addr)));
+                match found {
+                    Some((index, addr)) => {
+                        next = (index + 1) % len;
+                        vec![addr]
+                    }
+                    None => Vec::new(),
+                }
+            }
+        };
+
+        for addr in to_connect {
+            match node.connect_peer(&addr).await {
+                Ok(_) => info!("Connecting to trusted peer {}", addr),
+                Err(e) => error!("Failed to connect to trusted peer {}: {}", addr, e),
+            }
+        }
+    }
+}
@@ -1,25 +1,251 @@
 use core::fmt::Display;
+use std::fs;
 use std::net::IpAddr;
+use std::net::Ipv6Addr;
+use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use maxminddb::Reader;
 use maxminddb::geoip2;
 use memmap2::Mmap;
+use once_cell::sync::Lazy;
+use tokio::sync::RwLock;
 use tracing::error;
 use tracing::info;
 
+pub(crate) const ASN_DB_FILE: &str = "GeoLite2-ASN.mmdb";
+pub(crate) const CITY_DB_FILE: &str = "GeoLite2-City.mmdb";
+
+/// The GeoIP databases loaded by the app, if any.
+pub(crate) static GEOIP_READER: Lazy<Arc<RwLock<Option<GeoIpReader>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(None)));
+
+/// What kind of network an address belongs to.
+///
+/// Tor and I2P peers only have an IP address here when they are mapped into IPv6 using
+/// OnionCat (`fd87:d87e:eb43::/48`) or GarliCat (`fd60:db4d:ddb5::/48`), as in Bitcoin Core.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressKind {
+    Public,
+    Private,
+    Loopback,
+    Tor,
+    I2p,
+    Cjdns,
+}
+
+impl Display for AddressKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Public => write!(f, "public"),
+            Self::Private => write!(f, "private"),
+            Self::Loopback => write!(f, "loopback"),
+            Self::Tor => write!(f, "tor"),
+            Self::I2p => write!(f, "i2p"),
+            Self::Cjdns => write!(f, "cjdns"),
+        }
+    }
+}
+
+const ONIONCAT_PREFIX: [u16; 3] = [0xfd87, 0xd87e, 0xeb43];
+const GARLICAT_PREFIX: [u16; 3] = [0xfd60, 0xdb4d, 0xddb5];
+
+fn has_prefix(ip: &Ipv6Addr, prefix: &[u16; 3]) -> bool {
+    ip.segments()[..3] == prefix[..]
+}
+
+/// Classify `ip`, so non-public peers can be labeled instead of looked up.
+pub fn classify_address(ip: IpAddr) -> AddressKind {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            if ip.is_loopback() {
+                AddressKind::Loopback
+            } else if ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                // 100.64.0.0/10, carrier-grade NAT.
+                || (a == 100 && (b & 0xc0) == 64)
+            {
+                AddressKind::Private
+            } else {
+                AddressKind::Public
+            }
+        }
+        IpAddr::V6(ip) => {
+            if let Some(ipv4) = ip.to_ipv4_mapped() {
+                return classify_address(IpAddr::V4(ipv4));
+            }
+
+            let first = ip.segments()[0];
+            if ip.is_loopback() {
+                AddressKind::Loopback
+            } else if has_prefix(&ip, &ONIONCAT_PREFIX) {
+                AddressKind::Tor
+            } else if has_prefix(&ip, &GARLICAT_PREFIX) {
+                AddressKind::I2p
+            } else if (first & 0xff00) == 0xfc00 {
+                AddressKind::Cjdns
+            } else if ip.is_unspecified()
+                // fd00::/8, unique local.
+                || (first & 0xff00) == 0xfd00
+                // fe80::/10, link-local.
+                || (first & 0xffc0) == 0xfe80
+            {
+                AddressKind::Private
+            } else {
+                AddressKind::Public
+            }
+        }
+    }
+}
+
+/// Which lookup a GeoIP database serves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeoIpDatabaseKind {
+    Asn,
+    City,
+}
+
+impl GeoIpDatabaseKind {
+    /// Tell the kind from the database's metadata, e.g. `GeoLite2-ASN` or `GeoIP2-City`.
+    /// ISP databases carry the ASN fields too, so they can serve ASN lookups.
+    fn from_database_type(database_type: &str) -> Option<Self> {
+        if database_type.contains("City") {
+            Some(Self::City)
+        } else if database_type.contains("ASN") || database_type.contains("ISP") {
+            Some(Self::Asn)
+        } else {
+            None
+        }
+    }
+
+    /// The file name a database of this kind is stored under.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Asn => ASN_DB_FILE,
+            Self::City => CITY_DB_FILE,
+        }
+    }
+}
+
+impl Display for GeoIpDatabaseKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Asn => write!(f, "ASN"),
+            Self::City => write!(f, "City"),
+        }
+    }
+}
+
+/// Metadata of a loaded GeoIP database.
+#[derive(Clone, Debug)]
+pub struct GeoIpDatabaseInfo {
+    pub kind: GeoIpDatabaseKind,
+    pub path: PathBuf,
+    pub database_type: String,
+    /// UNIX timestamp of when the database was built.
+    pub build_epoch: u64,
+    /// Nodes in the database's search tree. The format doesn't record how many networks it
+    /// holds, this is the closest measure of its size.
+    pub search_tree_nodes: u32,
+    pub ip_version: u16,
+    pub description: Option<String>,
+}
+
+/// A memory-mapped GeoIP database and where it was loaded from.
+#[derive(Clone)]
+pub struct GeoIpDatabase {
+    reader: Arc<Reader<Mmap>>,
+    path: PathBuf,
+    kind: GeoIpDatabaseKind,
+}
+
+impl GeoIpDatabase {
+    /// Map the database at `path` and check that it is an ASN or City database.
+    ///
+    /// The file must not be modified while it is mapped: replace it with
+    /// [`import_database`], which swaps in a new file instead of writing over it.
+    pub fn open(path: &Path) -> Result<Self, maxminddb::MaxMindDbError> {
+        let reader = unsafe { Reader::open_mmap(path) }?;
+        let kind = GeoIpDatabaseKind::from_database_type(&reader.metadata.database_type)
+            .ok_or_else(|| {
+                maxminddb::MaxMindDbError::invalid_database(format!(
+                    "Unsupported GeoIP database type: {}",
+                    reader.metadata.database_type
+                ))
+            })?;
+
+        Ok(Self {
+            reader: Arc::new(reader),
+            path: path.to_path_buf(),
+            kind,
+        })
+    }
+
+    pub fn kind(&self) -> GeoIpDatabaseKind {
+        self.kind
+    }
+
+    pub fn info(&self) -> GeoIpDatabaseInfo {
+        let metadata = &self.reader.metadata;
+        GeoIpDatabaseInfo {
+            kind: self.kind,
+            path: self.path.clone(),
+            database_type: metadata.database_type.clone(),
+            build_epoch: metadata.build_epoch,
+            search_tree_nodes: metadata.node_count,
+            ip_version: metadata.ip_version,
+            description: metadata.description.get("en").cloned(),
+        }
+    }
+}
+
+/// Validate the `.mmdb` file at `source` and install it into `dir`, under the file name of its
+/// kind. The file is copied next to its destination and renamed over it, so a database that is
+/// currently mapped keeps its old contents until it is dropped.
+pub fn import_database(source: &Path, dir: &Path) -> Result<GeoIpDatabase, String> {
+    let kind = GeoIpDatabase::open(source)
+        .map_err(|e| format!("Invalid GeoIP database: {}", e))?
+        .kind();
+
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create GeoIP dir: {}", e))?;
+    let destination = dir.join(kind.file_name());
+    let staging = dir.join(format!(".{}.tmp", kind.file_name()));
+
+    fs::copy(source, &staging).map_err(|e| format!("Failed to copy GeoIP database: {}", e))?;
+    if let Err(e) = GeoIpDatabase::open(&staging) {
+        let _ = fs::remove_file(&staging);
+        return Err(format!("Invalid GeoIP database: {}", e));
+    }
+    fs::rename(&staging, &destination)
+        .map_err(|e| format!("Failed to install GeoIP database: {}", e))?;
+
+    info!(
+        "Imported GeoIP {} database to {}",
+        kind,
+        destination.to_string_lossy()
+    );
+    GeoIpDatabase::open(&destination).map_err(|e| e.to_string())
+}
+
 /// A [`Reader`] to GeoIP ASN and City databases.
+///
+/// Either database may be missing, in which case its lookups return `None`.
+#[derive(Clone, Default)]
 pub struct GeoIpReader {
-    asn_reader: Option<Arc<Reader<Mmap>>>,
-    city_reader: Option<Arc<Reader<Mmap>>>,
+    asn_db: Option<GeoIpDatabase>,
+    city_db: Option<GeoIpDatabase>,
 }
 
 impl GeoIpReader {
     pub fn new(asn_db_path: &str, city_db_path: &str) -> Result<Self, maxminddb::MaxMindDbError> {
-        let asn_reader = match unsafe { Reader::open_mmap(asn_db_path) } {
-            Ok(reader) => {
+        let asn_db = match GeoIpDatabase::open(Path::new(asn_db_path)) {
+            Ok(db) => {
                 info!("Loaded GeoLite2 ASN DB");
-                Some(Arc::new(reader))
+                Some(db)
             }
             Err(e) => {
                 error!("Failed to load GeoLite2 ASN DB: {e}");
@@ -27,10 +253,10 @@ impl GeoIpReader {
             }
         };
 
-        let city_reader = match unsafe { Reader::open_mmap(city_db_path) } {
-            Ok(reader) => {
+        let city_db = match GeoIpDatabase::open(Path::new(city_db_path)) {
+            Ok(db) => {
                 info!("Loaded GeoLite2 City DB");
-                Some(Arc::new(reader))
+                Some(db)
             }
             Err(e) => {
                 error!("Failed to load GeoLite2 City DB: {e}");
@@ -38,20 +264,41 @@ impl GeoIpReader {
             }
         };
 
-        if asn_reader.is_none() && city_reader.is_none() {
+        if asn_db.is_none() && city_db.is_none() {
             return Err(maxminddb::MaxMindDbError::invalid_database(
                 "Failed to load any GeoIP databases",
             ));
         }
 
-        Ok(Self {
-            asn_reader,
-            city_reader,
-        })
+        Ok(Self { asn_db, city_db })
+    }
+
+    /// Load `GeoLite2-ASN.mmdb` and `GeoLite2-City.mmdb` from `dir`.
+    pub fn from_dir(dir: &Path) -> Result<Self, maxminddb::MaxMindDbError> {
+        Self::new(
+            &dir.join(ASN_DB_FILE).to_string_lossy(),
+            &dir.join(CITY_DB_FILE).to_string_lossy(),
+        )
+    }
+
+    /// Put `db` in the slot of its kind, replacing the database there.
+    pub fn set_database(&mut self, db: GeoIpDatabase) {
+        match db.kind() {
+            GeoIpDatabaseKind::Asn => self.asn_db = Some(db),
+            GeoIpDatabaseKind::City => self.city_db = Some(db),
+        }
+    }
+
+    pub fn databases(&self) -> Vec<GeoIpDatabaseInfo> {
+        [&self.asn_db, &self.city_db]
+            .into_iter()
+            .flatten()
+            .map(GeoIpDatabase::info)
+            .collect()
     }
 
     pub fn lookup_asn(&self, ip: IpAddr) -> Option<AsnInfo> {
-        let reader = self.asn_reader.as_ref()?;
+        let reader = &self.asn_db.as_ref()?.reader;
         let lookup_result = reader.lookup(ip).ok()?;
         let asn = lookup_result.decode::<geoip2::Asn>().ok()??;
 
@@ -62,7 +309,7 @@ impl GeoIpReader {
     }
 
     pub fn lookup_city(&self, ip: IpAddr) -> Option<CityInfo> {
-        let reader = self.city_reader.as_ref()?;
+        let reader = &self.city_db.as_ref()?.reader;
         let lookup_result = reader.lookup(ip).ok()?;
         let city = lookup_result.decode::<geoip2::City>().ok()??;
 
@@ -81,15 +328,6 @@ impl GeoIpReader {
     }
 }
 
-impl Clone for GeoIpReader {
-    fn clone(&self) -> Self {
-        Self {
-            asn_reader: self.asn_reader.clone(),
-            city_reader: self.city_reader.clone(),
-        }
-    }
-}
-
 #[derive(Clone, Debug)]
 pub struct AsnInfo {
     pub number: u32,
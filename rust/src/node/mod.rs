@@ -1,11 +1,27 @@
+pub mod accumulator_package;
+pub mod accumulator_stats;
+pub mod ban_list;
+pub mod block_explorer;
+pub(crate) mod block_history;
+pub mod block_pipeline;
 // pub mod blocks;
 pub mod control;
 pub mod error;
+pub mod fee_stats;
+pub mod fixed_peers;
 pub mod geoip;
 pub mod log_capture;
 pub mod message;
 // pub mod network;
+pub mod peer_census;
+pub mod peer_diversity;
+pub mod recent_blocks;
+pub mod reorg;
+pub mod script_analyzer;
 // pub mod statistics;
 pub mod stats_fetcher;
+pub mod stats_sampler;
 // pub mod style;
+pub mod sync_progress;
 // pub mod utreexo;
+pub mod watchlist;
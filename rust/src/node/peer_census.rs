@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use bitcoin::p2p::ServiceFlags;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::node::geoip::AddressKind;
+use crate::node::geoip::GeoIpReader;
+use crate::node::geoip::classify_address;
+use crate::node::stats_fetcher::NODE_UTREEXO;
+use crate::node::stats_fetcher::NodeImpl;
+use crate::node::stats_fetcher::PeerInformation;
+
+/// `/name:version/` segments of a BIP-0014 user agent.
+static USER_AGENT_SEGMENT: Lazy<Regex> = Lazy::new(|| Regex::new(r"/([^:/]+):([^/(]+)").unwrap());
+
+/// How many peers share a key.
+#[derive(Clone, Debug)]
+pub struct CensusBucket {
+    pub key: String,
+    pub label: String,
+    pub peers: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PeerCensus {
+    pub total_peers: usize,
+    pub by_country: Vec<CensusBucket>,
+    pub by_asn: Vec<CensusBucket>,
+    pub by_implementation: Vec<CensusBucket>,
+    /// Implementation and version, e.g. `Bitcoin Core 27.1`.
+    pub by_version: Vec<CensusBucket>,
+    pub utreexo_peers: usize,
+    pub compact_filter_peers: usize,
+}
+
+/// The user agent segment each implementation reports its version in.
+fn version_segment(node_impl: &NodeImpl) -> Option<&'static str> {
+    match node_impl {
+        NodeImpl::Core => Some("Satoshi"),
+        NodeImpl::Knots => Some("Knots"),
+        NodeImpl::Btcd => Some("btcd"),
+        NodeImpl::Utreexod => Some("utreexod"),
+        NodeImpl::Floresta => Some("Floresta"),
+        NodeImpl::Unknown => None,
+    }
+}
+
+/// Parse the version out of a user agent, trimmed to `major.minor`
+/// for Core (`/Satoshi:27.1.0/` is `27.1`) and kept as-is otherwise.
+pub fn parse_user_agent_version(node_impl: &NodeImpl, user_agent: &str) -> Option<String> {
+    let segment = version_segment(node_impl)?;
+    let version = USER_AGENT_SEGMENT
+        .captures_iter(user_agent)
+        .filter(|captures| captures[1].eq_ignore_ascii_case(segment))
+        .map(|captures| captures[2].trim().to_string())
+        .last()?;
+
+    match node_impl {
+        NodeImpl::Core => Some(version.splitn(3, '.').take(2).collect::<Vec<_>>().join(".")),
+        _ => Some(version),
+    }
+}
+
+fn add(buckets: &mut HashMap<String, CensusBucket>, key: String, label: String) {
+    buckets
+        .entry(key.clone())
+        .or_insert(CensusBucket {
+            key,
+            label,
+            peers: 0,
+        })
+        .peers += 1;
+}
+
+fn sorted(buckets: HashMap<String, CensusBucket>) -> Vec<CensusBucket> {
+    let mut buckets: Vec<CensusBucket> = buckets.into_values().collect();
+    buckets.sort_by(|a, b| b.peers.cmp(&a.peers).then_with(|| a.key.cmp(&b.key)));
+    buckets
+}
+
+/// Group `peers` by country, ASN and software.
+///
+/// Peers that can't be located (no database, or a private, Tor or I2P address)
+/// are grouped under their [`AddressKind`] instead.
+pub fn take_census(peers: &[PeerInformation], geoip_reader: Option<&GeoIpReader>) -> PeerCensus {
+    let mut by_country = HashMap::new();
+    let mut by_asn = HashMap::new();
+    let mut by_implementation = HashMap::new();
+    let mut by_version = HashMap::new();
+    let mut utreexo_peers = 0;
+    let mut compact_filter_peers = 0;
+
+    for peer in peers {
+        let ip = peer.socket.ip();
+        let address_kind = classify_address(ip);
+        let geo_info = match (address_kind, geoip_reader) {
+            (AddressKind::Public, Some(reader)) => Some(reader.lookup_all(ip)),
+            _ => None,
+        };
+        let fallback = if address_kind == AddressKind::Public {
+            "unknown".to_string()
+        } else {
+            address_kind.to_string()
+        };
+
+        match geo_info.as_ref().and_then(|g| g.city.as_ref()) {
+            Some(city) => {
+                let code = city
+                    .country_code
+                    .clone()
+                    .unwrap_or_else(|| fallback.clone());
+                let name = city.country.clone().unwrap_or_else(|| code.clone());
+                add(&mut by_country, code, name);
+            }
+            None => add(&mut by_country, fallback.clone(), fallback.clone()),
+        }
+
+        match geo_info.as_ref().and_then(|g| g.asn.as_ref()) {
+            Some(asn) => add(
+                &mut by_asn,
+                format!("AS{}", asn.number),
+                asn.organization.clone(),
+            ),
+            None => add(&mut by_asn, fallback.clone(), fallback),
+        }
+
+        let implementation = peer.node_impl.to_string();
+        add(
+            &mut by_implementation,
+            implementation.clone(),
+            implementation.clone(),
+        );
+
+        let version = match parse_user_agent_version(&peer.node_impl, &peer.user_agent) {
+            Some(version) => format!("{} {}", implementation, version),
+            None => implementation,
+        };
+        add(&mut by_version, version.clone(), version);
+
+        if peer.services.to_u64() & NODE_UTREEXO != 0 {
+            utreexo_peers += 1;
+        }
+        if peer.services.has(ServiceFlags::COMPACT_FILTERS) {
+            compact_filter_peers += 1;
+        }
+    }
+
+    PeerCensus {
+        total_peers: peers.len(),
+        by_country: sorted(by_country),
+        by_asn: sorted(by_asn),
+        by_implementation: sorted(by_implementation),
+        by_version: sorted(by_version),
+        utreexo_peers,
+        compact_filter_peers,
+    }
+}
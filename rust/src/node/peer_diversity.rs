@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use bdk_floresta::ConnectionKind;
+use once_cell::sync::Lazy;
+use tokio::sync::RwLock;
+
+use crate::node::geoip::AddressKind;
+use crate::node::geoip::GeoIpReader;
+use crate::node::geoip::classify_address;
+use crate::node::stats_fetcher::PeerInformation;
+
+/// The diversity policy applied to the running node's outbound peers.
+pub(crate) static DIVERSITY_POLICY: Lazy<Arc<RwLock<DiversityPolicy>>> =
+    Lazy::new(|| Arc::new(RwLock::new(DiversityPolicy::default())));
+
+/// What to do when too many outbound peers share an ASN or a country.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiversityAction {
+    /// Only report the concentration.
+    #[default]
+    Warn,
+    /// Disconnect the newest peers of an over-represented group, so the node replaces them.
+    Rotate,
+}
+
+#[derive(Clone, Debug)]
+pub struct DiversityPolicy {
+    /// Largest share (0.0 - 1.0) of outbound peers allowed in a single ASN.
+    pub max_asn_share: f64,
+    /// Largest share (0.0 - 1.0) of outbound peers allowed in a single country.
+    pub max_country_share: f64,
+    /// Below this many outbound peers, shares are too noisy to act on.
+    pub min_peers: usize,
+    pub action: DiversityAction,
+}
+
+impl Default for DiversityPolicy {
+    fn default() -> Self {
+        Self {
+            max_asn_share: 0.5,
+            max_country_share: 0.75,
+            min_peers: 4,
+            action: DiversityAction::Warn,
+        }
+    }
+}
+
+/// How many outbound peers fall into one ASN or country.
+#[derive(Clone, Debug)]
+pub struct GroupShare {
+    pub key: String,
+    pub label: String,
+    pub peers: usize,
+    pub share: f64,
+    pub over_limit: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DiversityReport {
+    /// `1 - HHI` over outbound peers' ASNs: `0.0` when they all share one ASN,
+    /// approaching `1.0` as they spread out. Peers without a known ASN count as their own group.
+    pub score: f64,
+    pub outbound_peers: usize,
+    pub asn_groups: Vec<GroupShare>,
+    pub country_groups: Vec<GroupShare>,
+    /// Peers in a group that is over its limit.
+    pub concentrated_peers: Vec<SocketAddr>,
+    /// Peers to disconnect under [`DiversityAction::Rotate`].
+    pub to_rotate: Vec<SocketAddr>,
+    pub warnings: Vec<String>,
+}
+
+struct Group {
+    label: String,
+    members: Vec<(SocketAddr, SystemTime)>,
+}
+
+fn group_shares(
+    groups: &HashMap<String, Group>,
+    total: usize,
+    max_share: f64,
+    enforce: bool,
+) -> Vec<GroupShare> {
+    let mut shares: Vec<GroupShare> = groups
+        .iter()
+        .map(|(key, group)| {
+            let share = group.members.len() as f64 / total as f64;
+            GroupShare {
+                key: key.clone(),
+                label: group.label.clone(),
+                peers: group.members.len(),
+                share,
+                over_limit: enforce && group.members.len() > 1 && share > max_share,
+            }
+        })
+        .collect();
+    shares.sort_by(|a, b| b.peers.cmp(&a.peers).then_with(|| a.key.cmp(&b.key)));
+
+    shares
+}
+
+/// The newest peers of each group beyond what `max_share` allows.
+fn excess_peers(groups: &HashMap<String, Group>, total: usize, max_share: f64) -> Vec<SocketAddr> {
+    let allowed = ((total as f64 * max_share).floor() as usize).max(1);
+
+    let mut excess = Vec::new();
+    for group in groups.values() {
+        if group.members.len() <= allowed {
+            continue;
+        }
+
+        let mut members = group.members.clone();
+        // Keep the peers we have seen the longest.
+        members.sort_by_key(|(_, first_seen)| *first_seen);
+        excess.extend(members.into_iter().skip(allowed).map(|(socket, _)| socket));
+    }
+
+    excess
+}
+
+/// Evaluate `peers` against `policy`. Peers in `protected` (e.g. trusted peers) are counted,
+/// but never picked for rotation.
+pub fn evaluate(
+    policy: &DiversityPolicy,
+    peers: &[PeerInformation],
+    geoip_reader: Option<&GeoIpReader>,
+    protected: &[SocketAddr],
+) -> DiversityReport {
+    // Feelers are short-lived probes, not real outbound slots.
+    let outbound: Vec<&PeerInformation> = peers
+        .iter()
+        .filter(|peer| !matches!(peer.connection_kind, ConnectionKind::Feeler))
+        .collect();
+    let total = outbound.len();
+    if total == 0 {
+        return DiversityReport::default();
+    }
+
+    let mut asn_groups: HashMap<String, Group> = HashMap::new();
+    let mut country_groups: HashMap<String, Group> = HashMap::new();
+    for peer in &outbound {
+        let member = (peer.socket, peer.first_seen);
+        let ip = peer.socket.ip();
+
+        let geo_info = match (classify_address(ip), geoip_reader) {
+            (AddressKind::Public, Some(reader)) => Some(reader.lookup_all(ip)),
+            _ => None,
+        };
+
+        let (asn_key, asn_label) = match geo_info.as_ref().and_then(|g| g.asn.as_ref()) {
+            Some(asn) => (format!("AS{}", asn.number), asn.organization.clone()),
+            // Unknown peers can't be grouped, so each one is its own group.
+            None => (format!("unknown:{}", peer.socket), "Unknown".to_string()),
+        };
+        asn_groups
+            .entry(asn_key)
+            .or_insert_with(|| Group {
+                label: asn_label,
+                members: Vec::new(),
+            })
+            .members
+            .push(member);
+
+        if let Some(city) = geo_info.as_ref().and_then(|g| g.city.as_ref()) {
+            if let Some(code) = &city.country_code {
+                country_groups
+                    .entry(code.clone())
+                    .or_insert_with(|| Group {
+                        label: city.country.clone().unwrap_or_else(|| code.clone()),
+                        members: Vec::new(),
+                    })
+                    .members
+                    .push(member);
+            }
+        }
+    }
+
+    let enforce = total >= policy.min_peers;
+    let hhi: f64 = asn_groups
+        .values()
+        .map(|group| (group.members.len() as f64 / total as f64).powi(2))
+        .sum();
+
+    let asn_shares = group_shares(&asn_groups, total, policy.max_asn_share, enforce);
+    let country_shares = group_shares(&country_groups, total, policy.max_country_share, enforce);
+
+    let mut warnings = Vec::new();
+    let mut concentrated_peers = Vec::new();
+    for (shares, groups) in [
+        (&asn_shares, &asn_groups),
+        (&country_shares, &country_groups),
+    ] {
+        for share in shares.iter().filter(|share| share.over_limit) {
+            warnings.push(format!(
+                "{} of {} outbound peers are in {} ({})",
+                share.peers, total, share.key, share.label
+            ));
+            if let Some(group) = groups.get(&share.key) {
+                concentrated_peers.extend(group.members.iter().map(|(socket, _)| *socket));
+            }
+        }
+    }
+    concentrated_peers.sort();
+    concentrated_peers.dedup();
+
+    let mut to_rotate = Vec::new();
+    if enforce && policy.action == DiversityAction::Rotate {
+        to_rotate.extend(excess_peers(&asn_groups, total, policy.max_asn_share));
+        to_rotate.extend(excess_peers(
+            &country_groups,
+            total,
+            policy.max_country_share,
+        ));
+        to_rotate.retain(|socket| !protected.contains(socket));
+        to_rotate.sort();
+        to_rotate.dedup();
+    }
+
+    DiversityReport {
+        score: 1.0 - hhi,
+        outbound_peers: total,
+        asn_groups: asn_shares,
+        country_groups: country_shares,
+        concentrated_peers,
+        to_rotate,
+        warnings,
+    }
+}
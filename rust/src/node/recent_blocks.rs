@@ -0,0 +1,144 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use bitcoin::Amount;
+use bitcoin::Block;
+use bitcoin::BlockHash;
+use bitcoin::Network;
+use bitcoin::Transaction;
+use once_cell::sync::Lazy;
+
+use crate::node::block_explorer::subsidy_split;
+use crate::node::block_history::BlockEntry;
+use crate::node::block_history::push_block;
+use crate::node::block_pipeline::BlockDelivery;
+use crate::node::block_pipeline::BlockHandler;
+
+/// How many block summaries are kept.
+pub const RECENT_BLOCKS_LEN: usize = 100;
+
+/// Shortest printable run of the coinbase script that counts as part of the miner tag.
+const MIN_TAG_RUN: usize = 4;
+
+/// Summaries of the most recent blocks on our best chain, oldest first.
+pub(crate) static RECENT_BLOCKS: Lazy<Mutex<VecDeque<BlockSummary>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(RECENT_BLOCKS_LEN)));
+
+#[derive(Clone, Debug)]
+pub struct BlockSummary {
+    pub height: u32,
+    pub hash: BlockHash,
+    pub prev_hash: BlockHash,
+    /// Header timestamp.
+    pub time: u32,
+    pub tx_count: usize,
+    pub size: usize,
+    pub weight: u64,
+    /// Coinbase value above the subsidy.
+    pub total_fees: Amount,
+    pub miner_tag: Option<String>,
+}
+
+impl BlockSummary {
+    pub fn new(block: &Block, height: u32, network: Network) -> Self {
+        BlockSummary {
+            height,
+            hash: block.block_hash(),
+            prev_hash: block.header.prev_blockhash,
+            time: block.header.time,
+            tx_count: block.txdata.len(),
+            size: block.total_size(),
+            weight: block.weight().to_wu(),
+            total_fees: subsidy_split(block, height, network).fees,
+            miner_tag: block.txdata.first().and_then(miner_tag),
+        }
+    }
+}
+
+/// The printable text miners leave in the coinbase script, e.g. `/ViaBTC/Mined by foo/`.
+pub fn miner_tag(coinbase: &Transaction) -> Option<String> {
+    let script = coinbase.input.first()?.script_sig.as_bytes();
+
+    // Skip the BIP-0034 height push.
+    let skip = match script.first() {
+        Some(len) if *len <= 8 => 1 + *len as usize,
+        _ => 0,
+    };
+
+    let tag = script
+        .get(skip..)?
+        .split(|byte| !(0x20..=0x7e).contains(byte))
+        .filter(|run| run.len() >= MIN_TAG_RUN)
+        .map(|run| String::from_utf8_lossy(run).trim().to_string())
+        .filter(|run| !run.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    (!tag.is_empty()).then_some(tag)
+}
+
+impl BlockEntry for BlockSummary {
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn hash(&self) -> BlockHash {
+        self.hash
+    }
+
+    fn prev_hash(&self) -> BlockHash {
+        self.prev_hash
+    }
+}
+
+/// Append `summary`, first dropping whatever it replaces.
+///
+/// See [`push_block`] for how reorgs are handled.
+fn record(summary: BlockSummary) {
+    if let Ok(mut recent) = RECENT_BLOCKS.lock() {
+        push_block(&mut recent, summary, RECENT_BLOCKS_LEN);
+    }
+}
+
+/// Up to `limit` block summaries, newest first, skipping the `offset` newest.
+pub fn recent_blocks(offset: usize, limit: usize) -> Vec<BlockSummary> {
+    RECENT_BLOCKS
+        .lock()
+        .map(|recent| {
+            recent
+                .iter()
+                .rev()
+                .skip(offset)
+                .take(limit)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn recent_blocks_len() -> usize {
+    RECENT_BLOCKS.lock().map(|recent| recent.len()).unwrap_or(0)
+}
+
+pub fn clear_recent_blocks() {
+    if let Ok(mut recent) = RECENT_BLOCKS.lock() {
+        recent.clear();
+    }
+}
+
+/// Keeps [`RECENT_BLOCKS`] up to date.
+pub(crate) struct RecentBlocksHandler;
+
+impl BlockHandler for RecentBlocksHandler {
+    fn name(&self) -> &'static str {
+        "recent_blocks"
+    }
+
+    fn handle(&mut self, delivery: &BlockDelivery) {
+        record(BlockSummary::new(
+            &delivery.block,
+            delivery.height,
+            delivery.network,
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use bitcoin::BlockHash;
+use once_cell::sync::Lazy;
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::node::block_pipeline::BlockDelivery;
+use crate::node::block_pipeline::BlockHandler;
+
+/// How many block hashes a [`ChainTracker`] remembers, which bounds the reorg depth it can see.
+pub const TRACKED_BLOCKS: usize = 1_000;
+
+/// How many reorgs are kept for the API.
+const REORG_HISTORY_LEN: usize = 20;
+
+/// How many reorgs a slow event subscriber may fall behind before it misses some.
+const REORG_EVENTS_LEN: usize = 16;
+
+/// Reorgs seen by the [`ReorgDetector`], oldest first.
+static REORG_HISTORY: Lazy<Mutex<VecDeque<ReorgEvent>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(REORG_HISTORY_LEN)));
+
+/// Every new reorg, as it is detected.
+static REORG_EVENTS: Lazy<broadcast::Sender<ReorgEvent>> =
+    Lazy::new(|| broadcast::channel(REORG_EVENTS_LEN).0);
+
+#[derive(Clone, Debug)]
+pub struct ReorgEvent {
+    /// The last block both branches share.
+    pub fork_height: u32,
+    pub fork_hash: BlockHash,
+    /// How many blocks were disconnected.
+    pub depth: usize,
+    /// The disconnected blocks, lowest first.
+    pub disconnected: Vec<(u32, BlockHash)>,
+    /// The block that triggered the reorg, the first of the new branch.
+    pub new_block: (u32, BlockHash),
+    pub detected_at: SystemTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum ChainEvent {
+    /// The block extends the tip, or we have no parent to check it against.
+    Connected,
+    /// The block replaces blocks we had already connected.
+    Reorg(ReorgEvent),
+    /// The block doesn't build on the block we have at its parent's height.
+    /// Everything we knew is dropped, since we can't tell how deep the fork goes.
+    Unlinked,
+}
+
+/// Follows the best chain by the `prev_blockhash` of each new block.
+#[derive(Clone, Debug, Default)]
+pub struct ChainTracker {
+    blocks: BTreeMap<u32, BlockHash>,
+}
+
+impl ChainTracker {
+    pub fn tip(&self) -> Option<(u32, BlockHash)> {
+        self.blocks
+            .last_key_value()
+            .map(|(height, hash)| (*height, *hash))
+    }
+
+    /// Connect the block `hash` at `height`, reporting whether it reorganized the chain.
+    pub fn connect(&mut self, height: u32, hash: BlockHash, prev_hash: BlockHash) -> ChainEvent {
+        if self.blocks.get(&height) == Some(&hash) {
+            return ChainEvent::Connected;
+        }
+
+        let parent = height
+            .checked_sub(1)
+            .and_then(|parent_height| self.blocks.get(&parent_height));
+        if parent.is_some_and(|parent| *parent != prev_hash) {
+            self.blocks.clear();
+            self.blocks.insert(height, hash);
+            return ChainEvent::Unlinked;
+        }
+
+        let disconnected: Vec<(u32, BlockHash)> =
+            self.blocks.split_off(&height).into_iter().collect();
+        self.blocks.insert(height, hash);
+        while self.blocks.len() > TRACKED_BLOCKS {
+            self.blocks.pop_first();
+        }
+
+        if disconnected.is_empty() {
+            return ChainEvent::Connected;
+        }
+
+        ChainEvent::Reorg(ReorgEvent {
+            fork_height: height.saturating_sub(1),
+            fork_hash: prev_hash,
+            depth: disconnected.len(),
+            disconnected,
+            new_block: (height, hash),
+            detected_at: SystemTime::now(),
+        })
+    }
+}
+
+/// Reorgs seen since the node started, newest first.
+pub fn recent_reorgs() -> Vec<ReorgEvent> {
+    REORG_HISTORY
+        .lock()
+        .map(|history| history.iter().rev().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// A receiver of every new reorg.
+pub fn subscribe_reorgs() -> broadcast::Receiver<ReorgEvent> {
+    REORG_EVENTS.subscribe()
+}
+
+pub fn clear_reorg_history() {
+    if let Ok(mut history) = REORG_HISTORY.lock() {
+        history.clear();
+    }
+}
+
+/// Watches every new block for reorgs, recording each one.
+#[derive(Default)]
+pub(crate) struct ReorgDetector {
+    chain: ChainTracker,
+}
+
+impl BlockHandler for ReorgDetector {
+    fn name(&self) -> &'static str {
+        "reorg_detector"
+    }
+
+    fn handle(&mut self, delivery: &BlockDelivery) {
+        let block = &delivery.block;
+        let height = delivery.height;
+
+        match self
+            .chain
+            .connect(height, block.block_hash(), block.header.prev_blockhash)
+        {
+            ChainEvent::Connected => {}
+            ChainEvent::Reorg(event) => {
+                warn!(
+                    "Reorg of depth {} at height {} ({})",
+                    event.depth, event.fork_height, event.fork_hash
+                );
+                if let Ok(mut history) = REORG_HISTORY.lock() {
+                    if history.len() == REORG_HISTORY_LEN {
+                        history.pop_front();
+                    }
+                    history.push_back(event.clone());
+                }
+                // Nobody listening is fine.
+                let _ = REORG_EVENTS.send(event);
+            }
+            ChainEvent::Unlinked => {
+                warn!(
+                    "Block {} at height {} doesn't link to our chain, starting over",
+                    block.block_hash(),
+                    height
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use bitcoin::Script;
+use bitcoin::TxIn;
+use bitcoin::opcodes::all::OP_ENDIF;
+use bitcoin::opcodes::all::OP_IF;
+use bitcoin::opcodes::all::OP_PUSHNUM_1;
+use bitcoin::opcodes::all::OP_PUSHNUM_13;
+use bitcoin::opcodes::all::OP_PUSHNUM_16;
+use bitcoin::opcodes::all::OP_PUSHNUM_NEG1;
+use bitcoin::script::Instruction;
+
+/// Payload prefixes of protocols known to use OP_RETURN outputs.
+const PROTOCOL_TAGS: &[(&[u8], &str)] = &[
+    (&[0xaa, 0x21, 0xa9, 0xed], "witness_commitment"),
+    (b"omni", "omni"),
+    (b"CNTRPRTY", "counterparty"),
+    (b"RSKBLOCK:", "rsk_merged_mining"),
+    (b"DOCPROOF", "docproof"),
+    (b"EW ", "eternity_wall"),
+    (b"CORE", "core_dao"),
+];
+
+/// The tag of an inscription envelope, right after `OP_FALSE OP_IF`.
+const INSCRIPTION_TAG: &[u8] = b"ord";
+
+/// Envelope field holding the content type.
+const CONTENT_TYPE_FIELD: &[u8] = &[1];
+
+/// How much of a payload is kept as text.
+const MAX_TEXT_LEN: usize = 256;
+
+/// What an output script pays to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptKind {
+    P2pkh,
+    P2sh,
+    P2wpkh,
+    P2wsh,
+    P2tr,
+    P2pk,
+    /// Bare `m`-of-`n` multisig.
+    Multisig {
+        required: u8,
+        total: u8,
+    },
+    OpReturn,
+    /// A witness program of a version or length with no meaning yet.
+    WitnessUnknown {
+        version: u8,
+    },
+    NonStandard,
+}
+
+impl ScriptKind {
+    pub fn classify(script: &Script) -> Self {
+        if script.is_p2pkh() {
+            ScriptKind::P2pkh
+        } else if script.is_p2sh() {
+            ScriptKind::P2sh
+        } else if script.is_p2wpkh() {
+            ScriptKind::P2wpkh
+        } else if script.is_p2wsh() {
+            ScriptKind::P2wsh
+        } else if script.is_p2tr() {
+            ScriptKind::P2tr
+        } else if script.is_p2pk() {
+            ScriptKind::P2pk
+        } else if script.is_multisig() {
+            multisig_counts(script)
+                .map(|(required, total)| ScriptKind::Multisig { required, total })
+                .unwrap_or(ScriptKind::NonStandard)
+        } else if script.is_op_return() {
+            ScriptKind::OpReturn
+        } else if let Some(version) = script.witness_version() {
+            ScriptKind::WitnessUnknown {
+                version: version.to_num(),
+            }
+        } else {
+            ScriptKind::NonStandard
+        }
+    }
+
+    /// A short name, e.g. `p2wpkh` or `op_return`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScriptKind::P2pkh => "p2pkh",
+            ScriptKind::P2sh => "p2sh",
+            ScriptKind::P2wpkh => "p2wpkh",
+            ScriptKind::P2wsh => "p2wsh",
+            ScriptKind::P2tr => "p2tr",
+            ScriptKind::P2pk => "p2pk",
+            ScriptKind::Multisig { .. } => "multisig",
+            ScriptKind::OpReturn => "op_return",
+            ScriptKind::WitnessUnknown { .. } => "witness_unknown",
+            ScriptKind::NonStandard => "nonstandard",
+        }
+    }
+}
+
+/// `(m, n)` of a bare multisig script: `OP_m <keys> OP_n OP_CHECKMULTISIG`.
+fn multisig_counts(script: &Script) -> Option<(u8, u8)> {
+    let bytes = script.as_bytes();
+    let small_int = |opcode: u8| (0x51..=0x60).contains(&opcode).then(|| opcode - 0x50);
+    let required = small_int(*bytes.first()?)?;
+    let total = small_int(*bytes.get(bytes.len().checked_sub(2)?)?)?;
+    Some((required, total))
+}
+
+/// The data carried by an OP_RETURN output.
+#[derive(Clone, Debug)]
+pub struct OpReturnPayload {
+    /// Every push after the OP_RETURN, concatenated.
+    pub data: Vec<u8>,
+    pub hex: String,
+    /// The data as text, if it is printable UTF-8.
+    pub text: Option<String>,
+    /// The protocol the payload belongs to, if its prefix is a known one.
+    pub protocol: Option<&'static str>,
+}
+
+/// Decode the payload of an OP_RETURN `script`, or `None` if it isn't one.
+pub fn decode_op_return(script: &Script) -> Option<OpReturnPayload> {
+    if !script.is_op_return() {
+        return None;
+    }
+
+    let mut data = Vec::new();
+    let mut runestone = false;
+    for (index, instruction) in script.instructions().skip(1).enumerate() {
+        match instruction {
+            Ok(Instruction::PushBytes(bytes)) => data.extend_from_slice(bytes.as_bytes()),
+            // Runestones mark themselves with OP_13 right after the OP_RETURN.
+            Ok(Instruction::Op(op)) if index == 0 && op == OP_PUSHNUM_13 => runestone = true,
+            Ok(Instruction::Op(_)) => {}
+            // A truncated push, keep what we have.
+            Err(_) => break,
+        }
+    }
+
+    let protocol = if runestone {
+        Some("runes")
+    } else {
+        PROTOCOL_TAGS
+            .iter()
+            .find(|(tag, _)| data.starts_with(tag))
+            .map(|(_, protocol)| *protocol)
+    };
+
+    Some(OpReturnPayload {
+        hex: hex::encode(&data),
+        text: printable_text(&data),
+        protocol,
+        data,
+    })
+}
+
+/// `data` as text, if it is non-empty UTF-8 without control characters other than whitespace.
+fn printable_text(data: &[u8]) -> Option<String> {
+    let text = std::str::from_utf8(data).ok()?;
+    if text.is_empty() || text.chars().any(|c| c.is_control() && !c.is_whitespace()) {
+        return None;
+    }
+    Some(text.chars().take(MAX_TEXT_LEN).collect())
+}
+
+/// An inscription-style envelope, `OP_FALSE OP_IF "ord" ... OP_ENDIF`, in a taproot
+/// script-path spend.
+#[derive(Clone, Debug)]
+pub struct InscriptionEnvelope {
+    pub content_type: Option<String>,
+    /// Size of the inscribed body, in bytes.
+    pub content_length: usize,
+    /// The body as text, if it is printable UTF-8.
+    pub text: Option<String>,
+}
+
+/// The data an envelope instruction pushes. Like ord, `OP_PUSHNUM_1` to `OP_PUSHNUM_16` push
+/// the single byte `[n]` and `OP_PUSHNUM_NEG1` pushes `[0x81]`, so `OP_1` is the content type tag.
+fn envelope_push(instruction: &Instruction) -> Option<Vec<u8>> {
+    match instruction {
+        Instruction::PushBytes(bytes) => Some(bytes.as_bytes().to_vec()),
+        Instruction::Op(op) if *op == OP_PUSHNUM_NEG1 => Some(vec![0x81]),
+        Instruction::Op(op)
+            if (OP_PUSHNUM_1.to_u8()..=OP_PUSHNUM_16.to_u8()).contains(&op.to_u8()) =>
+        {
+            Some(vec![op.to_u8() - OP_PUSHNUM_1.to_u8() + 1])
+        }
+        Instruction::Op(_) => None,
+    }
+}
+
+/// Look for an inscription envelope in the tapscript `input` reveals, if any.
+pub fn find_inscription(input: &TxIn) -> Option<InscriptionEnvelope> {
+    let witness = &input.witness;
+    let tapscript = witness.tapscript()?;
+
+    let mut instructions = tapscript.instructions().peekable();
+    while let Some(instruction) = instructions.next() {
+        // OP_FALSE, which is an empty push.
+        let Ok(Instruction::PushBytes(bytes)) = instruction else {
+            continue;
+        };
+        if !bytes.is_empty() {
+            continue;
+        }
+        if !matches!(instructions.peek(), Some(Ok(Instruction::Op(op))) if *op == OP_IF) {
+            continue;
+        }
+        instructions.next();
+        match instructions.next() {
+            Some(Ok(Instruction::PushBytes(tag))) if tag.as_bytes() == INSCRIPTION_TAG => {}
+            _ => continue,
+        }
+
+        // Tag and value pairs, then an empty push and the body, up to OP_ENDIF.
+        let mut content_type = None;
+        let mut body: Option<Vec<u8>> = None;
+        while let Some(Ok(instruction)) = instructions.next() {
+            if matches!(instruction, Instruction::Op(op) if op == OP_ENDIF) {
+                break;
+            }
+            let Some(data) = envelope_push(&instruction) else {
+                continue;
+            };
+            if let Some(body) = body.as_mut() {
+                body.extend_from_slice(&data);
+            } else if data.is_empty() {
+                body = Some(Vec::new());
+            } else {
+                let value = match instructions.next() {
+                    Some(Ok(value)) => match envelope_push(&value) {
+                        Some(value) => value,
+                        None => break,
+                    },
+                    _ => break,
+                };
+                if data == CONTENT_TYPE_FIELD {
+                    content_type = Some(String::from_utf8_lossy(&value).into_owned());
+                }
+            }
+        }
+
+        let body = body.unwrap_or_default();
+        return Some(InscriptionEnvelope {
+            content_type,
+            content_length: body.len(),
+            text: printable_text(&body),
+        });
+    }
+    None
+}
@@ -1,8 +1,12 @@
 use core::fmt::Display;
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
 use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
 
 use bdk_floresta::ConnectionKind;
 use bdk_floresta::Node;
@@ -11,11 +15,32 @@ use bdk_floresta::PeerStatus;
 use bdk_floresta::TransportProtocol;
 use bdk_floresta::rustreexo::accumulator::stump::Stump;
 use bitcoin::p2p::ServiceFlags;
+use once_cell::sync::Lazy;
 use regex::Regex;
 use tokio::sync::RwLock;
 
+use crate::node::error::BonsaiNodeError;
+use crate::node::error::BonsaiNodeErrorKind;
 use crate::node::message::NodeMessage;
 
+/// BIP-0183 service flags, not yet known to `rust-bitcoin`.
+pub(crate) const NODE_UTREEXO: u64 = 1 << 24;
+pub(crate) const NODE_UTREEXO_ARCHIVE: u64 = 1 << 25;
+
+const KNOWN_SERVICE_FLAGS: &[(ServiceFlags, &str)] = &[
+    (ServiceFlags::NETWORK, "NETWORK"),
+    (ServiceFlags::GETUTXO, "GETUTXO"),
+    (ServiceFlags::BLOOM, "BLOOM"),
+    (ServiceFlags::WITNESS, "WITNESS"),
+    (ServiceFlags::COMPACT_FILTERS, "COMPACT_FILTERS"),
+    (ServiceFlags::NETWORK_LIMITED, "NETWORK_LIMITED"),
+    (ServiceFlags::P2P_V2, "P2P_V2"),
+];
+
+/// When we first saw each connected peer. Forgotten once the peer disconnects.
+static PEER_FIRST_SEEN: Lazy<Mutex<HashMap<SocketAddr, SystemTime>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
 #[derive(Clone, Default, Debug)]
 pub(crate) enum NodeImpl {
     Btcd,
@@ -35,7 +60,7 @@ impl Display for NodeImpl {
             Self::Floresta => write!(f, "Floresta"),
             Self::Utreexod => write!(f, "Utreexod"),
             Self::Knots => write!(f, "Bitcoin Knots"),
-            Self::Unknown => writeln!(f, "Unknown"),
+            Self::Unknown => write!(f, "Unknown"),
         }
     }
 }
@@ -51,18 +76,80 @@ pub struct PeerInformation {
     pub peer_status: PeerStatus,
     pub connection_kind: ConnectionKind,
     pub transport_protocol: TransportProtocol,
+    /// When this peer first showed up in our peer list. This is when we first sampled it, not
+    /// when the connection was made, so it restarts with the app.
+    pub first_seen: SystemTime,
+    pub is_inbound: bool,
+    /// Round trip of the last ping, if the peer answered one yet.
+    pub ping: Option<Duration>,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
 }
 
+/// The fields of [`NodeStatistics`] that are fetched from the node, and may fail.
+pub(crate) const STAT_FIELDS: &[&str] = &[
+    "in_ibd",
+    "headers",
+    "blocks",
+    "accumulator",
+    "user_agent",
+    "peers",
+    "filter_height",
+    "backfill_height",
+    "block_times",
+];
+
+/// A statistic that could not be fetched, and why.
+#[derive(Clone, Debug)]
+pub struct StatFailure {
+    pub field: &'static str,
+    pub error: BonsaiNodeError,
+}
+
+/// Statistics of the node. A field that failed to fetch keeps its default
+/// value and is listed in `failures`, so one bad call never loses the rest.
 #[derive(Clone)]
 pub struct NodeStatistics {
     pub in_ibd: bool,
     pub headers: u32,
     pub blocks: u32,
+    /// The highest block with a downloaded compact filter, if any.
+    pub filter_height: Option<u32>,
+    /// How far the assume-utreexo backfill has validated, while it runs.
+    pub backfill_height: Option<u32>,
+    /// Timestamps of the last validated block and of the best header.
+    pub block_times: Option<(u32, u32)>,
     pub accumulator: Stump,
     pub accumulator_qr_data: Option<String>,
     pub user_agent: String,
     pub peer_informations: Vec<PeerInformation>,
     pub uptime: Duration,
+    pub failures: Vec<StatFailure>,
+}
+
+impl NodeStatistics {
+    pub fn is_available(&self, field: &str) -> bool {
+        !self.failures.iter().any(|failure| failure.field == field)
+    }
+}
+
+/// Unwrap a node call, recording the failure instead of panicking.
+fn fetched<T, E: Display>(
+    field: &'static str,
+    result: Result<T, E>,
+    failures: &mut Vec<StatFailure>,
+) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(e) => {
+            tracing::warn!("Failed to fetch {}: {}", field, e);
+            failures.push(StatFailure {
+                field,
+                error: BonsaiNodeError::new(BonsaiNodeErrorKind::NodeExecError, e.to_string()),
+            });
+            None
+        }
+    }
 }
 
 fn encode_stump(stump: &Stump) -> String {
@@ -74,6 +161,61 @@ fn encode_stump(stump: &Stump) -> String {
     hex::encode(buffer)
 }
 
+/// Decode [`ServiceFlags`] into their names, including the utreexo flags.
+pub(crate) fn service_flag_names(services: ServiceFlags) -> Vec<String> {
+    let mut names: Vec<String> = KNOWN_SERVICE_FLAGS
+        .iter()
+        .filter(|(flag, _)| services.has(*flag))
+        .map(|(_, name)| name.to_string())
+        .collect();
+
+    if services.to_u64() & NODE_UTREEXO != 0 {
+        names.push("NODE_UTREEXO".to_string());
+    }
+    if services.to_u64() & NODE_UTREEXO_ARCHIVE != 0 {
+        names.push("NODE_UTREEXO_ARCHIVE".to_string());
+    }
+
+    names
+}
+
+/// The variant name of a `Debug` value, without its payload (e.g. `Regular` for `Regular(..)`).
+pub(crate) fn variant_name<T: core::fmt::Debug>(value: &T) -> String {
+    let debug = format!("{:?}", value);
+    debug
+        .split(|c: char| c == '(' || c == ' ' || c == '{')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+pub(crate) fn transport_name(transport: &TransportProtocol) -> &'static str {
+    match transport {
+        TransportProtocol::V1 => "v1",
+        TransportProtocol::V2 => "v2",
+    }
+}
+
+pub(crate) fn connection_kind_name(kind: &ConnectionKind) -> &'static str {
+    match kind {
+        ConnectionKind::Feeler => "Feeler",
+        ConnectionKind::Regular(_) => "Regular",
+        ConnectionKind::Extra => "Extra",
+        ConnectionKind::Manual => "Manual",
+    }
+}
+
+/// Whether the peer connected to us. Floresta only opens outbound connections for now, and a
+/// new [`ConnectionKind`] has to be sorted in here before this builds again.
+pub(crate) fn is_inbound(kind: &ConnectionKind) -> bool {
+    match kind {
+        ConnectionKind::Feeler
+        | ConnectionKind::Regular(_)
+        | ConnectionKind::Extra
+        | ConnectionKind::Manual => false,
+    }
+}
+
 fn regex_user_agent(user_agent: &str) -> NodeImpl {
     if Regex::new(r"Satoshi.*Knots").unwrap().is_match(user_agent) {
         NodeImpl::Knots
@@ -90,9 +232,18 @@ fn regex_user_agent(user_agent: &str) -> NodeImpl {
     }
 }
 
-fn process_peer_infos(peer_infos: Vec<PeerInfo>) -> Vec<PeerInformation> {
+pub(crate) fn process_peer_infos(peer_infos: Vec<PeerInfo>) -> Vec<PeerInformation> {
+    let now = SystemTime::now();
+    // Only timestamps live in here, a panic elsewhere can't leave them half-written.
+    let mut seen = PEER_FIRST_SEEN
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    seen.retain(|socket, _| peer_infos.iter().any(|p| p.address == *socket));
+
     let mut peer_informations: Vec<PeerInformation> = Vec::new();
     for peer_info in peer_infos {
+        let first_seen = *seen.entry(peer_info.address).or_insert(now);
+        let is_inbound = is_inbound(&peer_info.kind);
         let peer_information = PeerInformation {
             socket: peer_info.address,
             services: peer_info.services,
@@ -102,6 +253,11 @@ fn process_peer_infos(peer_infos: Vec<PeerInfo>) -> Vec<PeerInformation> {
             peer_status: peer_info.state,
             connection_kind: peer_info.kind,
             transport_protocol: peer_info.transport_protocol,
+            first_seen,
+            is_inbound,
+            ping: peer_info.ping,
+            bytes_sent: peer_info.bytes_sent,
+            bytes_received: peer_info.bytes_received,
         };
         peer_informations.push(peer_information);
     }
@@ -109,46 +265,124 @@ fn process_peer_infos(peer_infos: Vec<PeerInfo>) -> Vec<PeerInformation> {
     peer_informations
 }
 
+/// The highest height with a downloaded compact filter, at most `tip`.
+///
+/// Filters are downloaded in order, so it is found by bisecting up from `known`, a height
+/// already known to have one, and stays cheap once the filters are caught up.
+pub(crate) fn filter_height(
+    node: &Node,
+    known: Option<u32>,
+    tip: u32,
+) -> Result<Option<u32>, BonsaiNodeError> {
+    let has_filter = |height: u32| node.get_block_filter(height).map(|filter| filter.is_some());
+    if has_filter(tip)? {
+        return Ok(Some(tip));
+    }
+
+    let mut low = match known.filter(|&height| height < tip) {
+        Some(height) => height,
+        None if has_filter(0)? => 0,
+        None => return Ok(None),
+    };
+    // `low` has a filter and `high` doesn't.
+    let mut high = tip;
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if has_filter(mid)? {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(Some(low))
+}
+
+fn block_time(node: &Node, height: u32) -> Result<u32, BonsaiNodeError> {
+    let hash = node.get_block_hash(height)?;
+    Ok(node.get_block_header(&hash)?.time)
+}
+
+/// Fetch the statistics of the node.
+///
+/// The accumulator only changes with the validation height, so when `previous` was taken at
+/// the same height its accumulator and serialization are reused instead of fetched again.
 pub(crate) async fn fetch_stats(
     node_handle: Arc<RwLock<Node>>,
     start_time: Option<Instant>,
+    previous: Option<&NodeStatistics>,
 ) -> NodeMessage {
-    let result = async {
-        let node_handle = node_handle.read().await;
-
-        let in_ibd = node_handle.in_ibd().unwrap();
-        let headers = node_handle.get_height().unwrap_or(0);
-        let blocks = node_handle.get_validation_height().unwrap_or(0);
-        let accumulator = node_handle.get_accumulator().unwrap();
-        let user_agent = node_handle.get_config().await.unwrap().user_agent;
-        let uptime = start_time
-            .map(|t| t.elapsed())
-            .unwrap_or(Duration::from_secs(0));
-        let peer_infos_raw = node_handle.get_peer_info().await.unwrap_or_default();
-        let peer_informations = process_peer_infos(peer_infos_raw);
-
-        let encoded_stump = encode_stump(&accumulator);
-        let accumulator_qr_data = if !encoded_stump.is_empty() {
-            Some(encoded_stump)
-        } else {
-            None
-        };
+    let node_handle = node_handle.read().await;
+    let mut failures = Vec::new();
 
-        Ok(NodeStatistics {
-            in_ibd,
+    let in_ibd = fetched("in_ibd", node_handle.in_ibd(), &mut failures).unwrap_or(true);
+    let headers = fetched("headers", node_handle.get_height(), &mut failures).unwrap_or(0);
+    let blocks = fetched("blocks", node_handle.get_validation_height(), &mut failures).unwrap_or(0);
+    let filter_height = fetched(
+        "filter_height",
+        filter_height(
+            &node_handle,
+            previous.and_then(|previous| previous.filter_height),
             headers,
-            blocks,
-            accumulator,
-            user_agent,
-            accumulator_qr_data,
-            peer_informations,
-            uptime,
-        })
-    }
-    .await;
+        ),
+        &mut failures,
+    )
+    .flatten();
+    let backfill_height = fetched(
+        "backfill_height",
+        node_handle.get_backfill_height(),
+        &mut failures,
+    )
+    .flatten();
+    let block_times = fetched(
+        "block_times",
+        block_time(&node_handle, blocks)
+            .and_then(|block| Ok((block, block_time(&node_handle, headers)?))),
+        &mut failures,
+    );
 
-    match result {
-        Ok(stats) => NodeMessage::Statistics(stats),
-        Err(e) => NodeMessage::Error(e),
-    }
+    let reusable = previous.filter(|previous| {
+        previous.is_available("blocks")
+            && previous.is_available("accumulator")
+            && !failures.iter().any(|failure| failure.field == "blocks")
+            && previous.blocks == blocks
+    });
+    let (accumulator, accumulator_qr_data) = match reusable {
+        Some(previous) => (
+            Some(previous.accumulator.clone()),
+            previous.accumulator_qr_data.clone(),
+        ),
+        None => {
+            let accumulator = fetched("accumulator", node_handle.get_accumulator(), &mut failures);
+            let accumulator_qr_data = accumulator
+                .as_ref()
+                .map(encode_stump)
+                .filter(|encoded_stump| !encoded_stump.is_empty());
+            (accumulator, accumulator_qr_data)
+        }
+    };
+    let user_agent = fetched("user_agent", node_handle.get_config().await, &mut failures)
+        .map(|config| config.user_agent)
+        .unwrap_or_default();
+    let uptime = start_time
+        .map(|t| t.elapsed())
+        .unwrap_or(Duration::from_secs(0));
+    let peer_infos_raw =
+        fetched("peers", node_handle.get_peer_info().await, &mut failures).unwrap_or_default();
+    let peer_informations = process_peer_infos(peer_infos_raw);
+
+    NodeMessage::Statistics(NodeStatistics {
+        in_ibd,
+        headers,
+        blocks,
+        filter_height,
+        backfill_height,
+        block_times,
+        accumulator: accumulator.unwrap_or_else(Stump::new),
+        user_agent,
+        accumulator_qr_data,
+        peer_informations,
+        uptime,
+        failures,
+    })
 }
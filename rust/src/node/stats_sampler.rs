@@ -0,0 +1,218 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use bdk_floresta::Node;
+use bitcoin::Network;
+use once_cell::sync::Lazy;
+use tokio::sync::RwLock;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::info;
+
+use crate::node::control::FETCH_STATISTICS_TIME;
+use crate::node::control::check_peer_diversity;
+use crate::node::control::enforce_bans;
+use crate::node::message::NodeMessage;
+use crate::node::peer_diversity::DiversityReport;
+use crate::node::stats_fetcher::NodeStatistics;
+use crate::node::stats_fetcher::fetch_stats;
+
+/// How many samples the history keeps: one hour at one sample per second.
+pub const HISTORY_LEN: usize = 3600;
+
+/// How often bans and the peer diversity policy are enforced. Peers change far slower than
+/// the sync progress the sampler tracks every tick.
+const POLICY_INTERVAL_SECS: u64 = 30;
+
+/// The sampler of the running node.
+pub(crate) static STATS_SAMPLER: Lazy<Arc<RwLock<Option<StatsSampler>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(None)));
+
+/// Everything the sampler collected in one tick.
+#[derive(Clone)]
+pub struct StatsSnapshot {
+    pub statistics: NodeStatistics,
+    pub diversity: DiversityReport,
+    pub taken_at: SystemTime,
+}
+
+/// One point of the time-series history.
+#[derive(Clone, Debug)]
+pub struct StatsSample {
+    pub timestamp: SystemTime,
+    pub blocks: u32,
+    pub headers: u32,
+    pub filter_height: Option<u32>,
+    pub backfill_height: Option<u32>,
+    pub peers: usize,
+    /// Validated blocks per second since the previous sample.
+    pub blocks_per_sec: f64,
+}
+
+/// Polls the node in the background, so readers get the latest snapshot
+/// without taking the node lock themselves. Bans and the diversity policy
+/// are enforced from the same task, on a slower interval.
+///
+/// The sampler only holds a [`Weak`] reference to the node, so it never keeps
+/// [`stop_node`](crate::node::control::stop_node) from taking the node back.
+pub struct StatsSampler {
+    snapshot: watch::Receiver<Option<StatsSnapshot>>,
+    history: Arc<Mutex<VecDeque<StatsSample>>>,
+    network: Network,
+    started_at: SystemTime,
+    task: JoinHandle<()>,
+}
+
+impl StatsSampler {
+    /// Start sampling `node`, counting uptime from now.
+    pub fn spawn(node: &Arc<RwLock<Node>>, network: Network) -> Self {
+        let (tx, rx) = watch::channel(None);
+        let history = Arc::new(Mutex::new(VecDeque::with_capacity(HISTORY_LEN)));
+        let task = tokio::spawn(sample_loop(
+            Arc::downgrade(node),
+            Instant::now(),
+            tx,
+            history.clone(),
+        ));
+
+        StatsSampler {
+            snapshot: rx,
+            history,
+            network,
+            started_at: SystemTime::now(),
+            task,
+        }
+    }
+
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    pub fn started_at(&self) -> SystemTime {
+        self.started_at
+    }
+
+    /// The latest snapshot, or `None` before the first sample is taken.
+    pub fn latest(&self) -> Option<StatsSnapshot> {
+        self.snapshot.borrow().clone()
+    }
+
+    /// A receiver that is notified of every new snapshot.
+    pub fn subscribe(&self) -> watch::Receiver<Option<StatsSnapshot>> {
+        self.snapshot.clone()
+    }
+
+    /// The samples taken so far, oldest first.
+    pub fn history(&self) -> Vec<StatsSample> {
+        self.history
+            .lock()
+            .map(|history| history.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Stop sampling, and wait until the task has let go of the node.
+    pub async fn stop(self) {
+        self.task.abort();
+        let _ = self.task.await;
+    }
+}
+
+async fn sample_loop(
+    node: Weak<RwLock<Node>>,
+    start_time: Instant,
+    tx: watch::Sender<Option<StatsSnapshot>>,
+    history: Arc<Mutex<VecDeque<StatsSample>>>,
+) {
+    let mut interval = tokio::time::interval(Duration::from_secs(FETCH_STATISTICS_TIME));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut policy_interval = tokio::time::interval(Duration::from_secs(POLICY_INTERVAL_SECS));
+    policy_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut previous: Option<(Instant, u32)> = None;
+    let mut diversity = DiversityReport::default();
+
+    loop {
+        let enforce_policy = tokio::select! {
+            _ = interval.tick() => false,
+            _ = policy_interval.tick() => true,
+        };
+
+        let Some(node) = node.upgrade() else {
+            info!("Node is gone, stopping the stats sampler");
+            return;
+        };
+        if enforce_policy {
+            enforce_bans(node.clone()).await;
+            diversity = check_peer_diversity(node).await;
+            // Clone first, the borrow must be gone before sending.
+            let last = tx.borrow().clone();
+            if let Some(snapshot) = last {
+                let snapshot = StatsSnapshot {
+                    diversity: diversity.clone(),
+                    ..snapshot
+                };
+                if tx.send(Some(snapshot)).is_err() {
+                    return;
+                }
+            }
+            continue;
+        }
+
+        let last = tx.borrow().clone();
+        let message = fetch_stats(
+            node,
+            Some(start_time),
+            last.as_ref().map(|snapshot| &snapshot.statistics),
+        )
+        .await;
+
+        let NodeMessage::Statistics(statistics) = message else {
+            continue;
+        };
+
+        let now = Instant::now();
+        let blocks_per_sec = match previous {
+            Some((then, blocks)) if statistics.is_available("blocks") => {
+                let elapsed = now.duration_since(then).as_secs_f64();
+                if elapsed > 0.0 {
+                    statistics.blocks.saturating_sub(blocks) as f64 / elapsed
+                } else {
+                    0.0
+                }
+            }
+            _ => 0.0,
+        };
+        if statistics.is_available("blocks") {
+            previous = Some((now, statistics.blocks));
+        }
+
+        if let Ok(mut history) = history.lock() {
+            if history.len() == HISTORY_LEN {
+                history.pop_front();
+            }
+            history.push_back(StatsSample {
+                timestamp: SystemTime::now(),
+                blocks: statistics.blocks,
+                headers: statistics.headers,
+                filter_height: statistics.filter_height,
+                backfill_height: statistics.backfill_height,
+                peers: statistics.peer_informations.len(),
+                blocks_per_sec,
+            });
+        }
+
+        let snapshot = StatsSnapshot {
+            statistics,
+            diversity: diversity.clone(),
+            taken_at: SystemTime::now(),
+        };
+        if tx.send(Some(snapshot)).is_err() {
+            return;
+        }
+    }
+}
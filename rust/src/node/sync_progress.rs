@@ -0,0 +1,270 @@
+use core::fmt::Display;
+use std::time::Duration;
+
+use bitcoin::Network;
+use bitcoin::constants::genesis_block;
+
+use crate::node::stats_fetcher::NodeStatistics;
+use crate::node::stats_sampler::StatsSample;
+
+/// Approximate cumulative transaction count at mainnet heights.
+///
+/// Validation time tracks transactions far more closely than height: the first
+/// 200k blocks hold under 1% of all transactions. Only the shape of the curve matters here.
+const MAINNET_TX_COUNTS: &[(u32, u64)] = &[
+    (0, 1),
+    (100_000, 216_000),
+    (200_000, 7_300_000),
+    (300_000, 42_000_000),
+    (400_000, 120_000_000),
+    (500_000, 283_000_000),
+    (600_000, 475_000_000),
+    (700_000, 680_000_000),
+    (800_000, 850_000_000),
+    (840_000, 1_000_000_000),
+];
+
+/// Transactions per block assumed past the last entry of [`MAINNET_TX_COUNTS`].
+const MAINNET_TXS_PER_BLOCK: u64 = 3_500;
+
+/// How far back the validation rate is measured.
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Headers-first sync is considered done once we are this close to the best peer's height.
+const HEADERS_SLACK: u32 = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncPhase {
+    /// No peers and no headers yet.
+    Connecting,
+    /// Downloading the header chain.
+    Headers,
+    /// Downloading and validating blocks.
+    Blocks,
+    /// Validating the blocks below the assume-utreexo snapshot, after catching up with the tip.
+    Backfill,
+    /// Downloading the compact filters the wallet scans with.
+    Filters,
+    Synced,
+}
+
+impl Display for SyncPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connecting => write!(f, "connecting"),
+            Self::Headers => write!(f, "headers"),
+            Self::Blocks => write!(f, "blocks"),
+            Self::Backfill => write!(f, "backfill"),
+            Self::Filters => write!(f, "filters"),
+            Self::Synced => write!(f, "synced"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SyncProgress {
+    pub phase: SyncPhase,
+    /// Progress of the current phase, 0.0 - 1.0. Weighted by transaction count on mainnet,
+    /// by block time on the test networks.
+    pub progress: f64,
+    /// Validated blocks over the target height, unweighted.
+    pub height_progress: f64,
+    pub blocks: u32,
+    pub headers: u32,
+    pub filter_height: Option<u32>,
+    pub backfill_height: Option<u32>,
+    /// The best height we know of, from our headers or our peers.
+    pub target_height: u32,
+    /// Blocks per second the current phase advances at: validated blocks, backfilled blocks
+    /// or downloaded filters.
+    pub blocks_per_sec: f64,
+    /// Time left in the current phase, if it is advancing at all.
+    pub eta: Option<Duration>,
+}
+
+/// The estimated number of transactions in the chain up to `height`.
+///
+/// Mainnet uses [`MAINNET_TX_COUNTS`]. The other networks have no meaningful
+/// curve, so every block weighs the same there, see [`time_progress`] for a better
+/// progress measure on the test networks.
+pub fn estimated_tx_count(network: Network, height: u32) -> f64 {
+    if network != Network::Bitcoin {
+        return height as f64;
+    }
+
+    for pair in MAINNET_TX_COUNTS.windows(2) {
+        let (low_height, low_count) = pair[0];
+        let (high_height, high_count) = pair[1];
+        if height <= high_height {
+            let fraction = (height - low_height) as f64 / (high_height - low_height) as f64;
+            return low_count as f64 + fraction * (high_count - low_count) as f64;
+        }
+    }
+
+    let (last_height, last_count) = MAINNET_TX_COUNTS[MAINNET_TX_COUNTS.len() - 1];
+    last_count as f64 + (height - last_height) as f64 * MAINNET_TXS_PER_BLOCK as f64
+}
+
+/// Progress by block time from genesis, for the networks other than mainnet.
+///
+/// Testnet difficulty resets let miners pile up thousands of blocks in minutes, so height
+/// says little there, while time tracks how much of the chain is left.
+fn time_progress(network: Network, block_time: u32, target_time: u32) -> Option<f64> {
+    let genesis_time = genesis_block(network).header.time;
+    if target_time <= genesis_time {
+        return None;
+    }
+    let fraction =
+        block_time.saturating_sub(genesis_time) as f64 / (target_time - genesis_time) as f64;
+    Some(fraction.clamp(0.0, 1.0))
+}
+
+/// Validation progress from `blocks` to `target`, weighted by transaction count.
+pub fn weighted_progress(network: Network, blocks: u32, target: u32) -> f64 {
+    if target == 0 {
+        return 0.0;
+    }
+    if blocks >= target {
+        return 1.0;
+    }
+
+    estimated_tx_count(network, blocks) / estimated_tx_count(network, target)
+}
+
+/// The samples within [`RATE_WINDOW`] of the newest one.
+fn rate_window(history: &[StatsSample]) -> Option<(&StatsSample, &StatsSample)> {
+    let last = history.last()?;
+    let first = history
+        .iter()
+        .find(|sample| {
+            last.timestamp
+                .duration_since(sample.timestamp)
+                .map(|age| age <= RATE_WINDOW)
+                .unwrap_or(true)
+        })
+        .unwrap_or(last);
+
+    Some((first, last))
+}
+
+/// The height `phase` advances in a sample, and the height it runs up to.
+fn phase_heights(phase: SyncPhase, sample: &StatsSample, target_height: u32) -> Option<(u32, u32)> {
+    match phase {
+        SyncPhase::Blocks => Some((sample.blocks, target_height)),
+        SyncPhase::Backfill => Some((sample.backfill_height?, sample.blocks)),
+        SyncPhase::Filters => Some((sample.filter_height.unwrap_or(0), sample.headers)),
+        SyncPhase::Connecting | SyncPhase::Headers | SyncPhase::Synced => None,
+    }
+}
+
+/// How fast `phase` advanced over [`RATE_WINDOW`], in blocks per second, and the time it
+/// needs to finish at that pace.
+fn phase_rate(
+    network: Network,
+    phase: SyncPhase,
+    history: &[StatsSample],
+    target_height: u32,
+) -> Option<(f64, Option<Duration>)> {
+    let (first, last) = rate_window(history)?;
+    let (from, _) = phase_heights(phase, first, target_height)?;
+    let (to, end) = phase_heights(phase, last, target_height)?;
+    let elapsed = last
+        .timestamp
+        .duration_since(first.timestamp)
+        .unwrap_or_default()
+        .as_secs_f64();
+    if elapsed <= 0.0 || to <= from {
+        return None;
+    }
+
+    let txs_per_sec =
+        (estimated_tx_count(network, to) - estimated_tx_count(network, from)) / elapsed;
+    let txs_left = estimated_tx_count(network, end) - estimated_tx_count(network, to);
+    let eta = (txs_per_sec > 0.0 && txs_left > 0.0)
+        .then(|| Duration::from_secs_f64(txs_left / txs_per_sec));
+    Some(((to - from) as f64 / elapsed, eta))
+}
+
+/// Estimate the sync progress from the latest statistics and the sampler's history.
+pub fn estimate(
+    network: Network,
+    statistics: &NodeStatistics,
+    history: &[StatsSample],
+) -> SyncProgress {
+    let blocks = statistics.blocks;
+    let headers = statistics.headers;
+    let best_peer_height = statistics
+        .peer_informations
+        .iter()
+        .map(|peer| peer.initial_height)
+        .max()
+        .unwrap_or(0);
+    let target_height = headers.max(best_peer_height);
+
+    // Filters are always downloaded, a node that reports none has none yet.
+    let filter_height = statistics.filter_height.unwrap_or(0);
+    let filters_behind = statistics.is_available("filter_height")
+        && filter_height.saturating_add(HEADERS_SLACK) < headers;
+    let backfill_behind = statistics
+        .backfill_height
+        .is_some_and(|backfill| backfill < blocks);
+
+    let phase = if statistics.in_ibd {
+        if headers == 0 && statistics.peer_informations.is_empty() {
+            SyncPhase::Connecting
+        } else if headers.saturating_add(HEADERS_SLACK) < best_peer_height {
+            SyncPhase::Headers
+        } else {
+            SyncPhase::Blocks
+        }
+    } else if backfill_behind {
+        SyncPhase::Backfill
+    } else if filters_behind {
+        SyncPhase::Filters
+    } else {
+        SyncPhase::Synced
+    };
+
+    // Block times only help off mainnet, and only against our own best header.
+    let time_weighted = match statistics.block_times {
+        Some((block_time, header_time))
+            if network != Network::Bitcoin && target_height == headers =>
+        {
+            time_progress(network, block_time, header_time)
+        }
+        _ => None,
+    };
+    let progress = match phase {
+        SyncPhase::Connecting => 0.0,
+        SyncPhase::Headers => headers as f64 / best_peer_height as f64,
+        SyncPhase::Blocks => {
+            time_weighted.unwrap_or_else(|| weighted_progress(network, blocks, target_height))
+        }
+        SyncPhase::Backfill => {
+            weighted_progress(network, statistics.backfill_height.unwrap_or(0), blocks)
+        }
+        SyncPhase::Filters => weighted_progress(network, filter_height, headers),
+        SyncPhase::Synced => 1.0,
+    };
+    let height_progress = if target_height > 0 {
+        (blocks as f64 / target_height as f64).min(1.0)
+    } else {
+        0.0
+    };
+
+    let (blocks_per_sec, eta) =
+        phase_rate(network, phase, history, target_height).unwrap_or((0.0, None));
+
+    SyncProgress {
+        phase,
+        progress,
+        height_progress,
+        blocks,
+        headers,
+        filter_height: statistics.filter_height,
+        backfill_height: statistics.backfill_height,
+        target_height,
+        blocks_per_sec,
+        eta,
+    }
+}
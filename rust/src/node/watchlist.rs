@@ -0,0 +1,332 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use bdk_floresta::UtxoData;
+use bitcoin::Address;
+use bitcoin::Amount;
+use bitcoin::Block;
+use bitcoin::Network;
+use bitcoin::OutPoint;
+use bitcoin::ScriptBuf;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tracing::error;
+use tracing::info;
+
+use crate::common::util::unix_now;
+use crate::node::block_pipeline::BlockDelivery;
+use crate::node::block_pipeline::BlockHandler;
+use crate::node::script_analyzer::ScriptKind;
+use crate::node::script_analyzer::decode_op_return;
+
+pub(crate) const WATCHLIST_FILE: &str = "watchlist.toml";
+
+/// How many matches are kept, oldest dropped first.
+pub const WATCH_MATCHES_LEN: usize = 1_000;
+
+/// How many matches a slow event subscriber may fall behind before it misses some.
+const WATCH_EVENTS_LEN: usize = 64;
+
+/// The watchlist of the running node, loaded from its data directory on start.
+pub(crate) static WATCHLIST: Lazy<Mutex<Option<Watchlist>>> = Lazy::new(|| Mutex::new(None));
+
+/// Every new match, as it is found.
+static WATCH_EVENTS: Lazy<broadcast::Sender<WatchMatch>> =
+    Lazy::new(|| broadcast::channel(WATCH_EVENTS_LEN).0);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WatchEntry {
+    /// The watched output script, in hex.
+    pub script: String,
+    /// The address the script was added as, if it was.
+    pub address: Option<String>,
+    /// See [`ScriptKind::name`].
+    #[serde(default)]
+    pub script_type: String,
+    pub label: String,
+    /// UNIX timestamp of when it was added.
+    pub added_at: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// The transaction pays to the script.
+    Incoming,
+    /// The transaction spends an output of the script.
+    Outgoing,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WatchMatch {
+    pub script: String,
+    pub label: String,
+    pub txid: String,
+    pub block_hash: String,
+    pub height: u32,
+    pub direction: Direction,
+    /// Total paid to, or spent from, the script by the transaction.
+    pub amount_sats: u64,
+    /// The first OP_RETURN payload of the transaction, as text if it is, or hex.
+    #[serde(default)]
+    pub memo: Option<String>,
+}
+
+/// Scripts the user watches for activity, and what was seen, persisted as `watchlist.toml` in
+/// the node's data directory.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Watchlist {
+    #[serde(default)]
+    entries: Vec<WatchEntry>,
+    /// Oldest first.
+    #[serde(default)]
+    matches: Vec<WatchMatch>,
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(skip)]
+    network: Option<Network>,
+}
+
+impl Watchlist {
+    /// Load the watchlist from `data_dir`, or start an empty one if there is none.
+    pub fn load(data_dir: &Path, network: Network) -> Self {
+        let path = data_dir.join(WATCHLIST_FILE);
+
+        let mut watchlist = match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str::<Watchlist>(&contents).unwrap_or_else(|e| {
+                error!("Failed to parse {}: {}", path.to_string_lossy(), e);
+                Watchlist::default()
+            }),
+            Err(_) => Watchlist::default(),
+        };
+        watchlist.path = path;
+        watchlist.network = Some(network);
+
+        info!("Loaded {} watched script(s)", watchlist.entries.len());
+        watchlist
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let contents = toml::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(&self.path, contents).map_err(|e| {
+            format!(
+                "Failed to write watchlist to {}: {}",
+                self.path.to_string_lossy(),
+                e
+            )
+        })
+    }
+
+    /// Watch the script of `address`, replacing any entry for it.
+    pub fn add_address(&mut self, address: &str, label: String) -> Result<WatchEntry, String> {
+        let network = self.network.ok_or("Watchlist not loaded")?;
+        let address = Address::from_str(address.trim())
+            .map_err(|e| format!("Invalid address: {}", e))?
+            .require_network(network)
+            .map_err(|e| format!("Address network mismatch: {}", e))?;
+
+        Ok(self.add(address.script_pubkey(), Some(address.to_string()), label))
+    }
+
+    /// Watch a raw output script, given in hex, replacing any entry for it.
+    pub fn add_script(&mut self, script_hex: &str, label: String) -> Result<WatchEntry, String> {
+        let script =
+            ScriptBuf::from_hex(script_hex.trim()).map_err(|e| format!("Invalid script: {}", e))?;
+        if script.is_empty() {
+            return Err("Empty script".to_string());
+        }
+
+        let address = self
+            .network
+            .and_then(|network| Address::from_script(&script, network).ok())
+            .map(|address| address.to_string());
+        Ok(self.add(script, address, label))
+    }
+
+    fn add(&mut self, script: ScriptBuf, address: Option<String>, label: String) -> WatchEntry {
+        let entry = WatchEntry {
+            script: script.to_hex_string(),
+            address,
+            script_type: ScriptKind::classify(&script).name().to_string(),
+            label,
+            added_at: unix_now(),
+        };
+        self.entries
+            .retain(|existing| existing.script != entry.script);
+        self.entries.push(entry.clone());
+        entry
+    }
+
+    /// Stop watching `script`, an address or script hex. Returns whether it was watched.
+    /// Its past matches are kept.
+    pub fn remove(&mut self, script: &str) -> bool {
+        let script = script.trim();
+        let len = self.entries.len();
+        self.entries
+            .retain(|entry| entry.script != script && entry.address.as_deref() != Some(script));
+        self.entries.len() != len
+    }
+
+    pub fn entries(&self) -> &[WatchEntry] {
+        &self.entries
+    }
+
+    /// Up to `limit` matches, newest first.
+    pub fn matches(&self, limit: usize) -> Vec<WatchMatch> {
+        self.matches.iter().rev().take(limit).cloned().collect()
+    }
+
+    /// Drop matches at or above `height`. Returns whether anything was removed.
+    fn rollback(&mut self, height: u32) -> bool {
+        let len = self.matches.len();
+        self.matches.retain(|matched| matched.height < height);
+        self.matches.len() != len
+    }
+
+    /// Check `block` against the watched scripts, replacing the matches found at or above
+    /// `height` before. Spends are only seen if the spent outputs are given.
+    ///
+    /// Returns the new matches, and whether anything changed.
+    pub fn connect_block(
+        &mut self,
+        block: &Block,
+        height: u32,
+        spent_utxos: Option<&HashMap<OutPoint, UtxoData>>,
+    ) -> (Vec<WatchMatch>, bool) {
+        let changed = self.rollback(height);
+        if self.entries.is_empty() {
+            return (Vec::new(), changed);
+        }
+
+        let watched: HashMap<ScriptBuf, &WatchEntry> = self
+            .entries
+            .iter()
+            .filter_map(|entry| Some((ScriptBuf::from_hex(&entry.script).ok()?, entry)))
+            .collect();
+        let block_hash = block.block_hash().to_string();
+
+        let mut found = Vec::new();
+        for tx in &block.txdata {
+            // One match per script and direction, however many outputs are involved.
+            let mut amounts: BTreeMap<(&str, Direction), Amount> = BTreeMap::new();
+            let mut labels: HashMap<&str, &str> = HashMap::new();
+
+            for output in &tx.output {
+                if let Some(entry) = watched.get(&output.script_pubkey) {
+                    *amounts
+                        .entry((&entry.script, Direction::Incoming))
+                        .or_insert(Amount::ZERO) += output.value;
+                    labels.insert(&entry.script, &entry.label);
+                }
+            }
+            if let Some(spent_utxos) = spent_utxos {
+                for input in &tx.input {
+                    let Some(utxo) = spent_utxos.get(&input.previous_output) else {
+                        continue;
+                    };
+                    if let Some(entry) = watched.get(&utxo.txout.script_pubkey) {
+                        *amounts
+                            .entry((&entry.script, Direction::Outgoing))
+                            .or_insert(Amount::ZERO) += utxo.txout.value;
+                        labels.insert(&entry.script, &entry.label);
+                    }
+                }
+            }
+
+            if amounts.is_empty() {
+                continue;
+            }
+            let txid = tx.compute_txid().to_string();
+            let memo = tx
+                .output
+                .iter()
+                .find_map(|output| decode_op_return(&output.script_pubkey))
+                .map(|payload| payload.text.unwrap_or(payload.hex));
+            for ((script, direction), amount) in amounts {
+                found.push(WatchMatch {
+                    script: script.to_string(),
+                    label: labels.get(script).unwrap_or(&"").to_string(),
+                    txid: txid.clone(),
+                    block_hash: block_hash.clone(),
+                    height,
+                    direction,
+                    amount_sats: amount.to_sat(),
+                    memo: memo.clone(),
+                });
+            }
+        }
+
+        self.matches.extend(found.iter().cloned());
+        if self.matches.len() > WATCH_MATCHES_LEN {
+            let excess = self.matches.len() - WATCH_MATCHES_LEN;
+            self.matches.drain(..excess);
+        }
+        let changed = changed || !found.is_empty();
+        (found, changed)
+    }
+}
+
+/// A receiver of every new watchlist match.
+pub fn subscribe_watch_events() -> broadcast::Receiver<WatchMatch> {
+    WATCH_EVENTS.subscribe()
+}
+
+/// Persist the watchlist and let go of it.
+pub fn close_watchlist() {
+    if let Ok(mut watchlist) = WATCHLIST.lock() {
+        if let Some(watchlist) = watchlist.take() {
+            if let Err(e) = watchlist.save() {
+                error!("{}", e);
+            }
+        }
+    }
+}
+
+/// Checks every new block against [`WATCHLIST`], announcing what it finds.
+pub(crate) struct WatchlistHandler;
+
+impl BlockHandler for WatchlistHandler {
+    fn name(&self) -> &'static str {
+        "watchlist"
+    }
+
+    fn wants_spent_utxos(&self) -> bool {
+        true
+    }
+
+    fn handle(&mut self, delivery: &BlockDelivery) {
+        let Ok(mut watchlist) = WATCHLIST.lock() else {
+            return;
+        };
+        let Some(watchlist) = watchlist.as_mut() else {
+            return;
+        };
+
+        let (found, changed) = watchlist.connect_block(
+            &delivery.block,
+            delivery.height,
+            delivery.spent_utxos.as_deref(),
+        );
+        if changed {
+            if let Err(e) = watchlist.save() {
+                error!("{}", e);
+            }
+        }
+
+        for matched in found {
+            info!(
+                "Watched script {} ({}) {:?} {} sats in {}",
+                matched.script, matched.label, matched.direction, matched.amount_sats, matched.txid
+            );
+            // Nobody listening is fine, the match is stored either way.
+            let _ = WATCH_EVENTS.send(matched);
+        }
+    }
+}
@@ -1,17 +1,20 @@
 use std::path::Path;
 use std::sync::Arc;
-use std::collections::HashMap;
 use tokio::sync::RwLock;
 use once_cell::sync::Lazy;
-use bitcoin::{Network, Block, OutPoint};
+use bitcoin::{Network, Block};
 use bitcoin::bip32::{Xpriv, DerivationPath};
 use bitcoin::secp256k1::Secp256k1;
 use bdk_wallet::{Wallet, KeychainKind};
-use bdk_floresta::{BlockConsumer, UtxoData};
+use bdk_wallet::chain::BlockId;
 use bip39::Mnemonic;
 use std::fs;
 use std::str::FromStr;
 use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use crate::common::util::unix_now;
+use crate::node::block_pipeline::{BlockDelivery, BlockHandler};
+use crate::node::reorg::{ChainEvent, ChainTracker, ReorgEvent};
 
 pub static WALLET_MANAGER: Lazy<Arc<RwLock<Option<WalletManager>>>> = 
     Lazy::new(|| Arc::new(RwLock::new(None)));
@@ -28,10 +31,49 @@ pub struct WalletTransaction {
     pub timestamp: Option<u64>,
 }
 
+/// Wallet details kept next to the mnemonic
+const METADATA_FILE: &str = "wallet.toml";
+
+/// Scripts derived past the last used one on each keychain when scanning for the wallet
+pub const GAP_LIMIT: u32 = 20;
+
+/// What we know about the wallet besides its keys, persisted as `wallet.toml`
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WalletMetadata {
+    /// UNIX time the wallet was created, or first used if it was imported.
+    /// Nothing older can hold its coins, so rescans start here
+    pub birthday: Option<u64>,
+}
+
+impl WalletMetadata {
+    pub fn load(data_dir: &Path) -> Self {
+        fs::read_to_string(data_dir.join(METADATA_FILE))
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, data_dir: &Path) -> Result<(), String> {
+        let contents = toml::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(data_dir.join(METADATA_FILE), contents)
+            .map_err(|e| format!("Failed to save wallet metadata: {}", e))
+    }
+}
+
+/// The BIP44 coin type for a [`Network`]: `0` for mainnet, `1` for every test network.
+pub fn coin_type(network: Network) -> u32 {
+    match network {
+        Network::Bitcoin => 0,
+        Network::Testnet | Network::Testnet4 | Network::Signet | Network::Regtest => 1,
+    }
+}
+
 pub struct WalletManager {
     pub wallet: Wallet,
     pub network: Network,
-    pending_blocks: Vec<(Block, u32)>, // blocks to process
+    pub metadata: WalletMetadata,
+    applied_blocks: usize, // blocks applied since the last sync_wallet
+    chain: ChainTracker, // blocks applied so far, to spot reorgs
 }
 
 impl WalletManager {
@@ -51,8 +93,10 @@ impl WalletManager {
                 .map_err(|e| format!("Failed to create mnemonic: {}", e))?;
             fs::write(&mnemonic_path, m.to_string())
                 .map_err(|e| format!("Failed to save mnemonic: {}", e))?;
+            WalletMetadata { birthday: Some(unix_now()) }.save(Path::new(data_dir))?;
             m
         };
+        let metadata = WalletMetadata::load(Path::new(data_dir));
 
         // Derive master key from mnemonic
         let seed = mnemonic.to_seed("");
@@ -62,10 +106,7 @@ impl WalletManager {
 
         // Create BIP84 derivation paths
         // m/84'/coin'/0'/0 for external, m/84'/coin'/0'/1 for internal
-        let coin_type = match network {
-            Network::Bitcoin => 0,
-            _ => 1, // testnet/signet/regtest
-        };
+        let coin_type = coin_type(network);
 
         let external_path = DerivationPath::from_str(&format!("m/84'/{}'/0'/0", coin_type))
             .map_err(|e| format!("Invalid external path: {}", e))?;
@@ -94,7 +135,9 @@ impl WalletManager {
         Ok(Self {
             wallet,
             network,
-            pending_blocks: Vec::new(),
+            metadata,
+            applied_blocks: 0,
+            chain: ChainTracker::default(),
         })
     }
 
@@ -106,21 +149,180 @@ impl WalletManager {
         self.wallet.reveal_next_address(KeychainKind::External).address.to_string()
     }
 
-    /// Add a block to be processed by the wallet
-    pub fn queue_block(&mut self, block: Block, height: u32) {
-        self.pending_blocks.push((block, height));
+    /// How many blocks were applied since the last call
+    pub fn take_applied_blocks(&mut self) -> usize {
+        std::mem::take(&mut self.applied_blocks)
+    }
+
+    /// Apply a block, first rolling back the wallet if it reorganizes the chain
+    pub fn apply_block(&mut self, block: &Block, height: u32) {
+        match self.chain.connect(height, block.block_hash(), block.header.prev_blockhash) {
+            ChainEvent::Connected => {}
+            ChainEvent::Reorg(event) => {
+                let unconfirmed = self.disconnect_above(&event);
+                tracing::warn!(
+                    "Reorg of depth {} at height {}, {} wallet transaction(s) back to unconfirmed",
+                    event.depth, event.fork_height, unconfirmed
+                );
+            }
+            ChainEvent::Unlinked => {
+                tracing::warn!("Block {} doesn't link to the wallet's chain", height);
+            }
+        }
+
+        let result = match height.checked_sub(1) {
+            Some(parent_height) => {
+                let connected_to = BlockId {
+                    height: parent_height,
+                    hash: block.header.prev_blockhash,
+                };
+                self.wallet
+                    .apply_block_connected_to(block, height, connected_to)
+                    .map_err(|e| e.to_string())
+            }
+            None => self.wallet.apply_block(block, height).map_err(|e| e.to_string()),
+        };
+        match result {
+            Ok(()) => self.applied_blocks += 1,
+            Err(e) => tracing::error!("Failed to apply block {} to the wallet: {}", height, e),
+        }
+    }
+
+    /// Apply the wallet's transactions from a block below the tip, found by a rescan.
+    /// Unlike [`Self::apply_block`] this doesn't move the wallet's tip, the block is slotted
+    /// into its chain. Returns how many transactions were added
+    pub fn apply_rescanned_block(&mut self, block: &Block, height: u32) -> Result<usize, String> {
+        use bdk_wallet::Update;
+        use bdk_wallet::chain::{ConfirmationBlockTime, TxUpdate};
+
+        let block_id = BlockId { height, hash: block.block_hash() };
+        let anchor = ConfirmationBlockTime {
+            block_id,
+            confirmation_time: block.header.time as u64,
+        };
+
+        // Transactions spending outputs of earlier ones in the same block count too.
+        let mut relevant: Vec<&bitcoin::Transaction> = Vec::new();
+        for tx in &block.txdata {
+            let spends_relevant = tx.input.iter().any(|input| {
+                relevant.iter().any(|earlier| {
+                    earlier.compute_txid() == input.previous_output.txid
+                        && earlier.output.get(input.previous_output.vout as usize)
+                            .is_some_and(|txout| self.is_mine(&txout.script_pubkey))
+                })
+            });
+            if spends_relevant || self.is_relevant(tx) {
+                relevant.push(tx);
+            }
+        }
+        if relevant.is_empty() {
+            return Ok(0);
+        }
+
+        let mut tx_update = TxUpdate::default();
+        for tx in &relevant {
+            tx_update.anchors.insert((anchor, tx.compute_txid()));
+            tx_update.txs.push(Arc::new((*tx).clone()));
+        }
+
+        let mut last_active_indices = std::collections::BTreeMap::new();
+        for tx in &relevant {
+            for output in &tx.output {
+                if let Some((keychain, index)) =
+                    self.wallet.derivation_of_spk(output.script_pubkey.clone())
+                {
+                    let last = last_active_indices.entry(keychain).or_insert(index);
+                    *last = (*last).max(index);
+                }
+            }
+        }
+
+        let update = Update {
+            last_active_indices,
+            tx_update,
+            chain: Some(self.wallet.latest_checkpoint().insert(block_id)),
+        };
+        self.wallet.apply_update(update).map_err(|e| e.to_string())?;
+        Ok(relevant.len())
+    }
+
+    /// The scripts to look for in block filters: every one up to [`GAP_LIMIT`]
+    /// past the last used index of each keychain
+    pub fn scan_script_pubkeys(&self) -> Vec<bitcoin::ScriptBuf> {
+        [KeychainKind::External, KeychainKind::Internal]
+            .into_iter()
+            .flat_map(|keychain| {
+                let count = self.wallet.spk_index()
+                    .last_used_index(keychain)
+                    .map_or(0, |index| index + 1)
+                    + GAP_LIMIT;
+                (0..count).map(move |index| (keychain, index))
+            })
+            .map(|(keychain, index)| self.wallet.peek_address(keychain, index).script_pubkey())
+            .collect()
     }
 
-    /// Process all pending blocks
-    pub fn process_pending_blocks(&mut self) -> usize {
-        let count = self.pending_blocks.len();
-        // For now, we just clear them since BDK wallet in memory 
-        // doesn't have a direct "apply_block" without a chain source.
-        // The real sync will happen when we connect to the node's full scan.
-        self.pending_blocks.clear();
+    /// Disconnect the wallet's blocks above the fork of `event`, moving the transactions they
+    /// confirmed back to unconfirmed. Those the new branch confirms again, or double-spends,
+    /// are sorted out as its blocks are applied. Returns how many transactions were moved
+    fn disconnect_above(&mut self, event: &ReorgEvent) -> usize {
+        use bdk_wallet::Update;
+        use bdk_wallet::chain::{ChainPosition, CheckPoint};
+
+        let reorged: Vec<_> = self.wallet.transactions()
+            .filter(|wallet_tx| match &wallet_tx.chain_position {
+                ChainPosition::Confirmed { anchor, .. } => {
+                    anchor.block_id.height > event.fork_height
+                }
+                ChainPosition::Unconfirmed { .. } => false,
+            })
+            .map(|wallet_tx| wallet_tx.tx_node.tx.clone())
+            .collect();
+        let count = reorged.len();
+
+        // Kept in the mempool view, or they'd vanish with the blocks that confirmed them.
+        let now = unix_now();
+        self.wallet.apply_unconfirmed_txs(reorged.into_iter().map(|tx| (tx, now)));
+
+        // The chain up to the fork, plus the first block of the new branch, which displaces
+        // every checkpoint of the old one.
+        let fork = BlockId { height: event.fork_height, hash: event.fork_hash };
+        let fork_point = self.wallet.latest_checkpoint()
+            .get(event.fork_height)
+            .filter(|checkpoint| checkpoint.block_id() == fork)
+            .unwrap_or_else(|| CheckPoint::new(fork));
+        let (new_height, new_hash) = event.new_block;
+        let chain = match fork_point.push(BlockId { height: new_height, hash: new_hash }) {
+            Ok(chain) => chain,
+            Err(_) => {
+                tracing::error!("Block {} doesn't extend the fork point", new_height);
+                return count;
+            }
+        };
+
+        let update = Update { chain: Some(chain), ..Default::default() };
+        if let Err(e) = self.wallet.apply_update(update) {
+            tracing::error!("Failed to disconnect the reorged blocks from the wallet: {}", e);
+        }
         count
     }
 
+    /// Whether `script` belongs to the wallet
+    pub fn is_mine(&self, script: &bitcoin::Script) -> bool {
+        self.wallet.is_mine(script.to_owned())
+    }
+
+    /// Whether `tx` pays to the wallet or spends an output it knows of
+    pub fn is_relevant(&self, tx: &bitcoin::Transaction) -> bool {
+        tx.output.iter().any(|output| self.is_mine(&output.script_pubkey))
+            || tx.input.iter().any(|input| {
+                self.wallet
+                    .tx_graph()
+                    .get_txout(input.previous_output)
+                    .is_some_and(|txout| self.is_mine(&txout.script_pubkey))
+            })
+    }
+
     /// Get list of transactions from the wallet
     pub fn get_transactions(&self) -> Vec<WalletTransaction> {
         use bdk_wallet::chain::ChainPosition;
@@ -223,29 +425,20 @@ impl WalletManager {
 }
 
 
-/// BlockConsumer implementation for the wallet
-/// This allows the node to send blocks directly to the wallet
-pub struct WalletBlockConsumer;
-
-impl BlockConsumer for WalletBlockConsumer {
-    fn on_block(
-        &self,
-        block: &Block,
-        height: u32,
-        _spent_utxos: Option<&HashMap<OutPoint, UtxoData>>,
-    ) {
-        // Queue block for processing
-        // We use tokio::spawn to avoid blocking
-        let block_clone = block.clone();
-        tokio::spawn(async move {
-            let mut handle = WALLET_MANAGER.write().await;
-            if let Some(manager) = handle.as_mut() {
-                manager.queue_block(block_clone, height);
-            }
-        });
+/// Block handler for the wallet
+/// Applies every new block to the wallet, in order, as it arrives
+pub struct WalletBlockHandler;
+
+impl BlockHandler for WalletBlockHandler {
+    fn name(&self) -> &'static str {
+        "wallet"
     }
 
-    fn wants_spent_utxos(&self) -> bool {
-        false
+    fn handle(&mut self, delivery: &BlockDelivery) {
+        // Runs on the handler's own thread, outside the async runtime.
+        let mut handle = WALLET_MANAGER.blocking_write();
+        if let Some(manager) = handle.as_mut() {
+            manager.apply_block(&delivery.block, delivery.height);
+        }
     }
 }
@@ -1 +1,3 @@
 pub mod manager;
+pub mod rescan;
+pub mod tx_index;
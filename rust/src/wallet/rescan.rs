@@ -0,0 +1,269 @@
+use std::sync::Arc;
+use std::sync::Weak;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use bdk_floresta::Node;
+use bitcoin::ScriptBuf;
+use once_cell::sync::Lazy;
+use tokio::sync::RwLock;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::error;
+use tracing::info;
+
+use crate::node::error::BonsaiNodeError;
+use crate::node::stats_fetcher::filter_height;
+use crate::wallet::manager::WALLET_MANAGER;
+use crate::wallet::tx_index::TX_INDEX;
+
+/// How far before the birthday a rescan starts. Block timestamps may be off by a couple of hours,
+/// and an imported wallet's birthday is only as good as the user's memory.
+const BIRTHDAY_SLACK_SECS: u64 = 24 * 60 * 60;
+
+/// How long to wait before looking again for a filter that isn't downloaded yet.
+const FILTER_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The rescan of the running node, kept after it ends so its outcome can be read.
+pub(crate) static WALLET_RESCAN: Lazy<Arc<RwLock<Option<Rescan>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(None)));
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RescanStatus {
+    Running,
+    /// The next filter isn't downloaded yet, the rescan goes on once it is.
+    WaitingForFilters,
+    Finished,
+    Cancelled,
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct RescanProgress {
+    pub start_height: u32,
+    /// The next block to check.
+    pub current_height: u32,
+    /// The tip when the last filter was checked. The rescan runs until it reaches it.
+    pub tip_height: u32,
+    /// Blocks whose filter matched one of the wallet's scripts.
+    pub matched_blocks: u32,
+    /// Wallet transactions found in those blocks.
+    pub found_transactions: u32,
+    /// Scripts checked against each filter, growing as used addresses are found.
+    pub script_count: u32,
+    /// The highest downloaded filter, while waiting for filters.
+    pub filter_height: Option<u32>,
+    pub started_at: SystemTime,
+    pub status: RescanStatus,
+}
+
+impl RescanProgress {
+    /// Done share of the rescan, from 0.0 to 1.0.
+    pub fn fraction(&self) -> f64 {
+        let total = self.tip_height.saturating_sub(self.start_height) + 1;
+        let done = self.current_height.saturating_sub(self.start_height);
+        (done as f64 / total as f64).min(1.0)
+    }
+}
+
+/// A BIP-0158 rescan of the wallet, matching its scripts against the node's stored block
+/// filters and applying the blocks that match.
+///
+/// Like the [`StatsSampler`](crate::node::stats_sampler::StatsSampler), it only holds a
+/// [`Weak`] reference to the node.
+pub struct Rescan {
+    progress: watch::Receiver<RescanProgress>,
+    cancel: Arc<AtomicBool>,
+    task: JoinHandle<()>,
+}
+
+impl Rescan {
+    /// Start rescanning from `start_height`.
+    pub fn spawn(node: &Arc<RwLock<Node>>, start_height: u32) -> Self {
+        let (tx, rx) = watch::channel(RescanProgress {
+            start_height,
+            current_height: start_height,
+            tip_height: start_height,
+            matched_blocks: 0,
+            found_transactions: 0,
+            script_count: 0,
+            filter_height: None,
+            started_at: SystemTime::now(),
+            status: RescanStatus::Running,
+        });
+        let cancel = Arc::new(AtomicBool::new(false));
+        let task = tokio::spawn(rescan_loop(Arc::downgrade(node), tx, cancel.clone()));
+
+        Rescan {
+            progress: rx,
+            cancel,
+            task,
+        }
+    }
+
+    pub fn progress(&self) -> RescanProgress {
+        self.progress.borrow().clone()
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Ask the rescan to stop after the block it is on. What it found so far stays applied.
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop the rescan, and wait until the task has let go of the node.
+    pub async fn stop(self) {
+        self.task.abort();
+        let _ = self.task.await;
+    }
+}
+
+/// The height of the first block mined after `birthday`, less [`BIRTHDAY_SLACK_SECS`].
+pub(crate) async fn birthday_height(
+    node_handle: Arc<RwLock<Node>>,
+    birthday: u64,
+) -> Result<u32, BonsaiNodeError> {
+    let node = node_handle.read().await;
+    let target = birthday.saturating_sub(BIRTHDAY_SLACK_SECS);
+
+    // Block times aren't strictly increasing, but close enough with the slack above.
+    let mut low = 0;
+    let mut high = node.get_validation_height()?;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let header = node.get_block_header(&node.get_block_hash(mid)?)?;
+        if (header.time as u64) < target {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    Ok(low)
+}
+
+/// The wallet's scripts to match, or `None` if there is no wallet.
+async fn script_pubkeys() -> Option<Vec<ScriptBuf>> {
+    WALLET_MANAGER
+        .read()
+        .await
+        .as_ref()
+        .map(|manager| manager.scan_script_pubkeys())
+}
+
+async fn rescan_loop(
+    node: Weak<RwLock<Node>>,
+    tx: watch::Sender<RescanProgress>,
+    cancel: Arc<AtomicBool>,
+) {
+    let status = match rescan(&node, &tx, &cancel).await {
+        Ok(status) => status,
+        Err(e) => {
+            error!("Wallet rescan failed: {}", e);
+            RescanStatus::Failed(e.to_string())
+        }
+    };
+    tx.send_modify(|progress| progress.status = status);
+}
+
+async fn rescan(
+    node: &Weak<RwLock<Node>>,
+    tx: &watch::Sender<RescanProgress>,
+    cancel: &AtomicBool,
+) -> Result<RescanStatus, BonsaiNodeError> {
+    let mut height = tx.borrow().start_height;
+    let mut scripts = script_pubkeys()
+        .await
+        .ok_or_else(|| BonsaiNodeError::generic("Wallet not initialized"))?;
+    info!(
+        "Rescanning the wallet from height {} with {} scripts",
+        height,
+        scripts.len()
+    );
+    tx.send_modify(|progress| progress.script_count = scripts.len() as u32);
+    // The highest height known to have a filter, where the search starts while waiting.
+    let mut known_filter = None;
+
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            info!("Wallet rescan cancelled at height {}", height);
+            return Ok(RescanStatus::Cancelled);
+        }
+        let node_handle = node.upgrade().ok_or_else(BonsaiNodeError::not_running)?;
+
+        // Filters are only kept for validated blocks, live ones reach the wallet anyway.
+        let (tip, block) = {
+            let node = node_handle.read().await;
+            let tip = node.get_validation_height()?;
+            if height > tip {
+                info!("Wallet rescan reached the tip at height {}", tip);
+                return Ok(RescanStatus::Finished);
+            }
+
+            let hash = node.get_block_hash(height)?;
+            let Some(filter) = node.get_block_filter(height)? else {
+                // Filters download alongside blocks, wait for this one without holding the node.
+                let downloaded = filter_height(&node, known_filter, tip)?;
+                known_filter = downloaded.or(known_filter);
+                drop(node);
+                drop(node_handle);
+                tx.send_modify(|progress| {
+                    progress.status = RescanStatus::WaitingForFilters;
+                    progress.tip_height = tip;
+                    progress.filter_height = downloaded;
+                });
+                tokio::time::sleep(FILTER_POLL_INTERVAL).await;
+                continue;
+            };
+            let matched = filter
+                .match_any(hash, scripts.iter().map(|script| script.as_bytes()))
+                .map_err(|e| BonsaiNodeError::generic(e.to_string()))?;
+
+            if !matched {
+                (tip, None)
+            } else {
+                let block = node.get_block(&hash).await?.ok_or_else(|| {
+                    BonsaiNodeError::generic(format!("Block {} is not available", hash))
+                })?;
+                (tip, Some(block))
+            }
+        };
+
+        let mut found = 0;
+        if let Some(block) = &block {
+            let mut wallet = WALLET_MANAGER.write().await;
+            let manager = wallet
+                .as_mut()
+                .ok_or_else(|| BonsaiNodeError::generic("Wallet not initialized"))?;
+            found = manager.apply_rescanned_block(block, height)?;
+
+            // The index only follows new blocks, so it learns of these from the rescan.
+            if let Ok(mut index) = TX_INDEX.lock() {
+                if let Some(index) = index.as_mut() {
+                    if let Err(e) = index.add_block(block, height, manager) {
+                        error!("{}", e);
+                    }
+                }
+            }
+
+            // Found outputs may push the gap limit further.
+            scripts = manager.scan_script_pubkeys();
+        }
+        known_filter = Some(height);
+
+        height += 1;
+        tx.send_modify(|progress| {
+            progress.status = RescanStatus::Running;
+            progress.filter_height = None;
+            progress.current_height = height;
+            progress.tip_height = tip;
+            progress.matched_blocks += block.is_some() as u32;
+            progress.found_transactions += found as u32;
+            progress.script_count = scripts.len() as u32;
+        });
+    }
+}
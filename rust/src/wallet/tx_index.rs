@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use bitcoin::Block;
+use bitcoin::Network;
+use bitcoin::OutPoint;
+use bitcoin::Transaction;
+use bitcoin::TxOut;
+use bitcoin::Txid;
+use bitcoin::consensus::deserialize;
+use bitcoin::consensus::serialize;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::error;
+use tracing::info;
+
+use crate::node::block_pipeline::BlockDelivery;
+use crate::node::block_pipeline::BlockHandler;
+use crate::node::block_pipeline::StartFrom;
+use crate::node::error::BonsaiNodeError;
+use crate::wallet::manager::WALLET_MANAGER;
+use crate::wallet::manager::WalletManager;
+
+pub(crate) const TX_INDEX_DIR: &str = "tx_index";
+
+/// The last block indexed, next to the entries in [`TX_INDEX_DIR`].
+const TIP_FILE: &str = "tip.toml";
+
+/// Blocks below the tip that are indexed again on start, in case they were reorged out while
+/// we were offline.
+const REPLAY_DEPTH: u32 = 6;
+
+/// The transaction index of the running node, loaded from its data directory on start.
+pub(crate) static TX_INDEX: Lazy<Mutex<Option<TxIndex>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IndexedTx {
+    pub txid: String,
+    pub block_hash: String,
+    pub height: u32,
+    /// Position of the transaction in its block, the coinbase being 0.
+    pub position: u32,
+    /// The consensus encoded transaction, in hex.
+    pub raw_tx: String,
+}
+
+impl IndexedTx {
+    pub fn transaction(&self) -> Option<Transaction> {
+        let bytes = hex::decode(&self.raw_tx).ok()?;
+        deserialize(&bytes).ok()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct IndexTip {
+    height: u32,
+    hash: String,
+}
+
+/// The confirmed transactions of the wallet, so they can be shown without fetching their
+/// blocks again.
+///
+/// Each transaction is kept as `<txid>.toml` in the `tx_index` directory of the node's data
+/// directory, written when it is indexed and deleted when its block is rolled back.
+#[derive(Clone, Debug)]
+pub struct TxIndex {
+    /// The last block indexed.
+    tip: Option<IndexTip>,
+    txs: HashMap<Txid, IndexedTx>,
+    dir: PathBuf,
+    network: Network,
+}
+
+impl TxIndex {
+    /// Load the index from `data_dir`, or start an empty one if there is none.
+    pub fn load(data_dir: &Path, network: Network) -> Self {
+        let dir = data_dir.join(TX_INDEX_DIR);
+
+        let tip = fs::read_to_string(dir.join(TIP_FILE))
+            .ok()
+            .and_then(|contents| {
+                toml::from_str::<IndexTip>(&contents)
+                    .map_err(|e| error!("Failed to parse {}: {}", TIP_FILE, e))
+                    .ok()
+            });
+
+        let mut txs = HashMap::new();
+        for entry in fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            let Some(txid) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| Txid::from_str(stem).ok())
+            else {
+                continue;
+            };
+            match read_entry(&path) {
+                Ok(tx) => {
+                    txs.insert(txid, tx);
+                }
+                Err(e) => error!("Failed to read {}: {}", path.to_string_lossy(), e),
+            }
+        }
+
+        info!("Loaded {} indexed transaction(s)", txs.len());
+        TxIndex {
+            tip,
+            txs,
+            dir,
+            network,
+        }
+    }
+
+    /// Write the tip, the entries are written as they are indexed.
+    pub fn save(&self) -> Result<(), String> {
+        let path = self.dir.join(TIP_FILE);
+        let Some(tip) = &self.tip else {
+            return match fs::remove_file(&path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => Err(format!(
+                    "Failed to delete {}: {}",
+                    path.to_string_lossy(),
+                    e
+                )),
+                _ => Ok(()),
+            };
+        };
+        self.write(&path, tip)
+    }
+
+    fn write(&self, path: &Path, value: &impl Serialize) -> Result<(), String> {
+        let contents = toml::to_string_pretty(value).map_err(|e| e.to_string())?;
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(path, contents))
+            .map_err(|e| {
+                format!(
+                    "Failed to write transaction index to {}: {}",
+                    path.to_string_lossy(),
+                    e
+                )
+            })
+    }
+
+    fn entry_path(&self, txid: &Txid) -> PathBuf {
+        self.dir.join(format!("{}.toml", txid))
+    }
+
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    pub fn tip_height(&self) -> Option<u32> {
+        self.tip.as_ref().map(|tip| tip.height)
+    }
+
+    pub fn len(&self) -> usize {
+        self.txs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.txs.is_empty()
+    }
+
+    pub fn get(&self, txid: &Txid) -> Option<&IndexedTx> {
+        self.txs.get(txid)
+    }
+
+    fn txout(&self, outpoint: &OutPoint) -> Option<TxOut> {
+        self.get(&outpoint.txid)?
+            .transaction()?
+            .output
+            .get(outpoint.vout as usize)
+            .cloned()
+    }
+
+    /// Drop everything at or above `height`. Returns whether anything was removed.
+    pub fn rollback(&mut self, height: u32) -> Result<bool, String> {
+        let stale: Vec<Txid> = self
+            .txs
+            .iter()
+            .filter(|(_, tx)| tx.height >= height)
+            .map(|(txid, _)| *txid)
+            .collect();
+        for txid in &stale {
+            let path = self.entry_path(txid);
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => {
+                    return Err(format!(
+                        "Failed to delete {}: {}",
+                        path.to_string_lossy(),
+                        e
+                    ));
+                }
+                _ => {
+                    self.txs.remove(txid);
+                }
+            }
+        }
+
+        if self.tip.as_ref().is_some_and(|tip| tip.height >= height) {
+            self.tip = None;
+        }
+        Ok(!stale.is_empty())
+    }
+
+    /// Index the transactions of `block` that concern `wallet`, writing each new one, without
+    /// touching other heights or the tip. Returns whether the index changed.
+    pub(crate) fn add_block(
+        &mut self,
+        block: &Block,
+        height: u32,
+        wallet: &WalletManager,
+    ) -> Result<bool, String> {
+        let mut changed = false;
+        let block_hash = block.block_hash().to_string();
+
+        for (position, tx) in block.txdata.iter().enumerate() {
+            // Spends of outputs indexed earlier are caught here, even if the wallet hasn't seen
+            // the block that created them yet.
+            let relevant = wallet.is_relevant(tx)
+                || tx.input.iter().any(|input| {
+                    self.txout(&input.previous_output)
+                        .is_some_and(|txout| wallet.is_mine(&txout.script_pubkey))
+                });
+            if !relevant {
+                continue;
+            }
+
+            let txid = tx.compute_txid();
+            let indexed = IndexedTx {
+                txid: txid.to_string(),
+                block_hash: block_hash.clone(),
+                height,
+                position: position as u32,
+                raw_tx: hex::encode(serialize(tx)),
+            };
+            self.write(&self.entry_path(&txid), &indexed)?;
+            self.txs.insert(txid, indexed);
+            changed = true;
+        }
+        Ok(changed)
+    }
+
+    /// Index the transactions of `block` that concern `wallet`, replacing whatever was indexed
+    /// at or above `height` before. Returns whether the index changed.
+    pub fn connect_block(
+        &mut self,
+        block: &Block,
+        height: u32,
+        wallet: &WalletManager,
+    ) -> Result<bool, String> {
+        let rolled_back = self.rollback(height)?;
+        let added = self.add_block(block, height, wallet)?;
+
+        self.tip = Some(IndexTip {
+            height,
+            hash: block.block_hash().to_string(),
+        });
+        Ok(rolled_back || added)
+    }
+}
+
+fn read_entry(path: &Path) -> Result<IndexedTx, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    toml::from_str(&contents).map_err(|e| e.to_string())
+}
+
+/// Look up a transaction in the running node's index.
+pub fn indexed_tx(txid: &str) -> Result<Option<(IndexedTx, Network)>, BonsaiNodeError> {
+    let txid = Txid::from_str(txid.trim()).map_err(|e| format!("Invalid txid: {}", e))?;
+    let index = TX_INDEX.lock().map_err(|e| e.to_string())?;
+    let index = index.as_ref().ok_or_else(BonsaiNodeError::not_running)?;
+    Ok(index.get(&txid).cloned().map(|tx| (tx, index.network())))
+}
+
+/// Persist the index and let go of it.
+pub fn close_tx_index() {
+    if let Ok(mut index) = TX_INDEX.lock() {
+        if let Some(index) = index.take() {
+            if let Err(e) = index.save() {
+                error!("{}", e);
+            }
+        }
+    }
+}
+
+/// Keeps [`TX_INDEX`] up to date.
+///
+/// The tip is only written when the index changes, so after a restart some blocks that changed
+/// nothing may be replayed. That is harmless, each block replaces what was indexed at its height.
+pub(crate) struct TxIndexHandler;
+
+impl BlockHandler for TxIndexHandler {
+    fn name(&self) -> &'static str {
+        "tx_index"
+    }
+
+    fn start_from(&self) -> StartFrom {
+        let tip = TX_INDEX
+            .lock()
+            .ok()
+            .and_then(|index| index.as_ref().and_then(TxIndex::tip_height));
+        match tip {
+            Some(tip) => StartFrom::Height(tip.saturating_sub(REPLAY_DEPTH) + 1),
+            None => StartFrom::Tip,
+        }
+    }
+
+    fn handle(&mut self, delivery: &BlockDelivery) {
+        // Runs on the handler's own thread, outside the async runtime.
+        let wallet = WALLET_MANAGER.blocking_read();
+        let Some(wallet) = wallet.as_ref() else {
+            return;
+        };
+        let Ok(mut index) = TX_INDEX.lock() else {
+            return;
+        };
+        let Some(index) = index.as_mut() else {
+            return;
+        };
+
+        match index.connect_block(&delivery.block, delivery.height, wallet) {
+            Ok(true) => {
+                if let Err(e) = index.save() {
+                    error!("{}", e);
+                }
+            }
+            Ok(false) => {}
+            Err(e) => error!("{}", e),
+        }
+    }
+}